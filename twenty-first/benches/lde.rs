@@ -0,0 +1,50 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use twenty_first::math::lde::lde;
+use twenty_first::math::lde::ArithmeticDomain;
+use twenty_first::math::other::random_elements;
+use twenty_first::prelude::*;
+
+criterion_main!(benches);
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = low_degree_extension<14>,
+              low_degree_extension<16>,
+              low_degree_extension<18>,
+);
+
+fn low_degree_extension<const LOG2_TRACE_LEN: usize>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!(
+        "Low-Degree Extension – Trace Length: 2^{LOG2_TRACE_LEN}"
+    ));
+
+    let trace_domain = ArithmeticDomain::of_length(1 << LOG2_TRACE_LEN).unwrap();
+    let fri_domain = ArithmeticDomain::of_length(1 << (LOG2_TRACE_LEN + 2))
+        .unwrap()
+        .with_offset(BFieldElement::generator());
+    let column = random_elements::<BFieldElement>(trace_domain.length());
+
+    let id = BenchmarkId::new("Interpolate, then evaluate", LOG2_TRACE_LEN);
+    group.bench_function(id, |b| {
+        b.iter(|| {
+            Polynomial::fast_coset_interpolate(
+                trace_domain.offset(),
+                trace_domain.generator(),
+                &column,
+            )
+            .fast_coset_evaluate(
+                fri_domain.offset(),
+                fri_domain.generator(),
+                fri_domain.length(),
+            )
+        })
+    });
+
+    let id = BenchmarkId::new("LDE", LOG2_TRACE_LEN);
+    group.bench_function(id, |b| b.iter(|| lde(&column, trace_domain, fri_domain)));
+
+    group.finish();
+}
@@ -1,5 +1,6 @@
 use criterion::criterion_group;
 use criterion::criterion_main;
+use criterion::BatchSize;
 use criterion::BenchmarkId;
 use criterion::Criterion;
 use rand::random;
@@ -64,5 +65,100 @@ fn bench_parallel(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_10, bench_pair, bench_varlen, bench_parallel);
+/// Measured on an x86-64 machine with AVX-512, 1024 states:
+///
+/// | benchmark                   | time    |
+/// |:----------------------------|--------:|
+/// | `permutation`, sequentially | 1.42 ms |
+/// | `batch_permutation`         | 0.86 ms |
+fn bench_batch_permutation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tip5/batch_permutation");
+
+    let size = 1024;
+    let states: Vec<[BFieldElement; tip5::STATE_SIZE]> = (0..size).map(|_| random()).collect();
+
+    group.bench_function(BenchmarkId::new("Tip5 / Permutation", size), |bencher| {
+        bencher.iter_batched_ref(
+            || states.clone(),
+            |states| {
+                for state in states.iter_mut() {
+                    let mut sponge = Tip5 { state: *state };
+                    sponge.permutation();
+                    *state = sponge.state;
+                }
+            },
+            BatchSize::SmallInput,
+        );
+    });
+    group.bench_function(
+        BenchmarkId::new("Tip5 / Batch Permutation", size),
+        |bencher| {
+            bencher.iter_batched_ref(
+                || states.clone(),
+                |states| Tip5::batch_permutation(states),
+                BatchSize::SmallInput,
+            );
+        },
+    );
+}
+
+fn bench_batch_pairs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tip5/batch_hash_pairs");
+
+    let size = 1024;
+    let pairs: Vec<(Digest, Digest)> = (0..size).map(|_| (random(), random())).collect();
+
+    group.bench_function(BenchmarkId::new("Tip5 / Hash Pairs", size), |bencher| {
+        bencher.iter(|| {
+            pairs
+                .iter()
+                .map(|&(left, right)| Tip5::hash_pair(left, right))
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function(
+        BenchmarkId::new("Tip5 / Batch Hash Pairs", size),
+        |bencher| {
+            bencher.iter(|| Tip5::batch_hash_pairs(&pairs));
+        },
+    );
+}
+
+/// Hashes 1024 leafs of 3 elements each, like the FRI prover does for a
+/// codeword of [`XFieldElement`]s.
+fn bench_batch_varlen(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tip5/batch_hash_varlen");
+
+    let size = 1024;
+    let inputs: Vec<Vec<BFieldElement>> = (0..size).map(|_| random_elements(3)).collect();
+
+    group.bench_function(
+        BenchmarkId::new("Tip5 / Hash Variable Length", size),
+        |bencher| {
+            bencher.iter(|| {
+                inputs
+                    .iter()
+                    .map(|input| Tip5::hash_varlen(input))
+                    .collect::<Vec<_>>()
+            });
+        },
+    );
+    group.bench_function(
+        BenchmarkId::new("Tip5 / Batch Hash Variable Length", size),
+        |bencher| {
+            bencher.iter(|| Tip5::batch_hash_varlen(&inputs));
+        },
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_10,
+    bench_pair,
+    bench_varlen,
+    bench_parallel,
+    bench_batch_permutation,
+    bench_batch_pairs,
+    bench_batch_varlen
+);
 criterion_main!(benches);
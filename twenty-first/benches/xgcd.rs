@@ -0,0 +1,39 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use twenty_first::math::other::random_elements;
+use twenty_first::prelude::*;
+
+criterion_main!(benches);
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    // the following seem to outline the boundary where fast xgcd is faster
+    targets = xgcd< 8>,
+              xgcd< 9>,
+              xgcd<10>,
+              xgcd<11>,
+              xgcd<12>,
+              xgcd<14>,
+);
+
+fn xgcd<const LOG2_DEG: usize>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!(
+        "Extended GCD of Polynomials – Degree: 2^{LOG2_DEG}"
+    ));
+    let x = Polynomial::new(random_elements::<BFieldElement>((1 << LOG2_DEG) + 1));
+    let y = Polynomial::new(random_elements::<BFieldElement>(1 << LOG2_DEG));
+
+    let id = BenchmarkId::new("Naive", LOG2_DEG);
+    group.bench_function(id, |b| {
+        b.iter(|| Polynomial::naive_xgcd(x.clone(), y.clone()))
+    });
+
+    let id = BenchmarkId::new("Fast", LOG2_DEG);
+    group.bench_function(id, |b| {
+        b.iter(|| Polynomial::fast_xgcd(x.clone(), y.clone()))
+    });
+
+    group.finish();
+}
@@ -4,10 +4,16 @@ use thiserror::Error;
 
 pub use crate::math::bfield_codec::BFieldCodecError;
 pub use crate::math::bfield_codec::PolynomialBFieldCodecError;
+pub use crate::math::rs::ReedSolomonError;
+pub use crate::math::sparse_polynomial::SparsePolynomialError;
 use crate::prelude::tip5::Digest;
 use crate::prelude::x_field_element::EXTENSION_DEGREE;
 use crate::prelude::BFieldElement;
+pub use crate::util_types::fri::FriError;
 pub use crate::util_types::merkle_tree::MerkleTreeError;
+pub use crate::util_types::mmr::archival_mmr::ArchivalMmrError;
+pub use crate::util_types::proof_stream::ProofStreamError;
+pub use crate::util_types::sparse_merkle_tree::SparseMerkleTreeError;
 
 #[derive(Debug, Clone, Eq, PartialEq, Error)]
 #[non_exhaustive]
@@ -48,6 +48,7 @@ pub(crate) mod tests {
         implements_usual_auto_traits::<BFieldElement>();
         implements_usual_auto_traits::<Polynomial<BFieldElement>>();
         implements_usual_auto_traits::<Polynomial<XFieldElement>>();
+        implements_usual_auto_traits::<MPolynomial<BFieldElement>>();
         implements_usual_auto_traits::<Digest>();
         implements_usual_auto_traits::<Tip5>();
         implements_usual_auto_traits::<XFieldElement>();
@@ -59,14 +60,40 @@ pub(crate) mod tests {
 
     #[test]
     fn public_types_implement_the_usual_auto_traits() {
+        implements_usual_auto_traits::<math::baby_bear::BabyBearElement>();
+        implements_usual_auto_traits::<math::baby_bear::BabyBearExtensionElement>();
+        implements_usual_auto_traits::<math::mersenne_31::Mersenne31Element>();
+        implements_usual_auto_traits::<math::mersenne_31::ComplexMersenne31Element>();
+        implements_usual_auto_traits::<math::evaluation_form::EvaluationForm<BFieldElement>>();
+        implements_usual_auto_traits::<math::evaluation_form::LagrangeBasis<XFieldElement>>();
+        implements_usual_auto_traits::<math::extension_field::QuarticExtensionElement>();
+        implements_usual_auto_traits::<math::extension_field::QuinticExtensionElement>();
         implements_usual_auto_traits::<math::lattice::CyclotomicRingElement>();
         implements_usual_auto_traits::<math::lattice::ModuleElement<42>>();
         implements_usual_auto_traits::<math::lattice::kem::SecretKey>();
         implements_usual_auto_traits::<math::lattice::kem::PublicKey>();
         implements_usual_auto_traits::<math::lattice::kem::Ciphertext>();
+        implements_usual_auto_traits::<math::lde::ArithmeticDomain>();
+        implements_usual_auto_traits::<math::ntt::NttPlan>();
+        implements_usual_auto_traits::<math::ntt::MatrixLayout>();
+        implements_usual_auto_traits::<math::packed_b_field_element::PackedBFieldElement>();
+        implements_usual_auto_traits::<math::poseidon2::Poseidon2>();
+        implements_usual_auto_traits::<math::rpo::Rpo>();
+        implements_usual_auto_traits::<math::rs::ReedSolomon>();
+        implements_usual_auto_traits::<math::sparse_polynomial::SparsePolynomial<BFieldElement>>();
         implements_usual_auto_traits::<mock::mmr::MockMmr>();
         implements_usual_auto_traits::<util_types::algebraic_hasher::Domain>();
+        implements_usual_auto_traits::<util_types::fri::Fri>();
+        implements_usual_auto_traits::<util_types::fri::FriProof>();
+        implements_usual_auto_traits::<util_types::fri::FriRound>();
+        implements_usual_auto_traits::<util_types::proof_stream::ProofStream>();
+        implements_usual_auto_traits::<util_types::proof_stream::Proof>();
         implements_usual_auto_traits::<util_types::mmr::mmr_accumulator::MmrAccumulator>();
+        implements_usual_auto_traits::<util_types::mmr::archival_mmr::ArchivalMmr>();
+        implements_usual_auto_traits::<util_types::mmr::mmr_storage::InMemoryMmrStorage>();
+        implements_usual_auto_traits::<util_types::mmr::mmr_storage::FileMmrStorage>();
+        implements_usual_auto_traits::<util_types::sparse_merkle_tree::SparseMerkleTree>();
+        implements_usual_auto_traits::<util_types::sparse_merkle_tree::SparseMerkleTreeProof>();
         implements_usual_auto_traits::<math::zerofier_tree::Branch<BFieldElement>>();
         implements_usual_auto_traits::<math::zerofier_tree::Leaf<BFieldElement>>();
         implements_usual_auto_traits::<math::zerofier_tree::ZerofierTree<BFieldElement>>();
@@ -80,6 +107,10 @@ pub(crate) mod tests {
         implements_usual_auto_traits::<error::BFieldCodecError>();
         implements_usual_auto_traits::<error::PolynomialBFieldCodecError>();
         implements_usual_auto_traits::<error::MerkleTreeError>();
+        implements_usual_auto_traits::<error::FriError>();
+        implements_usual_auto_traits::<error::ReedSolomonError>();
+        implements_usual_auto_traits::<error::ProofStreamError>();
+        implements_usual_auto_traits::<error::SparseMerkleTreeError>();
         implements_usual_auto_traits::<error::ParseBFieldElementError>();
         implements_usual_auto_traits::<error::TryFromDigestError>();
         implements_usual_auto_traits::<error::TryFromHexDigestError>();
@@ -1,11 +1,23 @@
 pub mod b_field_element;
+pub mod baby_bear;
 pub mod bfield_codec;
 pub mod digest;
+pub mod evaluation_form;
+pub mod extension_field;
 pub mod lattice;
+pub mod lde;
 pub mod mds;
+pub mod mersenne_31;
+pub mod mpolynomial;
 pub mod ntt;
 pub mod other;
+pub mod packed_b_field_element;
 pub mod polynomial;
+pub mod poseidon2;
+pub mod rpo;
+pub mod rs;
+mod small_prime_field;
+pub mod sparse_polynomial;
 pub mod tip5;
 pub mod traits;
 pub mod x_field_element;
@@ -33,6 +33,7 @@ use super::traits::Inverse;
 use super::traits::PrimitiveRootOfUnity;
 use super::x_field_element::XFieldElement;
 use crate::error::ParseBFieldElementError;
+use crate::math::packed_b_field_element;
 use crate::math::traits::CyclicGroupGenerator;
 use crate::math::traits::FiniteField;
 use crate::math::traits::ModPowU32;
@@ -293,6 +294,72 @@ impl BFieldElement {
         acc
     }
 
+    /// The [Legendre symbol](https://en.wikipedia.org/wiki/Legendre_symbol) of
+    /// `self`: 0 if `self` is zero, 1 if `self` is a non-zero square, and -1
+    /// otherwise. Since the modulus is prime, this coincides with the Jacobi
+    /// symbol.
+    pub fn legendre_symbol(&self) -> i8 {
+        let euler_criterion = self.mod_pow((Self::P - 1) / 2);
+        if euler_criterion.is_zero() {
+            0
+        } else if euler_criterion.is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Whether `self` has a [square root](Self::sqrt). Zero is a square.
+    pub fn is_square(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// A square root of `self`, or [`None`] if `self` is not a
+    /// [square](Self::is_square). Which of the two square roots is returned is
+    /// unspecified but deterministic.
+    ///
+    /// Uses the [Tonelli-Shanks algorithm][tonelli_shanks], which is efficient
+    /// for this field because P - 1 = 2^32·(2^32 - 1) has a large power of two
+    /// as a factor.
+    ///
+    /// [tonelli_shanks]: https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm
+    pub fn sqrt(&self) -> Option<Self> {
+        const TWO_ADICITY: u32 = 32;
+        const ODD_FACTOR: u64 = (BFieldElement::P - 1) >> TWO_ADICITY;
+
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        // The generator is a non-square. Its ODD_FACTOR-th power generates the
+        // subgroup of order 2^32.
+        let mut max_order = TWO_ADICITY;
+        let mut c = Self::generator().mod_pow(ODD_FACTOR);
+        let mut t = self.mod_pow(ODD_FACTOR);
+        let mut root = self.mod_pow(ODD_FACTOR.div_ceil(2));
+
+        // invariant: root^2 = self·t, and t has order 2^i for some i < max_order
+        while !t.is_one() {
+            let mut order = 0;
+            let mut t_power = t;
+            while !t_power.is_one() {
+                t_power = t_power.square();
+                order += 1;
+                if order == max_order {
+                    return None;
+                }
+            }
+
+            let b = c.mod_pow(1 << (max_order - order - 1));
+            max_order = order;
+            c = b.square();
+            t *= c;
+            root *= b;
+        }
+
+        Some(root)
+    }
+
     /// Convert a `BFieldElement` from a byte slice in native endianness.
     pub fn from_ne_bytes(bytes: &[u8]) -> BFieldElement {
         let mut bytes_copied: [u8; 8] = [0; 8];
@@ -559,7 +626,13 @@ impl Distribution<BFieldElement> for Standard {
     }
 }
 
-impl FiniteField for BFieldElement {}
+impl FiniteField for BFieldElement {
+    type BaseField = Self;
+
+    fn batch_inversion(input: Vec<Self>) -> Vec<Self> {
+        packed_b_field_element::batch_inversion(input)
+    }
+}
 
 impl Zero for BFieldElement {
     #[inline]
@@ -698,9 +771,27 @@ impl ModPowU64 for BFieldElement {
 }
 
 impl PrimitiveRootOfUnity for BFieldElement {
+    /// Only supports powers of two up to 2^32. For other orders, see
+    /// [`primitive_root_of_unity_of_any_order`](Self::primitive_root_of_unity_of_any_order).
     fn primitive_root_of_unity(n: u64) -> Option<BFieldElement> {
         PRIMITIVE_ROOTS.get(&n).map(|&r| BFieldElement::new(r))
     }
+
+    /// A primitive `n`th root of unity exists if and only if `n` divides the order
+    /// of the multiplicative group, 2^32·3·5·17·257·65537. For powers of two,
+    /// this is the [table-based root](Self::primitive_root_of_unity); all others
+    /// are derived from the [generator](BFieldElement::generator).
+    fn primitive_root_of_unity_of_any_order(n: u64) -> Option<BFieldElement> {
+        if let Some(root) = Self::primitive_root_of_unity(n) {
+            return Some(root);
+        }
+
+        let group_order = BFieldElement::P - 1;
+        if n == 0 || !group_order.is_multiple_of(n) {
+            return None;
+        }
+        Some(BFieldElement::generator().mod_pow(group_order / n))
+    }
 }
 
 #[cfg(test)]
@@ -1184,6 +1275,53 @@ mod b_prime_field_element_test {
         }
     }
 
+    #[test]
+    fn primitive_root_of_unity_exists_only_for_powers_of_two() {
+        for n in [3, 5, 6, 12, 17, 257, 65537, 4294967295] {
+            assert!(BFieldElement::primitive_root_of_unity(n).is_none());
+            assert!(BFieldElement::primitive_root_of_unity_of_any_order(n).is_some());
+        }
+        for i in 0..=32 {
+            let n = 1 << i;
+            let root = BFieldElement::primitive_root_of_unity(n);
+            assert_eq!(root, BFieldElement::primitive_root_of_unity_of_any_order(n));
+        }
+    }
+
+    #[test]
+    fn primitive_roots_of_unity_exist_for_all_divisors_of_group_order() {
+        let prime_factors = [2, 3, 5, 17, 257, 65537];
+        for n in [
+            3,
+            5,
+            6,
+            12,
+            15,
+            17,
+            24,
+            51,
+            255,
+            257,
+            771,
+            3 << 20,
+            65537,
+            4294967295,
+        ] {
+            let root = BFieldElement::primitive_root_of_unity_of_any_order(n).unwrap();
+            assert!(root.mod_pow(n).is_one());
+            for factor in prime_factors.into_iter().filter(|f| n % f == 0) {
+                assert!(!root.mod_pow(n / factor).is_one());
+            }
+        }
+    }
+
+    #[test]
+    fn primitive_roots_of_unity_do_not_exist_for_non_divisors_of_group_order() {
+        for n in [7, 9, 11, 13, 25, 100, 1 << 33] {
+            assert!(BFieldElement::primitive_root_of_unity_of_any_order(n).is_none());
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Attempted to find the multiplicative inverse of zero.")]
     fn multiplicative_inverse_of_zero() {
@@ -1354,4 +1492,46 @@ mod b_prime_field_element_test {
     fn const_minus_two_inverse_is_really_minus_two_inverse() {
         assert_eq!(bfe!(-2).inverse(), BFieldElement::MINUS_TWO_INVERSE);
     }
+
+    #[proptest]
+    fn square_root_of_square_squares_to_square(x: BFieldElement) {
+        let square = x * x;
+        prop_assert!(square.is_square());
+        let root = square.sqrt().unwrap();
+        prop_assert_eq!(square, root * root);
+        prop_assert!(root == x || root == -x);
+    }
+
+    #[proptest]
+    fn square_root_exists_if_and_only_if_element_is_square(x: BFieldElement) {
+        let root = x.sqrt();
+        prop_assert_eq!(x.is_square(), root.is_some());
+        if let Some(root) = root {
+            prop_assert_eq!(x, root * root);
+        }
+    }
+
+    #[proptest]
+    fn legendre_symbol_is_multiplicative(a: BFieldElement, b: BFieldElement) {
+        prop_assert_eq!(
+            a.legendre_symbol() * b.legendre_symbol(),
+            (a * b).legendre_symbol()
+        );
+    }
+
+    #[proptest]
+    fn product_of_square_and_non_square_is_non_square(#[filter(!#x.is_zero())] x: BFieldElement) {
+        let non_square = BFieldElement::generator();
+        prop_assert_eq!(-1, non_square.legendre_symbol());
+        prop_assert!((x * x * non_square).sqrt().is_none());
+    }
+
+    #[test]
+    fn square_root_of_zero_and_one() {
+        assert_eq!(0, BFieldElement::ZERO.legendre_symbol());
+        assert_eq!(Some(BFieldElement::ZERO), BFieldElement::ZERO.sqrt());
+        assert_eq!(1, BFieldElement::ONE.legendre_symbol());
+        let root_of_one = BFieldElement::ONE.sqrt().unwrap();
+        assert!(root_of_one.is_one() || (-root_of_one).is_one());
+    }
 }
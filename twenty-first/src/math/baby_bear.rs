@@ -0,0 +1,466 @@
+//! The BabyBear field with modulus 2^31 - 2^27 + 1 and its quartic extension.
+//!
+//! The multiplicative group of the BabyBear field has order 2^27·3·5, making it
+//! well suited for [NTTs](crate::math::ntt) of length up to 2^27.
+
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use arbitrary::Arbitrary;
+use bfieldcodec_derive::BFieldCodec;
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use num_traits::One;
+use num_traits::Zero;
+use rand::Rng;
+use rand_distr::Distribution;
+use rand_distr::Standard;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::math::small_prime_field::small_prime_field;
+use crate::math::traits::CyclicGroupGenerator;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+use crate::math::traits::ModPowU32;
+use crate::math::traits::ModPowU64;
+use crate::math::traits::PrimitiveRootOfUnity;
+
+small_prime_field!(
+    /// An element of the BabyBear field with modulus 2^31 - 2^27 + 1.
+    ///
+    /// ```
+    /// # use twenty_first::math::baby_bear::BabyBearElement;
+    /// # use twenty_first::math::traits::Inverse;
+    /// let a = BabyBearElement::new(42);
+    /// assert_eq!(BabyBearElement::new(1), a * a.inverse());
+    /// ```
+    BabyBearElement,
+    0x7800_0001,
+    31,
+);
+
+pub const BABY_BEAR_EXTENSION_DEGREE: usize = 4;
+
+/// An element of the quartic extension of the [BabyBear field](BabyBearElement)
+/// defined by the irreducible polynomial x^4 - 11.
+///
+/// The coefficients are ordered from the constant term upwards.
+#[derive(
+    Debug, Default, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, BFieldCodec, Arbitrary,
+)]
+pub struct BabyBearExtensionElement {
+    pub coefficients: [BabyBearElement; BABY_BEAR_EXTENSION_DEGREE],
+}
+
+impl BabyBearExtensionElement {
+    /// The constant W in the extension field's modulus x^4 - W.
+    pub const W: BabyBearElement = BabyBearElement::new(11);
+
+    pub const fn new(coefficients: [BabyBearElement; BABY_BEAR_EXTENSION_DEGREE]) -> Self {
+        Self { coefficients }
+    }
+
+    pub const fn new_const(element: BabyBearElement) -> Self {
+        let zero = BabyBearElement::ZERO;
+        Self::new([element, zero, zero, zero])
+    }
+
+    /// The element as a [base field element](BabyBearElement) if it lies in the
+    /// base field, [`None`] otherwise.
+    pub fn unlift(&self) -> Option<BabyBearElement> {
+        let [c0, rest @ ..] = self.coefficients;
+        rest.iter().all(Zero::is_zero).then_some(c0)
+    }
+}
+
+impl From<BabyBearElement> for BabyBearExtensionElement {
+    fn from(element: BabyBearElement) -> Self {
+        Self::new_const(element)
+    }
+}
+
+impl From<u64> for BabyBearExtensionElement {
+    fn from(value: u64) -> Self {
+        Self::new_const(value.into())
+    }
+}
+
+impl Sum for BabyBearExtensionElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, element| acc + element)
+    }
+}
+
+impl Inverse for BabyBearExtensionElement {
+    fn inverse(&self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "Cannot invert the zero element in the extension field."
+        );
+
+        // With a(x)·a(-x) = b(x²) and b(y)·b(-y) = N ∈ F, the inverse of a(x) is
+        // a(-x)·b(-x²) / N.
+        let [a0, a1, a2, a3] = self.coefficients;
+        let conjugate = Self::new([a0, -a1, a2, -a3]);
+        let [b0, _, b2, _] = (*self * conjugate).coefficients;
+        let norm = b0 * b0 - Self::W * b2 * b2;
+
+        let zero = BabyBearElement::ZERO;
+        conjugate * Self::new([b0, zero, -b2, zero]) * norm.inverse()
+    }
+}
+
+impl PrimitiveRootOfUnity for BabyBearExtensionElement {
+    fn primitive_root_of_unity(n: u64) -> Option<Self> {
+        BabyBearElement::primitive_root_of_unity(n).map(Self::new_const)
+    }
+
+    fn primitive_root_of_unity_of_any_order(n: u64) -> Option<Self> {
+        BabyBearElement::primitive_root_of_unity_of_any_order(n).map(Self::new_const)
+    }
+}
+
+impl Distribution<BabyBearExtensionElement> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BabyBearExtensionElement {
+        BabyBearExtensionElement::new(rng.gen())
+    }
+}
+
+impl CyclicGroupGenerator for BabyBearExtensionElement {
+    fn get_cyclic_group_elements(&self, max: Option<usize>) -> Vec<Self> {
+        let mut val = *self;
+        let mut ret: Vec<Self> = vec![Self::one()];
+
+        loop {
+            ret.push(val);
+            val *= *self;
+            if val.is_one() || max.is_some() && ret.len() >= max.unwrap() {
+                break;
+            }
+        }
+        ret
+    }
+}
+
+impl Display for BabyBearExtensionElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(element) = self.unlift() {
+            return write!(f, "{element}_ext");
+        }
+
+        let [c0, c1, c2, c3] = self.coefficients;
+        write!(f, "({c3}·x³ + {c2}·x² + {c1}·x + {c0})")
+    }
+}
+
+impl Zero for BabyBearExtensionElement {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self == &Self::ZERO
+    }
+}
+
+impl ConstZero for BabyBearExtensionElement {
+    const ZERO: Self = Self::new_const(BabyBearElement::ZERO);
+}
+
+impl One for BabyBearExtensionElement {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self == &Self::ONE
+    }
+}
+
+impl ConstOne for BabyBearExtensionElement {
+    const ONE: Self = Self::new_const(BabyBearElement::ONE);
+}
+
+impl FiniteField for BabyBearExtensionElement {
+    type BaseField = BabyBearElement;
+}
+
+impl Add for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        let coefficients = std::array::from_fn(|i| self.coefficients[i] + other.coefficients[i]);
+        Self { coefficients }
+    }
+}
+
+impl Sub for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        let coefficients = std::array::from_fn(|i| self.coefficients[i] - other.coefficients[i]);
+        Self { coefficients }
+    }
+}
+
+impl Mul for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        // x^4 = W
+        let [a0, a1, a2, a3] = self.coefficients;
+        let [b0, b1, b2, b3] = other.coefficients;
+        let w = Self::W;
+
+        let r0 = a0 * b0 + w * (a1 * b3 + a2 * b2 + a3 * b1);
+        let r1 = a0 * b1 + a1 * b0 + w * (a2 * b3 + a3 * b2);
+        let r2 = a0 * b2 + a1 * b1 + a2 * b0 + w * (a3 * b3);
+        let r3 = a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0;
+
+        Self::new([r0, r1, r2, r3])
+    }
+}
+
+impl Mul<BabyBearElement> for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: BabyBearElement) -> Self {
+        let coefficients = self.coefficients.map(|c| c * other);
+        Self { coefficients }
+    }
+}
+
+impl Div for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl Neg for BabyBearExtensionElement {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        let coefficients = self.coefficients.map(Neg::neg);
+        Self { coefficients }
+    }
+}
+
+impl AddAssign for BabyBearExtensionElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for BabyBearExtensionElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for BabyBearExtensionElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<BabyBearElement> for BabyBearExtensionElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: BabyBearElement) {
+        *self = *self * rhs;
+    }
+}
+
+impl ModPowU64 for BabyBearExtensionElement {
+    fn mod_pow_u64(&self, exponent: u64) -> Self {
+        let mut x = *self;
+        let mut result = Self::one();
+        let mut i = exponent;
+
+        while i > 0 {
+            if i & 1 == 1 {
+                result *= x;
+            }
+
+            x *= x;
+            i >>= 1;
+        }
+
+        result
+    }
+}
+
+impl ModPowU32 for BabyBearExtensionElement {
+    fn mod_pow_u32(&self, exp: u32) -> Self {
+        self.mod_pow_u64(exp.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::bfield_codec::BFieldCodec;
+    use crate::math::ntt::intt;
+    use crate::math::ntt::ntt;
+    use crate::math::polynomial::Polynomial;
+    use crate::prelude::BFieldElement;
+
+    #[test]
+    fn generator_generates_the_multiplicative_group() {
+        let group_order = u64::from(BabyBearElement::P) - 1;
+        assert_eq!(group_order, (1 << 27) * 3 * 5);
+        for prime_factor in [2, 3, 5] {
+            let power = BabyBearElement::generator().mod_pow(group_order / prime_factor);
+            assert!(!power.is_one());
+        }
+    }
+
+    #[test]
+    fn extension_modulus_is_irreducible() {
+        // Since 4 divides P-1, x^4 - W is irreducible if and only if W is not a
+        // square.
+        let w = BabyBearExtensionElement::W;
+        let legendre_symbol = w.mod_pow(u64::from(BabyBearElement::MAX / 2));
+        assert_eq!(-BabyBearElement::ONE, legendre_symbol);
+    }
+
+    #[test]
+    fn primitive_roots_of_unity_exist_exactly_for_divisors_of_the_group_order() {
+        for log_2_of_n in 0..=27 {
+            let n = 1 << log_2_of_n;
+            let root = BabyBearElement::primitive_root_of_unity(n).unwrap();
+            assert!(root.mod_pow(n).is_one());
+            assert!(n == 1 || !root.mod_pow(n / 2).is_one());
+        }
+        assert!(BabyBearElement::primitive_root_of_unity(15).is_some());
+        assert!(BabyBearElement::primitive_root_of_unity(0).is_none());
+        assert!(BabyBearElement::primitive_root_of_unity(1 << 28).is_none());
+        assert!(BabyBearElement::primitive_root_of_unity(7).is_none());
+    }
+
+    #[proptest]
+    fn arithmetic_agrees_with_integer_arithmetic(
+        #[strategy(arb())] a: BabyBearElement,
+        #[strategy(arb())] b: BabyBearElement,
+    ) {
+        let p = u64::from(BabyBearElement::P);
+        let (a_val, b_val) = (u64::from(a.value()), u64::from(b.value()));
+        prop_assert_eq!((a_val + b_val) % p, u64::from((a + b).value()));
+        prop_assert_eq!((a_val + p - b_val) % p, u64::from((a - b).value()));
+        prop_assert_eq!(a_val * b_val % p, u64::from((a * b).value()));
+        prop_assert_eq!((p - a_val) % p, u64::from((-a).value()));
+    }
+
+    #[proptest]
+    fn inverse_of_non_zero_element_is_inverse(
+        #[strategy(arb())]
+        #[filter(!#a.is_zero())]
+        a: BabyBearElement,
+    ) {
+        prop_assert!((a * a.inverse()).is_one());
+    }
+
+    #[proptest]
+    fn extension_field_multiplication_is_associative_and_distributive(
+        #[strategy(arb())] a: BabyBearExtensionElement,
+        #[strategy(arb())] b: BabyBearExtensionElement,
+        #[strategy(arb())] c: BabyBearExtensionElement,
+    ) {
+        prop_assert_eq!((a * b) * c, a * (b * c));
+        prop_assert_eq!(a * (b + c), a * b + a * c);
+        prop_assert_eq!(a * b, b * a);
+    }
+
+    #[proptest]
+    fn extension_field_inverse_is_inverse(
+        #[strategy(arb())]
+        #[filter(!#a.is_zero())]
+        a: BabyBearExtensionElement,
+    ) {
+        prop_assert!((a * a.inverse()).is_one());
+        prop_assert_eq!(a, a.inverse().inverse());
+    }
+
+    #[proptest]
+    fn scalar_multiplication_agrees_with_multiplication_by_lifted_scalar(
+        #[strategy(arb())] a: BabyBearExtensionElement,
+        #[strategy(arb())] scalar: BabyBearElement,
+    ) {
+        prop_assert_eq!(a * BabyBearExtensionElement::from(scalar), a * scalar);
+    }
+
+    #[proptest]
+    fn encoding_round_trips(
+        #[strategy(arb())] element: BabyBearElement,
+        #[strategy(arb())] ext_element: BabyBearExtensionElement,
+    ) {
+        prop_assert_eq!(element, *BabyBearElement::decode(&element.encode())?);
+        prop_assert_eq!(
+            ext_element,
+            *BabyBearExtensionElement::decode(&ext_element.encode())?
+        );
+        prop_assert_eq!(Some(4), BabyBearExtensionElement::static_length());
+    }
+
+    #[test]
+    fn decoding_out_of_range_element_fails() {
+        let encoding = [BFieldElement::new(BabyBearElement::P.into())];
+        assert!(BabyBearElement::decode(&encoding).is_err());
+        assert!(BabyBearElement::decode(&[]).is_err());
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_over_extension_field_agrees_with_evaluation(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<BabyBearExtensionElement>,
+    ) {
+        let n = 1 << log_2_of_n;
+        let omega = BabyBearElement::primitive_root_of_unity(n).unwrap();
+        let mut values = coefficients.clone();
+        ntt(&mut values, omega, log_2_of_n);
+
+        let polynomial = Polynomial::new(coefficients.clone());
+        let mut point = BabyBearElement::ONE;
+        for &value in &values {
+            prop_assert_eq!(polynomial.evaluate(point.into()), value);
+            point *= omega;
+        }
+
+        intt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 20)]
+    fn fast_polynomial_multiplication_agrees_with_naive_multiplication(
+        #[strategy(vec(arb(), 0..100))] a: Vec<BabyBearElement>,
+        #[strategy(vec(arb(), 0..100))] b: Vec<BabyBearElement>,
+    ) {
+        let a = Polynomial::new(a);
+        let b = Polynomial::new(b);
+        prop_assert_eq!(a.naive_multiply(&b), a.fast_multiply(&b));
+    }
+}
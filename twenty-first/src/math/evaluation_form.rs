@@ -0,0 +1,398 @@
+//! Polynomials represented by their values on a coset domain, and their
+//! [barycentric evaluation](EvaluationForm::evaluate) in arbitrary points.
+
+use std::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+use crate::math::traits::ModPowU64;
+
+/// A polynomial of degree less than the order of some coset domain, represented
+/// by its values on that domain. The coset domain is the group generated by
+/// `generator^i * offset`.
+///
+/// In contrast to [`Polynomial`], evaluating in a single point does not require
+/// interpolation first: [barycentric evaluation](Self::evaluate) runs in time
+/// linear in the order of the domain.
+///
+/// ```
+/// # use twenty_first::math::evaluation_form::EvaluationForm;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// # use twenty_first::prelude::*;
+/// let polynomial = Polynomial::new(bfe_vec![1, 2, 3]);
+/// let offset = bfe!(7);
+/// let generator = BFieldElement::primitive_root_of_unity(4).unwrap();
+/// let evaluation_form = EvaluationForm::from_polynomial(&polynomial, offset, generator, 4);
+///
+/// let point = xfe!([3, 1, 4]);
+/// let lifted_coefficients = polynomial.coefficients.iter().map(|c| c.lift()).collect();
+/// let lifted_polynomial = Polynomial::new(lifted_coefficients);
+/// assert_eq!(lifted_polynomial.evaluate(point), evaluation_form.evaluate(point));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvaluationForm<FF: FiniteField> {
+    offset: FF::BaseField,
+    generator: FF::BaseField,
+    values: Vec<FF>,
+}
+
+impl<FF: FiniteField> EvaluationForm<FF> {
+    /// The polynomial taking the given `values` on the coset domain generated by
+    /// `generator^i * offset`.
+    ///
+    /// It is the caller's responsibility to ensure that the order of the
+    /// `generator` equals the number of `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `offset` is zero.
+    pub fn new(offset: FF::BaseField, generator: FF::BaseField, values: Vec<FF>) -> Self {
+        assert!(
+            !offset.is_zero(),
+            "The offset of a coset domain must be non-zero."
+        );
+        Self {
+            offset,
+            generator,
+            values,
+        }
+    }
+
+    /// The [evaluation form](Self) of the given polynomial on the coset domain
+    /// generated by `generator^i * offset`, where `order` is the order of the
+    /// `generator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `offset` is zero, or under the same conditions as
+    /// [`Polynomial::fast_coset_evaluate`].
+    pub fn from_polynomial(
+        polynomial: &Polynomial<FF>,
+        offset: FF::BaseField,
+        generator: FF::BaseField,
+        order: usize,
+    ) -> Self
+    where
+        FF: Mul<FF::BaseField, Output = FF>,
+    {
+        let values = polynomial.fast_coset_evaluate(offset, generator, order);
+        Self::new(offset, generator, values)
+    }
+
+    pub fn offset(&self) -> FF::BaseField {
+        self.offset
+    }
+
+    pub fn generator(&self) -> FF::BaseField {
+        self.generator
+    }
+
+    pub fn values(&self) -> &[FF] {
+        &self.values
+    }
+
+    /// The coefficient form of the polynomial.
+    pub fn interpolate(&self) -> Polynomial<FF>
+    where
+        FF: Mul<FF::BaseField, Output = FF>,
+    {
+        Polynomial::fast_coset_interpolate(self.offset, self.generator, &self.values)
+    }
+
+    /// Evaluate the polynomial in the given point using the barycentric formula.
+    ///
+    /// The `point` may live in an extension of the field of the values; for
+    /// example, a codeword of [base field elements](crate::prelude::BFieldElement)
+    /// can be evaluated in an [extension field element](crate::prelude::XFieldElement).
+    ///
+    /// To evaluate many polynomials over the same domain in the same point,
+    /// compute the [`LagrangeBasis`] once and use [`LagrangeBasis::evaluate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no values, since the domain is empty then.
+    pub fn evaluate<P>(&self, point: P) -> P
+    where
+        P: FiniteField<BaseField = FF::BaseField> + Mul<FF, Output = P>,
+    {
+        let order = self.values.len();
+        LagrangeBasis::new(self.offset, self.generator, order, point).evaluate(&self.values)
+    }
+}
+
+/// The values of all Lagrange basis polynomials of some coset domain in a fixed
+/// point. The coset domain is the group generated by `generator^i * offset`.
+///
+/// The `i`th Lagrange basis polynomial is the unique polynomial of degree less
+/// than the order of the domain that is 1 on the `i`th domain element and 0 on
+/// all others.
+///
+/// Computing the basis takes time linear in the order of the domain and
+/// requires only one [batch inversion](FiniteField::batch_inversion). Once
+/// computed, any codeword on the same domain can be
+/// [evaluated](Self::evaluate) in the fixed point with an inner product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeBasis<FF: FiniteField> {
+    point: FF,
+    evaluations: Vec<FF>,
+}
+
+impl<FF: FiniteField> LagrangeBasis<FF> {
+    /// The values of the Lagrange basis polynomials of the coset domain generated
+    /// by `generator^i * offset` in the given `point`.
+    ///
+    /// It is the caller's responsibility to ensure that `order` is the order of
+    /// the `generator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `offset` is zero, or if the `order` is zero. The empty
+    /// domain has no Lagrange basis.
+    pub fn new(offset: FF::BaseField, generator: FF::BaseField, order: usize, point: FF) -> Self {
+        assert!(
+            !offset.is_zero(),
+            "The offset of a coset domain must be non-zero."
+        );
+        assert!(order > 0, "The order of a coset domain must be non-zero.");
+
+        let domain = (0..order)
+            .scan(offset, |domain_element, _| {
+                let current = *domain_element;
+                *domain_element *= generator;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(index) = domain.iter().position(|&d| FF::from_base_field(d) == point) {
+            let mut evaluations = vec![FF::ZERO; order];
+            evaluations[index] = FF::ONE;
+            return Self { point, evaluations };
+        }
+
+        // With n the order and c the offset, the domain's zerofier is Z(x) = x^n - c^n. The `i`th
+        // Lagrange basis polynomial is Z(x) / (Z'(d_i)·(x - d_i)), and Z'(d_i) = n·c^n / d_i.
+        let order_as_u64 = u64::try_from(order).unwrap();
+        let offset_to_the_order = offset.mod_pow_u64(order_as_u64);
+        let zerofier_in_point =
+            point.mod_pow_u64(order_as_u64) - FF::from_base_field(offset_to_the_order);
+        let mut common_factor = zerofier_in_point;
+        common_factor *= (FF::BaseField::from(order_as_u64) * offset_to_the_order).inverse();
+
+        let differences = domain
+            .iter()
+            .map(|&d| point - FF::from_base_field(d))
+            .collect();
+        let mut evaluations = FF::batch_inversion(differences);
+        for (evaluation, domain_element) in evaluations.iter_mut().zip(domain) {
+            *evaluation *= domain_element;
+            *evaluation *= common_factor;
+        }
+
+        Self { point, evaluations }
+    }
+
+    /// The point in which the Lagrange basis polynomials are evaluated.
+    pub fn point(&self) -> FF {
+        self.point
+    }
+
+    /// The values of the Lagrange basis polynomials in [the point](Self::point),
+    /// in the order of the domain.
+    pub fn evaluations(&self) -> &[FF] {
+        &self.evaluations
+    }
+
+    /// Evaluate the polynomial taking the values of the `codeword` on the domain
+    /// in [the point](Self::point).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the `codeword` does not equal the order of the
+    /// domain.
+    pub fn evaluate<V>(&self, codeword: &[V]) -> FF
+    where
+        V: Copy,
+        FF: Mul<V, Output = FF>,
+    {
+        assert_eq!(
+            self.evaluations.len(),
+            codeword.len(),
+            "The length of the codeword must equal the order of the domain."
+        );
+
+        self.evaluations
+            .iter()
+            .zip(codeword)
+            .fold(FF::ZERO, |acc, (&basis, &value)| acc + basis * value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::ConstOne;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::bfe;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::traits::PrimitiveRootOfUnity;
+    use crate::math::x_field_element::XFieldElement;
+
+    #[derive(Debug, Clone, test_strategy::Arbitrary)]
+    struct EvaluationFormToTest {
+        #[strategy(0_u32..10)]
+        log2_order: u32,
+
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+
+        #[strategy(proptest::collection::vec(arb(), 1_usize << #log2_order))]
+        b_field_values: Vec<BFieldElement>,
+
+        #[strategy(proptest::collection::vec(arb(), 1_usize << #log2_order))]
+        x_field_values: Vec<XFieldElement>,
+    }
+
+    fn lift(polynomial: &Polynomial<BFieldElement>) -> Polynomial<XFieldElement> {
+        Polynomial::new(polynomial.coefficients.iter().map(|c| c.lift()).collect())
+    }
+
+    impl EvaluationFormToTest {
+        fn generator(&self) -> BFieldElement {
+            BFieldElement::primitive_root_of_unity(1 << self.log2_order).unwrap()
+        }
+
+        fn b_field_evaluation_form(&self) -> EvaluationForm<BFieldElement> {
+            let values = self.b_field_values.clone();
+            EvaluationForm::new(self.offset, self.generator(), values)
+        }
+
+        fn x_field_evaluation_form(&self) -> EvaluationForm<XFieldElement> {
+            let values = self.x_field_values.clone();
+            EvaluationForm::new(self.offset, self.generator(), values)
+        }
+    }
+
+    #[proptest]
+    fn barycentric_evaluation_agrees_with_interpolation_then_evaluation(
+        test_case: EvaluationFormToTest,
+        #[strategy(arb())] b_field_point: BFieldElement,
+        #[strategy(arb())] x_field_point: XFieldElement,
+    ) {
+        let b_field_evaluation_form = test_case.b_field_evaluation_form();
+        let b_field_polynomial = b_field_evaluation_form.interpolate();
+        prop_assert_eq!(
+            b_field_polynomial.evaluate(b_field_point),
+            b_field_evaluation_form.evaluate(b_field_point)
+        );
+        prop_assert_eq!(
+            lift(&b_field_polynomial).evaluate(x_field_point),
+            b_field_evaluation_form.evaluate(x_field_point)
+        );
+
+        let x_field_evaluation_form = test_case.x_field_evaluation_form();
+        let x_field_polynomial = x_field_evaluation_form.interpolate();
+        prop_assert_eq!(
+            x_field_polynomial.evaluate(x_field_point),
+            x_field_evaluation_form.evaluate(x_field_point)
+        );
+    }
+
+    #[proptest]
+    fn evaluating_in_domain_element_gives_corresponding_value(
+        test_case: EvaluationFormToTest,
+        #[strategy(0_usize..1 << #test_case.log2_order)] index: usize,
+    ) {
+        let evaluation_form = test_case.b_field_evaluation_form();
+        let domain_element = test_case.offset * test_case.generator().mod_pow(index as u64);
+        let value = evaluation_form.evaluate(XFieldElement::from(domain_element));
+        prop_assert_eq!(XFieldElement::from(test_case.b_field_values[index]), value);
+    }
+
+    #[proptest]
+    fn conversion_from_polynomial_round_trips(
+        test_case: EvaluationFormToTest,
+        #[strategy(arb())] point: XFieldElement,
+    ) {
+        let polynomial = test_case.x_field_evaluation_form().interpolate();
+        let order = 1 << test_case.log2_order;
+        let evaluation_form = EvaluationForm::from_polynomial(
+            &polynomial,
+            test_case.offset,
+            test_case.generator(),
+            order,
+        );
+        prop_assert_eq!(test_case.x_field_evaluation_form(), evaluation_form.clone());
+        prop_assert_eq!(polynomial.evaluate(point), evaluation_form.evaluate(point));
+    }
+
+    #[proptest]
+    fn lagrange_basis_sums_to_one_and_can_be_reused(
+        test_case: EvaluationFormToTest,
+        #[strategy(arb())] point: XFieldElement,
+    ) {
+        let order = 1 << test_case.log2_order;
+        let basis = LagrangeBasis::new(test_case.offset, test_case.generator(), order, point);
+        prop_assert_eq!(point, basis.point());
+        prop_assert_eq!(
+            XFieldElement::ONE,
+            basis.evaluations().iter().copied().sum()
+        );
+
+        let b_field_evaluation_form = test_case.b_field_evaluation_form();
+        let x_field_evaluation_form = test_case.x_field_evaluation_form();
+        prop_assert_eq!(
+            b_field_evaluation_form.evaluate(point),
+            basis.evaluate(&test_case.b_field_values)
+        );
+        prop_assert_eq!(
+            x_field_evaluation_form.evaluate(point),
+            basis.evaluate(&test_case.x_field_values)
+        );
+    }
+
+    #[test]
+    fn lagrange_basis_of_domain_element_is_unit_vector() {
+        let generator = BFieldElement::primitive_root_of_unity(8).unwrap();
+        let point = bfe!(3) * generator.mod_pow(5);
+        let basis = LagrangeBasis::new(bfe!(3), generator, 8, point);
+
+        let mut expected = vec![bfe!(0); 8];
+        expected[5] = bfe!(1);
+        assert_eq!(expected, basis.evaluations());
+    }
+
+    #[test]
+    #[should_panic(expected = "The length of the codeword must equal the order of the domain.")]
+    fn evaluating_codeword_of_wrong_length_panics() {
+        let generator = BFieldElement::primitive_root_of_unity(4).unwrap();
+        let basis = LagrangeBasis::new(bfe!(1), generator, 4, bfe!(42));
+        basis.evaluate(&[bfe!(1), bfe!(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The offset of a coset domain must be non-zero.")]
+    fn zero_offset_panics() {
+        let generator = BFieldElement::primitive_root_of_unity(4).unwrap();
+        EvaluationForm::new(bfe!(0), generator, vec![bfe!(1); 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The order of a coset domain must be non-zero.")]
+    fn lagrange_basis_of_empty_domain_panics() {
+        LagrangeBasis::new(bfe!(1), bfe!(1), 0, bfe!(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "The order of a coset domain must be non-zero.")]
+    fn evaluating_empty_evaluation_form_panics() {
+        let evaluation_form = EvaluationForm::new(bfe!(1), bfe!(1), Vec::<BFieldElement>::new());
+        evaluation_form.evaluate(bfe!(42));
+    }
+}
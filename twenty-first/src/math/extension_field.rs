@@ -0,0 +1,708 @@
+//! Extensions of arbitrary degree of the [base field](BFieldElement).
+//!
+//! The [extension field element](ExtensionField) is generic over its degree and
+//! its [modulus](Modulus), allowing protocols to trade speed for soundness. For
+//! the cubic extension defined by the Shah polynomial, prefer the specialised
+//! [`XFieldElement`], which is considerably faster. Conversions between the two
+//! are available through [`ShahModulus`].
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use num_traits::One;
+use num_traits::Zero;
+use rand::Rng;
+use rand_distr::Distribution;
+use rand_distr::Standard;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::bfield_codec::BFieldCodec;
+use crate::math::bfield_codec::BFieldCodecError;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::CyclicGroupGenerator;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+use crate::math::traits::ModPowU32;
+use crate::math::traits::ModPowU64;
+use crate::math::traits::PrimitiveRootOfUnity;
+use crate::math::x_field_element::XFieldElement;
+use crate::math::x_field_element::EXTENSION_DEGREE;
+
+/// The modulus of an [extension field](ExtensionField), a monic polynomial
+/// that is irreducible over the [base field](BFieldElement).
+///
+/// Implementors are marker types. The modulus' degree is the number of
+/// [coefficients](Self::COEFFICIENTS), which must match the degree of any
+/// extension field it is used with.
+pub trait Modulus: Debug + Copy + Clone + PartialEq + Eq + Hash + Send + Sync + 'static {
+    /// The coefficients of the modulus without its leading 1, ordered from the
+    /// constant term upwards. For example, x³ - x + 1 is represented as
+    /// `[1, -1, 0]`.
+    const COEFFICIENTS: &'static [BFieldElement];
+
+    /// The modulus as a polynomial.
+    fn polynomial() -> Polynomial<BFieldElement> {
+        let mut coefficients = Self::COEFFICIENTS.to_vec();
+        coefficients.push(BFieldElement::ONE);
+        Polynomial::new(coefficients)
+    }
+}
+
+/// The Shah polynomial x³ - x + 1, the modulus of [`XFieldElement`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ShahModulus;
+
+impl Modulus for ShahModulus {
+    const COEFFICIENTS: &'static [BFieldElement] = &[
+        BFieldElement::ONE,
+        BFieldElement::new(BFieldElement::MAX),
+        BFieldElement::ZERO,
+    ];
+}
+
+/// The polynomial x⁴ - 7, which is irreducible since 7 is not a square in the
+/// base field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct QuarticModulus;
+
+impl Modulus for QuarticModulus {
+    const COEFFICIENTS: &'static [BFieldElement] = &[
+        BFieldElement::new(BFieldElement::P - 7),
+        BFieldElement::ZERO,
+        BFieldElement::ZERO,
+        BFieldElement::ZERO,
+    ];
+}
+
+/// The polynomial x⁵ - 3, which is irreducible since 3 is not a fifth power in
+/// the base field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct QuinticModulus;
+
+impl Modulus for QuinticModulus {
+    const COEFFICIENTS: &'static [BFieldElement] = &[
+        BFieldElement::new(BFieldElement::P - 3),
+        BFieldElement::ZERO,
+        BFieldElement::ZERO,
+        BFieldElement::ZERO,
+        BFieldElement::ZERO,
+    ];
+}
+
+/// An element of the quartic extension defined by [`QuarticModulus`].
+pub type QuarticExtensionElement = ExtensionField<4, QuarticModulus>;
+
+/// An element of the quintic extension defined by [`QuinticModulus`].
+pub type QuinticExtensionElement = ExtensionField<5, QuinticModulus>;
+
+/// An element of the degree-`N` extension of the [base field](BFieldElement)
+/// defined by the [modulus](Modulus) `M`.
+///
+/// The coefficients are ordered from the constant term upwards.
+///
+/// ```
+/// # use twenty_first::math::extension_field::QuinticExtensionElement;
+/// # use num_traits::ConstOne;
+/// # use twenty_first::math::traits::Inverse;
+/// # use twenty_first::prelude::*;
+/// let a = QuinticExtensionElement::new(bfe_array![1, 2, 3, 4, 5]);
+/// assert_eq!(QuinticExtensionElement::ONE, a * a.inverse());
+/// ```
+///
+/// The degree of the modulus must match `N`, which is checked at compile time:
+///
+/// ```compile_fail
+/// # use twenty_first::math::extension_field::ExtensionField;
+/// # use twenty_first::math::extension_field::ShahModulus;
+/// # use twenty_first::prelude::*;
+/// let a = ExtensionField::<4, ShahModulus>::new(bfe_array![1, 2, 3, 4]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct ExtensionField<const N: usize, M: Modulus> {
+    pub coefficients: [BFieldElement; N],
+    modulus: PhantomData<M>,
+}
+
+impl<const N: usize, M: Modulus> ExtensionField<N, M> {
+    const MODULUS_HAS_DEGREE_N: () = assert!(
+        M::COEFFICIENTS.len() == N,
+        "the degree of the modulus must equal the degree of the extension"
+    );
+
+    pub const fn new(coefficients: [BFieldElement; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MODULUS_HAS_DEGREE_N;
+        Self {
+            coefficients,
+            modulus: PhantomData,
+        }
+    }
+
+    pub const fn new_const(element: BFieldElement) -> Self {
+        let mut coefficients = [BFieldElement::ZERO; N];
+        coefficients[0] = element;
+        Self::new(coefficients)
+    }
+
+    /// The element as a [base field element](BFieldElement) if it lies in the
+    /// base field, [`None`] otherwise.
+    pub fn unlift(&self) -> Option<BFieldElement> {
+        let (c0, rest) = self.coefficients.split_first()?;
+        rest.iter().all(Zero::is_zero).then_some(*c0)
+    }
+
+    /// Multiply by the indeterminate x and reduce by the modulus.
+    #[inline]
+    fn mul_by_x(coefficients: [BFieldElement; N]) -> [BFieldElement; N] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MODULUS_HAS_DEGREE_N;
+        let Some(&top) = coefficients.last() else {
+            return coefficients;
+        };
+
+        let mut shifted = [BFieldElement::ZERO; N];
+        shifted[1..].copy_from_slice(&coefficients[..N - 1]);
+        for (s, &m) in shifted.iter_mut().zip(M::COEFFICIENTS) {
+            *s -= top * m;
+        }
+        shifted
+    }
+}
+
+impl<const N: usize, M: Modulus> From<BFieldElement> for ExtensionField<N, M> {
+    fn from(element: BFieldElement) -> Self {
+        Self::new_const(element)
+    }
+}
+
+impl<const N: usize, M: Modulus> From<u64> for ExtensionField<N, M> {
+    fn from(value: u64) -> Self {
+        Self::new_const(value.into())
+    }
+}
+
+impl<const N: usize, M: Modulus> From<[BFieldElement; N]> for ExtensionField<N, M> {
+    fn from(coefficients: [BFieldElement; N]) -> Self {
+        Self::new(coefficients)
+    }
+}
+
+impl<const N: usize, M: Modulus> From<ExtensionField<N, M>> for Polynomial<BFieldElement> {
+    fn from(element: ExtensionField<N, M>) -> Self {
+        Self::new(element.coefficients.to_vec())
+    }
+}
+
+impl<const N: usize, M: Modulus> From<Polynomial<BFieldElement>> for ExtensionField<N, M> {
+    fn from(poly: Polynomial<BFieldElement>) -> Self {
+        let (_, remainder) = poly.naive_divide(&M::polynomial());
+        let mut coefficients = [BFieldElement::ZERO; N];
+        for (c, &r) in coefficients.iter_mut().zip(&remainder.coefficients) {
+            *c = r;
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl From<XFieldElement> for ExtensionField<EXTENSION_DEGREE, ShahModulus> {
+    fn from(element: XFieldElement) -> Self {
+        Self::new(element.coefficients)
+    }
+}
+
+impl From<ExtensionField<EXTENSION_DEGREE, ShahModulus>> for XFieldElement {
+    fn from(element: ExtensionField<EXTENSION_DEGREE, ShahModulus>) -> Self {
+        Self::new(element.coefficients)
+    }
+}
+
+impl<const N: usize, M: Modulus> Default for ExtensionField<N, M> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize, M: Modulus> Serialize for ExtensionField<N, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.coefficients)
+    }
+}
+
+impl<'de, const N: usize, M: Modulus> Deserialize<'de> for ExtensionField<N, M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let coefficients = Vec::<BFieldElement>::deserialize(deserializer)?;
+        let num_coefficients = coefficients.len();
+        let coefficients = coefficients.try_into().map_err(|_| {
+            let expected = format!("{N} coefficients");
+            serde::de::Error::invalid_length(num_coefficients, &expected.as_str())
+        })?;
+        Ok(Self::new(coefficients))
+    }
+}
+
+impl<const N: usize, M: Modulus> BFieldCodec for ExtensionField<N, M> {
+    type Error = BFieldCodecError;
+
+    fn decode(sequence: &[BFieldElement]) -> Result<Box<Self>, Self::Error> {
+        if sequence.is_empty() {
+            return Err(Self::Error::EmptySequence);
+        }
+        let coefficients = sequence.try_into().map_err(|_| match sequence.len() {
+            len if len < N => Self::Error::SequenceTooShort,
+            _ => Self::Error::SequenceTooLong,
+        })?;
+        Ok(Box::new(Self::new(coefficients)))
+    }
+
+    fn encode(&self) -> Vec<BFieldElement> {
+        self.coefficients.to_vec()
+    }
+
+    fn static_length() -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<'a, const N: usize, M: Modulus> Arbitrary<'a> for ExtensionField<N, M> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(Self::new)
+    }
+}
+
+impl<const N: usize, M: Modulus> Distribution<ExtensionField<N, M>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ExtensionField<N, M> {
+        ExtensionField::new(std::array::from_fn(|_| rng.gen()))
+    }
+}
+
+impl<const N: usize, M: Modulus> Sum for ExtensionField<N, M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, element| acc + element)
+    }
+}
+
+impl<const N: usize, M: Modulus> Inverse for ExtensionField<N, M> {
+    fn inverse(&self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "Cannot invert the zero element in the extension field."
+        );
+
+        // Since the modulus is irreducible, the gcd is 1 and the Bézout
+        // coefficient of `self` is its inverse.
+        let (_, inverse, _) = Polynomial::xgcd((*self).into(), M::polynomial());
+        inverse.into()
+    }
+}
+
+impl<const N: usize, M: Modulus> PrimitiveRootOfUnity for ExtensionField<N, M> {
+    fn primitive_root_of_unity(n: u64) -> Option<Self> {
+        BFieldElement::primitive_root_of_unity(n).map(Self::new_const)
+    }
+
+    fn primitive_root_of_unity_of_any_order(n: u64) -> Option<Self> {
+        BFieldElement::primitive_root_of_unity_of_any_order(n).map(Self::new_const)
+    }
+}
+
+impl<const N: usize, M: Modulus> CyclicGroupGenerator for ExtensionField<N, M> {
+    fn get_cyclic_group_elements(&self, max: Option<usize>) -> Vec<Self> {
+        let mut val = *self;
+        let mut ret: Vec<Self> = vec![Self::one()];
+
+        loop {
+            ret.push(val);
+            val *= *self;
+            if val.is_one() || max.is_some_and(|max_len| ret.len() >= max_len) {
+                break;
+            }
+        }
+        ret
+    }
+}
+
+impl<const N: usize, M: Modulus> Display for ExtensionField<N, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(element) = self.unlift() {
+            return write!(f, "{element}_ext");
+        }
+
+        write!(f, "(")?;
+        for (i, c) in self.coefficients.iter().enumerate().rev() {
+            match i {
+                0 => write!(f, "{c}")?,
+                1 => write!(f, "{c}·x + ")?,
+                _ => write!(f, "{c}·x^{i} + ")?,
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl<const N: usize, M: Modulus> Zero for ExtensionField<N, M> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self == &Self::ZERO
+    }
+}
+
+impl<const N: usize, M: Modulus> ConstZero for ExtensionField<N, M> {
+    const ZERO: Self = Self::new_const(BFieldElement::ZERO);
+}
+
+impl<const N: usize, M: Modulus> One for ExtensionField<N, M> {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self == &Self::ONE
+    }
+}
+
+impl<const N: usize, M: Modulus> ConstOne for ExtensionField<N, M> {
+    const ONE: Self = Self::new_const(BFieldElement::ONE);
+}
+
+impl<const N: usize, M: Modulus> FiniteField for ExtensionField<N, M> {
+    type BaseField = BFieldElement;
+}
+
+impl<const N: usize, M: Modulus> Add for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        let coefficients = std::array::from_fn(|i| self.coefficients[i] + other.coefficients[i]);
+        Self::new(coefficients)
+    }
+}
+
+impl<const N: usize, M: Modulus> Sub for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        let coefficients = std::array::from_fn(|i| self.coefficients[i] - other.coefficients[i]);
+        Self::new(coefficients)
+    }
+}
+
+impl<const N: usize, M: Modulus> Mul for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        // Horner's method: multiply the accumulator by x, then add the
+        // appropriate multiple of `other`
+        let mut coefficients = [BFieldElement::ZERO; N];
+        for &a in self.coefficients.iter().rev() {
+            coefficients = Self::mul_by_x(coefficients);
+            for (c, &b) in coefficients.iter_mut().zip(&other.coefficients) {
+                *c += a * b;
+            }
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<const N: usize, M: Modulus> Mul<BFieldElement> for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: BFieldElement) -> Self {
+        Self::new(self.coefficients.map(|c| c * other))
+    }
+}
+
+impl<const N: usize, M: Modulus> Div for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl<const N: usize, M: Modulus> Neg for ExtensionField<N, M> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.coefficients.map(Neg::neg))
+    }
+}
+
+impl<const N: usize, M: Modulus> AddAssign for ExtensionField<N, M> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, M: Modulus> SubAssign for ExtensionField<N, M> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, M: Modulus> MulAssign for ExtensionField<N, M> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, M: Modulus> MulAssign<BFieldElement> for ExtensionField<N, M> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: BFieldElement) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, M: Modulus> ModPowU64 for ExtensionField<N, M> {
+    fn mod_pow_u64(&self, exponent: u64) -> Self {
+        let mut x = *self;
+        let mut result = Self::one();
+        let mut i = exponent;
+
+        while i > 0 {
+            if i & 1 == 1 {
+                result *= x;
+            }
+
+            x *= x;
+            i >>= 1;
+        }
+
+        result
+    }
+}
+
+impl<const N: usize, M: Modulus> ModPowU32 for ExtensionField<N, M> {
+    fn mod_pow_u32(&self, exp: u32) -> Self {
+        self.mod_pow_u64(exp.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::ntt::intt;
+    use crate::math::ntt::ntt;
+
+    type ShahExtensionElement = ExtensionField<EXTENSION_DEGREE, ShahModulus>;
+
+    /// Rabin's irreducibility test: a polynomial m of degree n is irreducible
+    /// if and only if x^(P^n) ≡ x (mod m), and gcd(x^(P^(n/q)) - x, m) = 1 for
+    /// every prime divisor q of n.
+    fn modulus_is_irreducible<const N: usize, M: Modulus>(prime_divisors_of_n: &[usize]) -> bool {
+        let mut x = [BFieldElement::ZERO; N];
+        x[1] = BFieldElement::ONE;
+        let x = ExtensionField::<N, M>::new(x);
+
+        let iterated_frobenius = |num_iterations| {
+            (0..num_iterations).fold(x, |acc, _| acc.mod_pow_u64(BFieldElement::P))
+        };
+        let coprime_to_modulus = |element: ExtensionField<N, M>| {
+            let (gcd, _, _) = Polynomial::xgcd(element.into(), M::polynomial());
+            gcd.degree() == 0
+        };
+
+        iterated_frobenius(N) == x
+            && prime_divisors_of_n
+                .iter()
+                .all(|q| coprime_to_modulus(iterated_frobenius(N / q) - x))
+    }
+
+    #[test]
+    fn moduli_are_irreducible() {
+        assert!(modulus_is_irreducible::<3, ShahModulus>(&[3]));
+        assert!(modulus_is_irreducible::<4, QuarticModulus>(&[2]));
+        assert!(modulus_is_irreducible::<5, QuinticModulus>(&[5]));
+    }
+
+    #[test]
+    fn reducible_modulus_is_recognized() {
+        // x⁴ - 16 = (x - 2)(x + 2)(x² + 4)
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        struct ReducibleModulus;
+
+        impl Modulus for ReducibleModulus {
+            const COEFFICIENTS: &'static [BFieldElement] = &[
+                BFieldElement::new(BFieldElement::P - 16),
+                BFieldElement::ZERO,
+                BFieldElement::ZERO,
+                BFieldElement::ZERO,
+            ];
+        }
+
+        assert!(!modulus_is_irreducible::<4, ReducibleModulus>(&[2]));
+        assert!(!ReducibleModulus::polynomial().is_irreducible());
+    }
+
+    #[test]
+    fn moduli_are_irreducible_according_to_polynomial_irreducibility_test() {
+        assert!(ShahModulus::polynomial().is_irreducible());
+        assert!(QuarticModulus::polynomial().is_irreducible());
+        assert!(QuinticModulus::polynomial().is_irreducible());
+    }
+
+    #[test]
+    fn shah_modulus_is_the_shah_polynomial() {
+        assert_eq!(XFieldElement::shah_polynomial(), ShahModulus::polynomial());
+    }
+
+    #[proptest]
+    fn cubic_extension_agrees_with_x_field_element(
+        #[strategy(arb())] a: XFieldElement,
+        #[strategy(arb())] b: XFieldElement,
+    ) {
+        let (ext_a, ext_b) = (ShahExtensionElement::from(a), ShahExtensionElement::from(b));
+        prop_assert_eq!(a + b, (ext_a + ext_b).into());
+        prop_assert_eq!(a - b, (ext_a - ext_b).into());
+        prop_assert_eq!(a * b, (ext_a * ext_b).into());
+        prop_assert_eq!(-a, (-ext_a).into());
+        if !b.is_zero() {
+            prop_assert_eq!(b.inverse(), ext_b.inverse().into());
+        }
+    }
+
+    #[proptest]
+    fn quartic_multiplication_agrees_with_polynomial_multiplication(
+        #[strategy(arb())] a: QuarticExtensionElement,
+        #[strategy(arb())] b: QuarticExtensionElement,
+    ) {
+        let product = Polynomial::from(a) * Polynomial::from(b);
+        prop_assert_eq!(QuarticExtensionElement::from(product), a * b);
+    }
+
+    #[proptest]
+    fn quintic_multiplication_is_associative_and_distributive(
+        #[strategy(arb())] a: QuinticExtensionElement,
+        #[strategy(arb())] b: QuinticExtensionElement,
+        #[strategy(arb())] c: QuinticExtensionElement,
+    ) {
+        prop_assert_eq!((a * b) * c, a * (b * c));
+        prop_assert_eq!(a * (b + c), a * b + a * c);
+        prop_assert_eq!(a * b, b * a);
+    }
+
+    #[proptest]
+    fn inverse_is_inverse(
+        #[strategy(arb())]
+        #[filter(!#a.is_zero())]
+        a: QuarticExtensionElement,
+        #[strategy(arb())]
+        #[filter(!#b.is_zero())]
+        b: QuinticExtensionElement,
+    ) {
+        prop_assert!((a * a.inverse()).is_one());
+        prop_assert!((b * b.inverse()).is_one());
+        prop_assert_eq!(b, b.inverse().inverse());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot invert the zero element in the extension field.")]
+    fn inverting_zero_panics() {
+        let _ = QuinticExtensionElement::ZERO.inverse();
+    }
+
+    #[proptest]
+    fn scalar_multiplication_agrees_with_multiplication_by_lifted_scalar(
+        #[strategy(arb())] a: QuinticExtensionElement,
+        #[strategy(arb())] scalar: BFieldElement,
+    ) {
+        prop_assert_eq!(a * QuinticExtensionElement::from(scalar), a * scalar);
+    }
+
+    #[proptest]
+    fn fermat_little_theorem_holds_in_base_field_embedding(#[strategy(arb())] a: BFieldElement) {
+        let lifted = QuarticExtensionElement::from(a);
+        prop_assert_eq!(lifted, lifted.mod_pow_u64(BFieldElement::P));
+        prop_assert_eq!(Some(a), lifted.unlift());
+    }
+
+    #[proptest]
+    fn encoding_round_trips(#[strategy(arb())] element: QuinticExtensionElement) {
+        prop_assert_eq!(
+            element,
+            *QuinticExtensionElement::decode(&element.encode())?
+        );
+        prop_assert_eq!(Some(5), QuinticExtensionElement::static_length());
+    }
+
+    #[test]
+    fn decoding_sequence_of_wrong_length_fails() {
+        let too_short = QuarticExtensionElement::decode(&[BFieldElement::ONE; 3]);
+        assert!(matches!(too_short, Err(BFieldCodecError::SequenceTooShort)));
+        let too_long = QuarticExtensionElement::decode(&[BFieldElement::ONE; 5]);
+        assert!(matches!(too_long, Err(BFieldCodecError::SequenceTooLong)));
+        let empty = QuarticExtensionElement::decode(&[]);
+        assert!(matches!(empty, Err(BFieldCodecError::EmptySequence)));
+    }
+
+    #[proptest]
+    fn serialization_round_trips(#[strategy(arb())] element: QuarticExtensionElement) {
+        let serialized = serde_json::to_string(&element)?;
+        prop_assert_eq!(element, serde_json::from_str(&serialized)?);
+        let wrong_length = serde_json::from_str::<QuinticExtensionElement>(&serialized);
+        prop_assert!(wrong_length.is_err());
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_over_quintic_extension_agrees_with_evaluation(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<QuinticExtensionElement>,
+    ) {
+        let n = 1 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let mut values = coefficients.clone();
+        ntt(&mut values, omega, log_2_of_n);
+
+        let polynomial = Polynomial::new(coefficients.clone());
+        let mut point = BFieldElement::ONE;
+        for &value in &values {
+            prop_assert_eq!(polynomial.evaluate(point.into()), value);
+            point *= omega;
+        }
+
+        intt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[test]
+    fn display_lists_coefficients_from_highest_to_lowest() {
+        let element = QuarticExtensionElement::new(crate::bfe_array![1, 2, 3, 4]);
+        assert_eq!("(4·x^3 + 3·x^2 + 2·x + 1)", element.to_string());
+        assert_eq!("7_ext", QuarticExtensionElement::from(7).to_string());
+    }
+}
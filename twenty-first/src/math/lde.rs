@@ -0,0 +1,301 @@
+//! Low-degree extension of columns in evaluation form.
+//!
+//! A column of values on some [domain](ArithmeticDomain) uniquely defines a
+//! polynomial of degree less than the length of that domain. Its [low-degree
+//! extension](lde) are the values of the same polynomial on a larger domain.
+
+use rayon::prelude::*;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::ntt::NttPlan;
+use crate::math::traits::FiniteField;
+
+/// A coset of a multiplicative subgroup of power-of-two order, _i.e._, the points
+/// `offset·generator^i` for `i` in `0..length`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ArithmeticDomain<B: FiniteField = BFieldElement> {
+    offset: B,
+    generator: B,
+    length: usize,
+}
+
+impl<B: FiniteField> ArithmeticDomain<B> {
+    /// The subgroup of the given `length`, generated by the
+    /// [canonical](crate::math::traits::PrimitiveRootOfUnity) primitive root of
+    /// unity of that order.
+    ///
+    /// Returns [`None`] if no such subgroup exists, in particular if `length` is
+    /// not a power of two.
+    pub fn of_length(length: usize) -> Option<Self> {
+        if !length.is_power_of_two() {
+            return None;
+        }
+        let generator = B::primitive_root_of_unity(length.try_into().ok()?)?;
+        let offset = B::ONE;
+
+        Some(Self {
+            offset,
+            generator,
+            length,
+        })
+    }
+
+    /// The coset of `self` with the given `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `offset` is zero.
+    #[must_use]
+    pub fn with_offset(mut self, offset: B) -> Self {
+        assert!(
+            !offset.is_zero(),
+            "The offset of a domain must be non-zero."
+        );
+        self.offset = offset;
+        self
+    }
+
+    pub fn offset(&self) -> B {
+        self.offset
+    }
+
+    pub fn generator(&self) -> B {
+        self.generator
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// All points of the domain, in order.
+    pub fn domain_values(&self) -> Vec<B> {
+        let mut values = Vec::with_capacity(self.length);
+        let mut value = self.offset;
+        for _ in 0..self.length {
+            values.push(value);
+            value *= self.generator;
+        }
+        values
+    }
+
+    fn ntt_plan(&self) -> std::sync::Arc<NttPlan<B>> {
+        NttPlan::cached(self.generator, self.length.ilog2())
+    }
+}
+
+/// The low-degree extension of a `column` of values on the `trace_domain` to the
+/// (larger) `fri_domain`.
+///
+/// The `column` is interpolated with an [INTT](NttPlan::inverse_coset),
+/// zero-padded, and evaluated with an [NTT](NttPlan::forward_coset), all in one
+/// buffer. The result is the same as
+/// [interpolating](crate::prelude::Polynomial::fast_coset_interpolate) and then
+/// [evaluating](crate::prelude::Polynomial::fast_coset_evaluate), but no
+/// intermediate polynomial is allocated.
+///
+/// ```
+/// # use twenty_first::math::lde::lde;
+/// # use twenty_first::math::lde::ArithmeticDomain;
+/// # use twenty_first::prelude::*;
+/// let trace_domain = ArithmeticDomain::of_length(4).unwrap();
+/// let fri_domain = ArithmeticDomain::of_length(16).unwrap().with_offset(bfe!(7));
+/// let column = bfe_vec![1, 2, 3, 4];
+/// let extension = lde(&column, trace_domain, fri_domain);
+///
+/// let polynomial = Polynomial::fast_coset_interpolate(bfe!(1), trace_domain.generator(), &column);
+/// for (point, value) in fri_domain.domain_values().into_iter().zip(extension) {
+///     assert_eq!(polynomial.evaluate(point), value);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if
+/// - the length of the `column` does not equal the length of the `trace_domain`,
+/// - the `fri_domain` is shorter than the `trace_domain`.
+pub fn lde<FF: FiniteField>(
+    column: &[FF],
+    trace_domain: ArithmeticDomain<FF::BaseField>,
+    fri_domain: ArithmeticDomain<FF::BaseField>,
+) -> Vec<FF> {
+    assert_eq!(
+        trace_domain.length,
+        column.len(),
+        "The length of the column must equal the length of the trace domain."
+    );
+    assert!(
+        trace_domain.length <= fri_domain.length,
+        "The FRI domain must not be shorter than the trace domain."
+    );
+
+    let mut extension = Vec::with_capacity(fri_domain.length);
+    extension.extend_from_slice(column);
+    trace_domain
+        .ntt_plan()
+        .inverse_coset(&mut extension, trace_domain.offset);
+    extension.resize(fri_domain.length, FF::ZERO);
+    fri_domain
+        .ntt_plan()
+        .forward_coset(&mut extension, fri_domain.offset);
+
+    extension
+}
+
+/// The [low-degree extension](lde) of every column. The columns are extended in
+/// parallel.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`lde`], for any of the columns.
+pub fn batch_lde<FF: FiniteField>(
+    columns: &[Vec<FF>],
+    trace_domain: ArithmeticDomain<FF::BaseField>,
+    fri_domain: ArithmeticDomain<FF::BaseField>,
+) -> Vec<Vec<FF>> {
+    columns
+        .par_iter()
+        .map(|column| lde(column, trace_domain, fri_domain))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::ConstOne;
+    use num_traits::Zero;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::bfe;
+    use crate::math::polynomial::Polynomial;
+    use crate::math::x_field_element::XFieldElement;
+
+    #[derive(Debug, Clone, test_strategy::Arbitrary)]
+    struct LdeToTest {
+        #[strategy(0_u32..8)]
+        log2_trace_length: u32,
+
+        #[strategy(#log2_trace_length..10)]
+        log2_fri_length: u32,
+
+        #[strategy(arb())]
+        #[filter(!#trace_offset.is_zero())]
+        trace_offset: BFieldElement,
+
+        #[strategy(arb())]
+        #[filter(!#fri_offset.is_zero())]
+        fri_offset: BFieldElement,
+    }
+
+    impl LdeToTest {
+        fn trace_domain(&self) -> ArithmeticDomain {
+            ArithmeticDomain::of_length(1 << self.log2_trace_length)
+                .unwrap()
+                .with_offset(self.trace_offset)
+        }
+
+        fn fri_domain(&self) -> ArithmeticDomain {
+            ArithmeticDomain::of_length(1 << self.log2_fri_length)
+                .unwrap()
+                .with_offset(self.fri_offset)
+        }
+
+        fn interpolate_then_evaluate<FF>(&self, column: &[FF]) -> Vec<FF>
+        where
+            FF: FiniteField<BaseField = BFieldElement> + std::ops::Mul<BFieldElement, Output = FF>,
+        {
+            let trace_domain = self.trace_domain();
+            let fri_domain = self.fri_domain();
+            Polynomial::fast_coset_interpolate(trace_domain.offset, trace_domain.generator, column)
+                .fast_coset_evaluate(fri_domain.offset, fri_domain.generator, fri_domain.length)
+        }
+    }
+
+    #[test]
+    fn domain_of_non_power_of_two_length_does_not_exist() {
+        assert!(ArithmeticDomain::<BFieldElement>::of_length(0).is_none());
+        assert!(ArithmeticDomain::<BFieldElement>::of_length(3).is_none());
+        assert!(ArithmeticDomain::<BFieldElement>::of_length(1 << 33).is_none());
+    }
+
+    #[test]
+    fn domain_values_are_offset_powers_of_generator() {
+        let domain = ArithmeticDomain::of_length(8).unwrap().with_offset(bfe!(3));
+        let values = domain.domain_values();
+        assert_eq!(8, values.len());
+        assert_eq!(bfe!(3), values[0]);
+        for (value, next_value) in values.iter().zip(values.iter().skip(1)) {
+            assert_eq!(*value * domain.generator(), *next_value);
+        }
+        assert_eq!(BFieldElement::ONE, domain.generator().mod_pow(8));
+    }
+
+    #[proptest]
+    fn lde_of_b_field_column_agrees_with_interpolate_then_evaluate(
+        test_case: LdeToTest,
+        #[strategy(vec(arb(), 1_usize << #test_case.log2_trace_length))] column: Vec<BFieldElement>,
+    ) {
+        let extension = lde(&column, test_case.trace_domain(), test_case.fri_domain());
+        prop_assert_eq!(test_case.interpolate_then_evaluate(&column), extension);
+    }
+
+    #[proptest]
+    fn lde_of_x_field_column_agrees_with_interpolate_then_evaluate(
+        test_case: LdeToTest,
+        #[strategy(vec(arb(), 1_usize << #test_case.log2_trace_length))] column: Vec<XFieldElement>,
+    ) {
+        let extension = lde(&column, test_case.trace_domain(), test_case.fri_domain());
+        prop_assert_eq!(test_case.interpolate_then_evaluate(&column), extension);
+    }
+
+    #[proptest]
+    fn lde_to_same_domain_is_identity(
+        test_case: LdeToTest,
+        #[strategy(vec(arb(), 1_usize << #test_case.log2_trace_length))] column: Vec<XFieldElement>,
+    ) {
+        let trace_domain = test_case.trace_domain();
+        prop_assert_eq!(column.clone(), lde(&column, trace_domain, trace_domain));
+    }
+
+    #[proptest(cases = 20)]
+    fn batch_lde_agrees_with_lde(
+        test_case: LdeToTest,
+        #[strategy(vec(vec(arb(), 1_usize << #test_case.log2_trace_length), 0..5))] columns: Vec<
+            Vec<BFieldElement>,
+        >,
+    ) {
+        let trace_domain = test_case.trace_domain();
+        let fri_domain = test_case.fri_domain();
+        let extensions = batch_lde(&columns, trace_domain, fri_domain);
+        prop_assert_eq!(columns.len(), extensions.len());
+        for (column, extension) in columns.iter().zip(extensions) {
+            prop_assert_eq!(lde(column, trace_domain, fri_domain), extension);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The length of the column must equal the length of the trace domain."
+    )]
+    fn lde_of_column_with_wrong_length_panics() {
+        let trace_domain = ArithmeticDomain::of_length(4).unwrap();
+        let fri_domain = ArithmeticDomain::of_length(8).unwrap();
+        lde(&[bfe!(1), bfe!(2)], trace_domain, fri_domain);
+    }
+
+    #[test]
+    #[should_panic(expected = "The FRI domain must not be shorter than the trace domain.")]
+    fn lde_to_shorter_domain_panics() {
+        let trace_domain = ArithmeticDomain::of_length(4).unwrap();
+        let fri_domain = ArithmeticDomain::of_length(2).unwrap();
+        lde(&[bfe!(1); 4], trace_domain, fri_domain);
+    }
+
+    #[test]
+    #[should_panic(expected = "The offset of a domain must be non-zero.")]
+    fn zero_offset_panics() {
+        let _ = ArithmeticDomain::of_length(4).unwrap().with_offset(bfe!(0));
+    }
+}
@@ -242,8 +242,57 @@ pub fn recursive_cyclic_mul<
     res
 }
 
+/// Wrapping arithmetic as needed by [`generated_function`].
+///
+/// Allows applying the MDS matrix to many states at once by using arrays of
+/// lanes instead of single [`u64`]s.
+pub(crate) trait WrappingArithmetic: Copy {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: u64) -> Self;
+}
+
+impl WrappingArithmetic for u64 {
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        u64::wrapping_add(self, rhs)
+    }
+
+    #[inline(always)]
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        u64::wrapping_sub(self, rhs)
+    }
+
+    #[inline(always)]
+    fn wrapping_mul(self, rhs: u64) -> Self {
+        u64::wrapping_mul(self, rhs)
+    }
+}
+
+impl<const N: usize> WrappingArithmetic for [u64; N] {
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        std::array::from_fn(|i| self[i].wrapping_add(rhs[i]))
+    }
+
+    #[inline(always)]
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        std::array::from_fn(|i| self[i].wrapping_sub(rhs[i]))
+    }
+
+    #[inline(always)]
+    fn wrapping_mul(self, rhs: u64) -> Self {
+        std::array::from_fn(|i| self[i].wrapping_mul(rhs))
+    }
+}
+
 #[inline(always)]
 pub fn generated_function(input: &[u64]) -> [u64; 16] {
+    lane_wise_generated_function(input)
+}
+
+#[inline(always)]
+pub(crate) fn lane_wise_generated_function<T: WrappingArithmetic>(input: &[T]) -> [T; 16] {
     let node_34 = input[0].wrapping_add(input[8]);
     let node_38 = input[4].wrapping_add(input[12]);
     let node_36 = input[2].wrapping_add(input[10]);
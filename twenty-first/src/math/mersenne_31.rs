@@ -0,0 +1,445 @@
+//! The Mersenne-31 field with modulus 2^31 - 1 and its quadratic “complex”
+//! extension.
+//!
+//! The multiplicative group of the Mersenne-31 field has order 2·3²·7·11·31·151·331,
+//! which is why it is ill-suited for [NTTs](crate::math::ntt) of power-of-two
+//! length. The multiplicative group of the [complex
+//! extension](ComplexMersenne31Element), on the other hand, has order
+//! 2^32·(2^30 - 1). NTTs of power-of-two length are therefore performed over
+//! the extension.
+
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use arbitrary::Arbitrary;
+use bfieldcodec_derive::BFieldCodec;
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use num_traits::One;
+use num_traits::Zero;
+use rand::Rng;
+use rand_distr::Distribution;
+use rand_distr::Standard;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::math::small_prime_field::small_prime_field;
+use crate::math::traits::CyclicGroupGenerator;
+use crate::math::traits::FiniteField;
+use crate::math::traits::Inverse;
+use crate::math::traits::ModPowU32;
+use crate::math::traits::ModPowU64;
+use crate::math::traits::PrimitiveRootOfUnity;
+
+small_prime_field!(
+    /// An element of the Mersenne-31 field with modulus 2^31 - 1.
+    ///
+    /// ```
+    /// # use twenty_first::math::mersenne_31::Mersenne31Element;
+    /// let a = Mersenne31Element::new(1 << 30);
+    /// assert_eq!(Mersenne31Element::new(1), a + a);
+    /// ```
+    Mersenne31Element,
+    0x7fff_ffff,
+    7,
+);
+
+/// An element of the quadratic extension of the [Mersenne-31
+/// field](Mersenne31Element) defined by the irreducible polynomial x² + 1,
+/// _i.e._, a “complex number” `re + im·i` with i² = -1.
+///
+/// Since the multiplicative group of this field has a large power-of-two order,
+/// its [base field](FiniteField::BaseField) is the field itself.
+#[derive(
+    Debug, Default, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, BFieldCodec, Arbitrary,
+)]
+pub struct ComplexMersenne31Element {
+    pub re: Mersenne31Element,
+    pub im: Mersenne31Element,
+}
+
+impl ComplexMersenne31Element {
+    /// The order of the multiplicative group, P² - 1.
+    const GROUP_ORDER: u64 = {
+        let p = Mersenne31Element::P as u64;
+        p * p - 1
+    };
+
+    pub const fn new(re: Mersenne31Element, im: Mersenne31Element) -> Self {
+        Self { re, im }
+    }
+
+    pub const fn new_const(element: Mersenne31Element) -> Self {
+        Self::new(element, Mersenne31Element::ZERO)
+    }
+
+    /// A generator of the field's multiplicative group.
+    pub const fn generator() -> Self {
+        Self::new(Mersenne31Element::new(2), Mersenne31Element::new(7))
+    }
+
+    /// The complex conjugate `re - im·i`, which is also the image under the
+    /// Frobenius automorphism.
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// The element as a [base field element](Mersenne31Element) if it lies in the
+    /// base field, [`None`] otherwise.
+    pub fn unlift(&self) -> Option<Mersenne31Element> {
+        self.im.is_zero().then_some(self.re)
+    }
+}
+
+impl From<Mersenne31Element> for ComplexMersenne31Element {
+    fn from(element: Mersenne31Element) -> Self {
+        Self::new_const(element)
+    }
+}
+
+impl From<u64> for ComplexMersenne31Element {
+    fn from(value: u64) -> Self {
+        Self::new_const(value.into())
+    }
+}
+
+impl Sum for ComplexMersenne31Element {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, element| acc + element)
+    }
+}
+
+impl Inverse for ComplexMersenne31Element {
+    fn inverse(&self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "Cannot invert the zero element in the extension field."
+        );
+
+        let norm = self.re * self.re + self.im * self.im;
+        self.conjugate() * norm.inverse()
+    }
+}
+
+impl PrimitiveRootOfUnity for ComplexMersenne31Element {
+    /// The canonical primitive `n`th root of unity, which is
+    /// [`generator`](Self::generator)^((P²-1)/n), if `n` divides P²-1.
+    fn primitive_root_of_unity(n: u64) -> Option<Self> {
+        if n == 0 || Self::GROUP_ORDER % n != 0 {
+            return None;
+        }
+        Some(Self::generator().mod_pow_u64(Self::GROUP_ORDER / n))
+    }
+}
+
+impl Distribution<ComplexMersenne31Element> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ComplexMersenne31Element {
+        ComplexMersenne31Element::new(rng.gen(), rng.gen())
+    }
+}
+
+impl CyclicGroupGenerator for ComplexMersenne31Element {
+    fn get_cyclic_group_elements(&self, max: Option<usize>) -> Vec<Self> {
+        let mut val = *self;
+        let mut ret: Vec<Self> = vec![Self::one()];
+
+        loop {
+            ret.push(val);
+            val *= *self;
+            if val.is_one() || max.is_some() && ret.len() >= max.unwrap() {
+                break;
+            }
+        }
+        ret
+    }
+}
+
+impl Display for ComplexMersenne31Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(element) = self.unlift() {
+            return write!(f, "{element}_ext");
+        }
+
+        write!(f, "({} + {}·i)", self.re, self.im)
+    }
+}
+
+impl Zero for ComplexMersenne31Element {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self == &Self::ZERO
+    }
+}
+
+impl ConstZero for ComplexMersenne31Element {
+    const ZERO: Self = Self::new_const(Mersenne31Element::ZERO);
+}
+
+impl One for ComplexMersenne31Element {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self == &Self::ONE
+    }
+}
+
+impl ConstOne for ComplexMersenne31Element {
+    const ONE: Self = Self::new_const(Mersenne31Element::ONE);
+}
+
+impl FiniteField for ComplexMersenne31Element {
+    type BaseField = Self;
+}
+
+impl Add for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let re = self.re * other.re - self.im * other.im;
+        let im = self.re * other.im + self.im * other.re;
+        Self::new(re, im)
+    }
+}
+
+impl Mul<Mersenne31Element> for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Mersenne31Element) -> Self {
+        Self::new(self.re * other, self.im * other)
+    }
+}
+
+impl Div for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl Neg for ComplexMersenne31Element {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for ComplexMersenne31Element {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for ComplexMersenne31Element {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for ComplexMersenne31Element {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<Mersenne31Element> for ComplexMersenne31Element {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Mersenne31Element) {
+        *self = *self * rhs;
+    }
+}
+
+impl ModPowU64 for ComplexMersenne31Element {
+    fn mod_pow_u64(&self, exponent: u64) -> Self {
+        let mut x = *self;
+        let mut result = Self::one();
+        let mut i = exponent;
+
+        while i > 0 {
+            if i & 1 == 1 {
+                result *= x;
+            }
+
+            x *= x;
+            i >>= 1;
+        }
+
+        result
+    }
+}
+
+impl ModPowU32 for ComplexMersenne31Element {
+    fn mod_pow_u32(&self, exp: u32) -> Self {
+        self.mod_pow_u64(exp.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::bfield_codec::BFieldCodec;
+    use crate::math::ntt::intt;
+    use crate::math::ntt::ntt;
+    use crate::math::polynomial::Polynomial;
+
+    const GROUP_ORDER_PRIME_FACTORS: [u64; 7] = [2, 3, 7, 11, 31, 151, 331];
+
+    #[test]
+    fn generators_generate_the_multiplicative_groups() {
+        let group_order = u64::from(Mersenne31Element::MAX);
+        for prime_factor in GROUP_ORDER_PRIME_FACTORS {
+            assert_eq!(0, group_order % prime_factor);
+            let power = Mersenne31Element::generator().mod_pow(group_order / prime_factor);
+            assert!(!power.is_one());
+        }
+
+        let complex_group_order = ComplexMersenne31Element::GROUP_ORDER;
+        for prime_factor in GROUP_ORDER_PRIME_FACTORS {
+            let power = ComplexMersenne31Element::generator()
+                .mod_pow_u64(complex_group_order / prime_factor);
+            assert!(!power.is_one());
+        }
+    }
+
+    #[test]
+    fn complex_extension_has_power_of_two_roots_of_unity() {
+        for log_2_of_n in 0..=32 {
+            let n = 1 << log_2_of_n;
+            let root = ComplexMersenne31Element::primitive_root_of_unity(n).unwrap();
+            assert!(root.mod_pow_u64(n).is_one());
+            assert!(n == 1 || !root.mod_pow_u64(n / 2).is_one());
+        }
+        assert!(ComplexMersenne31Element::primitive_root_of_unity(1 << 33).is_none());
+        assert!(Mersenne31Element::primitive_root_of_unity(4).is_none());
+    }
+
+    #[proptest]
+    fn arithmetic_agrees_with_integer_arithmetic(
+        #[strategy(arb())] a: Mersenne31Element,
+        #[strategy(arb())] b: Mersenne31Element,
+    ) {
+        let p = u64::from(Mersenne31Element::P);
+        let (a_val, b_val) = (u64::from(a.value()), u64::from(b.value()));
+        prop_assert_eq!((a_val + b_val) % p, u64::from((a + b).value()));
+        prop_assert_eq!((a_val + p - b_val) % p, u64::from((a - b).value()));
+        prop_assert_eq!(a_val * b_val % p, u64::from((a * b).value()));
+        prop_assert_eq!((p - a_val) % p, u64::from((-a).value()));
+    }
+
+    #[proptest]
+    fn inverse_of_non_zero_element_is_inverse(
+        #[strategy(arb())]
+        #[filter(!#a.is_zero())]
+        a: Mersenne31Element,
+        #[strategy(arb())]
+        #[filter(!#b.is_zero())]
+        b: ComplexMersenne31Element,
+    ) {
+        prop_assert!((a * a.inverse()).is_one());
+        prop_assert!((b * b.inverse()).is_one());
+    }
+
+    #[proptest]
+    fn conjugation_is_the_frobenius_automorphism(#[strategy(arb())] a: ComplexMersenne31Element) {
+        let frobenius = a.mod_pow_u64(Mersenne31Element::P.into());
+        prop_assert_eq!(a.conjugate(), frobenius);
+    }
+
+    #[proptest]
+    fn complex_multiplication_is_associative_and_distributive(
+        #[strategy(arb())] a: ComplexMersenne31Element,
+        #[strategy(arb())] b: ComplexMersenne31Element,
+        #[strategy(arb())] c: ComplexMersenne31Element,
+    ) {
+        prop_assert_eq!((a * b) * c, a * (b * c));
+        prop_assert_eq!(a * (b + c), a * b + a * c);
+    }
+
+    #[proptest]
+    fn encoding_round_trips(
+        #[strategy(arb())] element: Mersenne31Element,
+        #[strategy(arb())] complex_element: ComplexMersenne31Element,
+    ) {
+        prop_assert_eq!(element, *Mersenne31Element::decode(&element.encode())?);
+        prop_assert_eq!(
+            complex_element,
+            *ComplexMersenne31Element::decode(&complex_element.encode())?
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_over_complex_extension_agrees_with_evaluation(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<ComplexMersenne31Element>,
+    ) {
+        let n = 1 << log_2_of_n;
+        let omega = ComplexMersenne31Element::primitive_root_of_unity(n).unwrap();
+        let mut values = coefficients.clone();
+        ntt(&mut values, omega, log_2_of_n);
+
+        let polynomial = Polynomial::new(coefficients.clone());
+        let mut point = ComplexMersenne31Element::ONE;
+        for &value in &values {
+            prop_assert_eq!(polynomial.evaluate(point), value);
+            point *= omega;
+        }
+
+        intt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 20)]
+    fn fast_polynomial_multiplication_agrees_with_naive_multiplication(
+        #[strategy(vec(arb(), 0..100))] a: Vec<ComplexMersenne31Element>,
+        #[strategy(vec(arb(), 0..100))] b: Vec<ComplexMersenne31Element>,
+    ) {
+        let a = Polynomial::new(a);
+        let b = Polynomial::new(b);
+        prop_assert_eq!(a.naive_multiply(&b), a.fast_multiply(&b));
+    }
+}
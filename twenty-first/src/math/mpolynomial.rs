@@ -0,0 +1,732 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+use itertools::Itertools;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+
+/// The exponents of one term of a [multivariate polynomial](MPolynomial). The
+/// `i`th entry is the exponent of the `i`th variable.
+pub type Exponents = Vec<u32>;
+
+/// A multivariate polynomial with coefficients in a [finite field](FiniteField),
+/// in sparse monomial form.
+///
+/// Every term is stored as a map entry from its [exponents](Exponents) to its
+/// coefficient. Terms with a zero coefficient are never stored, which makes the
+/// representation of any polynomial unique.
+///
+/// # Examples
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// let [x, y] = MPolynomial::<BFieldElement>::variables(2).try_into().unwrap();
+/// let poly = x.clone() * x + y.scalar_mul(bfe!(3)) - MPolynomial::from_constant(bfe!(1), 2);
+///
+/// assert_eq!(2, poly.degree());
+/// assert_eq!(bfe!(4 + 15 - 1), poly.evaluate(&bfe_vec![2, 5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MPolynomial<FF: FiniteField> {
+    variable_count: usize,
+    coefficients: HashMap<Exponents, FF>,
+}
+
+// Manually implemented to guarantee that terms with zero coefficients are ignored.
+impl<FF: FiniteField> PartialEq for MPolynomial<FF> {
+    fn eq(&self, other: &Self) -> bool {
+        let non_zero_terms = |poly: &Self| {
+            poly.coefficients
+                .iter()
+                .filter(|(_, coefficient)| !coefficient.is_zero())
+                .map(|(exponents, &coefficient)| (exponents.clone(), coefficient))
+                .collect::<HashMap<_, _>>()
+        };
+
+        self.variable_count == other.variable_count && non_zero_terms(self) == non_zero_terms(other)
+    }
+}
+
+impl<FF: FiniteField> Eq for MPolynomial<FF> {}
+
+impl<FF: FiniteField> Display for MPolynomial<FF> {
+    /// Terms are ordered by descending total degree, ties broken by descending
+    /// exponents of the lower-indexed variables. This makes the output
+    /// deterministic.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let display_term = |(exponents, coefficient): (&Exponents, &FF)| {
+            let variables = exponents
+                .iter()
+                .enumerate()
+                .filter(|(_, &exponent)| exponent != 0)
+                .map(|(i, &exponent)| match exponent {
+                    1 => format!("x_{i}"),
+                    _ => format!("x_{i}^{exponent}"),
+                })
+                .join("·");
+
+            match (coefficient.is_one(), variables.is_empty()) {
+                (_, true) => format!("{coefficient}"),
+                (true, false) => variables,
+                (false, false) => format!("{coefficient}·{variables}"),
+            }
+        };
+
+        let terms = self
+            .terms()
+            .sorted_by(|(l, _), (r, _)| {
+                Self::total_degree(r)
+                    .cmp(&Self::total_degree(l))
+                    .then(r.cmp(l))
+            })
+            .map(display_term)
+            .join(" + ");
+
+        write!(f, "{terms}")
+    }
+}
+
+impl<FF: FiniteField> MPolynomial<FF> {
+    /// Create a new multivariate polynomial in `variable_count` variables from
+    /// the given terms. Terms with a zero coefficient are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of exponents of any term does not match the
+    /// `variable_count`.
+    pub fn new(variable_count: usize, coefficients: HashMap<Exponents, FF>) -> Self {
+        assert!(
+            coefficients.keys().all(|e| e.len() == variable_count),
+            "every term must have exactly {variable_count} exponents"
+        );
+
+        let mut polynomial = Self {
+            variable_count,
+            coefficients,
+        };
+        polynomial.normalize();
+        polynomial
+    }
+
+    /// The zero polynomial in `variable_count` variables.
+    pub fn zero(variable_count: usize) -> Self {
+        Self {
+            variable_count,
+            coefficients: HashMap::new(),
+        }
+    }
+
+    /// The constant polynomial `constant`, living in `variable_count` variables.
+    pub fn from_constant(constant: FF, variable_count: usize) -> Self {
+        let coefficients = [(vec![0; variable_count], constant)].into();
+        Self::new(variable_count, coefficients)
+    }
+
+    /// The polynomials x_0, x_1, …, x_{n-1}, where n is the `variable_count`.
+    pub fn variables(variable_count: usize) -> Vec<Self> {
+        (0..variable_count)
+            .map(|i| {
+                let mut exponents = vec![0; variable_count];
+                exponents[i] = 1;
+                let coefficients = [(exponents, FF::ONE)].into();
+                Self::new(variable_count, coefficients)
+            })
+            .collect()
+    }
+
+    /// Lift a univariate polynomial into the `variable`th variable of a
+    /// multivariate polynomial in `variable_count` variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variable` is not smaller than `variable_count`.
+    pub fn from_univariate(
+        polynomial: &Polynomial<FF>,
+        variable: usize,
+        variable_count: usize,
+    ) -> Self {
+        assert!(variable < variable_count, "variable index out of range");
+
+        let coefficients = (0..)
+            .zip(&polynomial.coefficients)
+            .map(|(exponent, &coefficient)| {
+                let mut exponents = vec![0; variable_count];
+                exponents[variable] = exponent;
+                (exponents, coefficient)
+            })
+            .collect();
+        Self::new(variable_count, coefficients)
+    }
+
+    pub fn variable_count(&self) -> usize {
+        self.variable_count
+    }
+
+    /// The coefficient of the term with the given exponents. Zero if no such
+    /// term exists.
+    pub fn coefficient(&self, exponents: &[u32]) -> FF {
+        self.coefficients
+            .get(exponents)
+            .copied()
+            .unwrap_or(FF::ZERO)
+    }
+
+    /// All terms with a non-zero coefficient, in no particular order.
+    pub fn terms(&self) -> impl Iterator<Item = (&Exponents, &FF)> {
+        self.coefficients.iter()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.coefficients.len() == 1 && self.constant_term().is_one()
+    }
+
+    /// The coefficient of the term of degree 0.
+    pub fn constant_term(&self) -> FF {
+        self.coefficient(&vec![0; self.variable_count])
+    }
+
+    /// The total degree, _i.e._, the largest sum of exponents of any term.
+    /// The degree of the zero polynomial is -1.
+    pub fn degree(&self) -> isize {
+        self.coefficients
+            .keys()
+            .map(|exponents| Self::total_degree(exponents) as isize)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    /// The largest exponent of the `variable`th variable in any term.
+    /// The degree of the zero polynomial is -1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variable` is not smaller than the
+    /// [variable count](Self::variable_count).
+    pub fn degree_in(&self, variable: usize) -> isize {
+        assert!(
+            variable < self.variable_count,
+            "variable index out of range"
+        );
+        self.coefficients
+            .keys()
+            .map(|exponents| exponents[variable] as isize)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    /// An upper bound on the degree of the univariate polynomial resulting from
+    /// [symbolic evaluation](Self::evaluate_symbolic) on polynomials of the
+    /// given degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of degrees does not match the
+    /// [variable count](Self::variable_count).
+    pub fn symbolic_degree_bound(&self, max_degrees: &[isize]) -> isize {
+        assert_eq!(self.variable_count, max_degrees.len());
+        self.coefficients
+            .keys()
+            .map(|exponents| {
+                let factors = exponents.iter().zip_eq(max_degrees);
+                let factors = factors.filter(|(&exponent, _)| exponent != 0);
+
+                // substituting the zero polynomial makes the entire term vanish
+                if factors.clone().any(|(_, &degree)| degree < 0) {
+                    return -1;
+                }
+                factors
+                    .map(|(&exponent, &degree)| exponent as isize * degree)
+                    .sum()
+            })
+            .max()
+            .unwrap_or(-1)
+    }
+
+    /// Evaluate the polynomial in the given point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the point does not match the
+    /// [variable count](Self::variable_count).
+    pub fn evaluate(&self, point: &[FF]) -> FF {
+        assert_eq!(self.variable_count, point.len());
+
+        let evaluate_term = |(exponents, &coefficient): (&Exponents, &FF)| {
+            exponents
+                .iter()
+                .zip(point)
+                .filter(|(&exponent, _)| exponent != 0)
+                .fold(coefficient, |acc, (&exp, x)| acc * x.mod_pow_u32(exp))
+        };
+
+        self.terms().map(evaluate_term).fold(FF::ZERO, FF::add)
+    }
+
+    /// Replace the `variable`th variable with the given `value`. The
+    /// [variable count](Self::variable_count) of the result is unchanged; the
+    /// resulting polynomial does not depend on the substituted variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variable` is not smaller than the
+    /// [variable count](Self::variable_count).
+    #[must_use]
+    pub fn partial_evaluate(&self, variable: usize, value: FF) -> Self {
+        assert!(
+            variable < self.variable_count,
+            "variable index out of range"
+        );
+
+        let mut coefficients = HashMap::<_, FF>::new();
+        for (exponents, &coefficient) in self.terms() {
+            let mut new_exponents = exponents.clone();
+            let exponent = std::mem::take(&mut new_exponents[variable]);
+            let new_coefficient = coefficient * value.mod_pow_u32(exponent);
+            *coefficients.entry(new_exponents).or_insert(FF::ZERO) += new_coefficient;
+        }
+
+        Self::new(self.variable_count, coefficients)
+    }
+
+    /// Multiply the polynomial with a scalar, _i.e._, compute `scalar · self`.
+    #[must_use]
+    pub fn scalar_mul(&self, scalar: FF) -> Self {
+        let coefficients = self
+            .terms()
+            .map(|(exponents, &coefficient)| (exponents.clone(), coefficient * scalar))
+            .collect();
+        Self::new(self.variable_count, coefficients)
+    }
+
+    /// Raise the polynomial to the given power.
+    #[must_use]
+    pub fn mod_pow(&self, pow: u32) -> Self {
+        let mut acc = Self::from_constant(FF::ONE, self.variable_count);
+        for i in (0..u32::BITS - pow.leading_zeros()).rev() {
+            acc = acc.clone() * acc;
+            if pow & (1 << i) != 0 {
+                acc *= self.clone();
+            }
+        }
+
+        acc
+    }
+
+    /// The formal partial derivative with respect to the `variable`th variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variable` is not smaller than the
+    /// [variable count](Self::variable_count).
+    #[must_use]
+    pub fn formal_derivative(&self, variable: usize) -> Self {
+        assert!(
+            variable < self.variable_count,
+            "variable index out of range"
+        );
+
+        let coefficients = self
+            .terms()
+            .filter(|(exponents, _)| exponents[variable] != 0)
+            .map(|(exponents, &coefficient)| {
+                let mut new_exponents = exponents.clone();
+                new_exponents[variable] -= 1;
+                let new_coefficient = coefficient * FF::from(u64::from(exponents[variable]));
+                (new_exponents, new_coefficient)
+            })
+            .collect();
+        Self::new(self.variable_count, coefficients)
+    }
+
+    /// Remove all terms with a zero coefficient.
+    fn normalize(&mut self) {
+        self.coefficients
+            .retain(|_, coefficient| !coefficient.is_zero());
+    }
+
+    fn total_degree(exponents: &[u32]) -> u64 {
+        exponents.iter().map(|&e| u64::from(e)).sum()
+    }
+
+    /// Extend the exponents of all terms to the given number of variables.
+    fn with_variable_count(mut self, variable_count: usize) -> Self {
+        if variable_count <= self.variable_count {
+            return self;
+        }
+
+        let coefficients = self
+            .coefficients
+            .drain()
+            .map(|(mut exponents, coefficient)| {
+                exponents.resize(variable_count, 0);
+                (exponents, coefficient)
+            });
+        Self {
+            variable_count,
+            coefficients: coefficients.collect(),
+        }
+    }
+}
+
+impl<FF> MPolynomial<FF>
+where
+    FF: FiniteField + MulAssign<BFieldElement>,
+{
+    /// Substitute every variable with a univariate polynomial, resulting in a
+    /// univariate polynomial. In particular, the `i`th variable is replaced by
+    /// the `i`th polynomial of `point`.
+    ///
+    /// This is useful for composing, for example, AIR constraints with trace
+    /// interpolants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the point does not match the
+    /// [variable count](Self::variable_count).
+    pub fn evaluate_symbolic(&self, point: &[Polynomial<FF>]) -> Polynomial<FF> {
+        assert_eq!(self.variable_count, point.len());
+
+        // Powers are computed once per variable and re-used across all terms.
+        let powers = point
+            .iter()
+            .enumerate()
+            .map(|(variable, polynomial)| {
+                let max_exponent = self.degree_in(variable).max(0) as usize;
+                let mut powers = vec![Polynomial::one()];
+                for i in 0..max_exponent {
+                    powers.push(powers[i].multiply(polynomial));
+                }
+                powers
+            })
+            .collect_vec();
+
+        let mut acc = Polynomial::zero();
+        for (exponents, &coefficient) in self.terms() {
+            let factors = exponents
+                .iter()
+                .enumerate()
+                .filter(|(_, &exponent)| exponent != 0)
+                .map(|(variable, &exponent)| powers[variable][exponent as usize].clone())
+                .collect_vec();
+            let mut term = Polynomial::batch_multiply(&factors);
+            term.scalar_mul_mut(coefficient);
+            acc += term;
+        }
+        acc.normalize();
+
+        acc
+    }
+}
+
+impl<FF: FiniteField> Add for MPolynomial<FF> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<FF: FiniteField> AddAssign for MPolynomial<FF> {
+    /// The variable count of the sum is the larger of the two summands'.
+    fn add_assign(&mut self, rhs: Self) {
+        let variable_count = self.variable_count.max(rhs.variable_count);
+        *self = std::mem::replace(self, Self::zero(0)).with_variable_count(variable_count);
+        let rhs = rhs.with_variable_count(variable_count);
+
+        for (exponents, coefficient) in rhs.coefficients {
+            *self.coefficients.entry(exponents).or_insert(FF::ZERO) += coefficient;
+        }
+        self.normalize();
+    }
+}
+
+impl<FF: FiniteField> Sub for MPolynomial<FF> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<FF: FiniteField> SubAssign for MPolynomial<FF> {
+    /// The variable count of the difference is the larger of the two operands'.
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += -rhs;
+    }
+}
+
+impl<FF: FiniteField> Neg for MPolynomial<FF> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        for coefficient in self.coefficients.values_mut() {
+            *coefficient = -*coefficient;
+        }
+        self
+    }
+}
+
+impl<FF: FiniteField> Mul for MPolynomial<FF> {
+    type Output = Self;
+
+    /// The variable count of the product is the larger of the two factors'.
+    fn mul(self, rhs: Self) -> Self {
+        let variable_count = self.variable_count.max(rhs.variable_count);
+        let lhs = self.with_variable_count(variable_count);
+        let rhs = rhs.with_variable_count(variable_count);
+
+        let mut coefficients = HashMap::<_, FF>::new();
+        for ((l_exponents, &l_coeff), (r_exponents, &r_coeff)) in lhs
+            .coefficients
+            .iter()
+            .cartesian_product(rhs.coefficients.iter())
+        {
+            let exponents = l_exponents
+                .iter()
+                .zip_eq(r_exponents)
+                .map(|(l, r)| l + r)
+                .collect();
+            *coefficients.entry(exponents).or_insert(FF::ZERO) += l_coeff * r_coeff;
+        }
+
+        Self::new(variable_count, coefficients)
+    }
+}
+
+impl<FF: FiniteField> MulAssign for MPolynomial<FF> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Self::zero(0)) * rhs;
+    }
+}
+
+#[cfg(test)]
+mod test_mpolynomials {
+    use num_traits::ConstOne;
+    use num_traits::ConstZero;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::prelude::*;
+
+    const VARIABLE_COUNT: usize = 3;
+
+    fn arbitrary_mpolynomial(
+        variable_count: usize,
+    ) -> impl Strategy<Value = MPolynomial<BFieldElement>> {
+        let term = (vec(0_u32..5, variable_count), arb());
+        vec(term, 0..10).prop_map(move |terms| {
+            let mut coefficients = HashMap::<_, BFieldElement>::new();
+            for (exponents, coefficient) in terms {
+                *coefficients.entry(exponents).or_insert(BFieldElement::ZERO) += coefficient;
+            }
+            MPolynomial::new(variable_count, coefficients)
+        })
+    }
+
+    #[test]
+    fn display_is_deterministic_and_readable() {
+        let [x, y] = MPolynomial::<BFieldElement>::variables(2)
+            .try_into()
+            .unwrap();
+        let constant = |c| MPolynomial::from_constant(bfe!(c), 2);
+
+        assert_eq!("0", MPolynomial::<BFieldElement>::zero(2).to_string());
+        assert_eq!("5", constant(5).to_string());
+        assert_eq!("x_0", x.to_string());
+
+        let poly = x.clone() * x.clone() * y.clone() + x.scalar_mul(bfe!(3)) + y + constant(7);
+        assert_eq!("x_0^2·x_1 + 3·x_0 + x_1 + 7", poly.to_string());
+    }
+
+    #[test]
+    fn degree_of_zero_polynomial_is_minus_one() {
+        let zero = MPolynomial::<BFieldElement>::zero(VARIABLE_COUNT);
+        assert_eq!(-1, zero.degree());
+        assert_eq!(-1, zero.degree_in(0));
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn degree_queries_give_expected_results() {
+        let [x, y, z] = MPolynomial::<BFieldElement>::variables(3)
+            .try_into()
+            .unwrap();
+        let poly = x.clone().mod_pow(3) * y.clone() + y.mod_pow(2) * z.mod_pow(3);
+        assert_eq!(5, poly.degree());
+        assert_eq!(3, poly.degree_in(0));
+        assert_eq!(2, poly.degree_in(1));
+        assert_eq!(3, poly.degree_in(2));
+        assert_eq!(3 * 4 + 5, poly.symbolic_degree_bound(&[4, 5, 0]));
+    }
+
+    #[test]
+    fn zero_coefficients_are_dropped() {
+        let coefficients = [(vec![1, 2], BFieldElement::ZERO)].into();
+        let poly = MPolynomial::new(2, coefficients);
+        assert!(poly.is_zero());
+        assert_eq!(MPolynomial::zero(2), poly);
+    }
+
+    #[test]
+    #[should_panic(expected = "every term must have exactly 2 exponents")]
+    fn creating_polynomial_with_inconsistent_exponents_panics() {
+        let coefficients = [(vec![1, 2, 3], BFieldElement::ONE)].into();
+        MPolynomial::new(2, coefficients);
+    }
+
+    #[proptest]
+    fn constant_polynomial_evaluates_to_constant(
+        constant: BFieldElement,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let poly = MPolynomial::from_constant(constant, VARIABLE_COUNT);
+        prop_assert_eq!(constant, poly.evaluate(&point));
+    }
+
+    #[proptest]
+    fn addition_is_homomorphic_under_evaluation(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] b: MPolynomial<BFieldElement>,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let sum = a.clone() + b.clone();
+        prop_assert_eq!(
+            a.evaluate(&point) + b.evaluate(&point),
+            sum.evaluate(&point)
+        );
+    }
+
+    #[proptest]
+    fn subtraction_is_homomorphic_under_evaluation(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] b: MPolynomial<BFieldElement>,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let difference = a.clone() - b.clone();
+        let expected = a.evaluate(&point) - b.evaluate(&point);
+        prop_assert_eq!(expected, difference.evaluate(&point));
+    }
+
+    #[proptest]
+    fn multiplication_is_homomorphic_under_evaluation(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] b: MPolynomial<BFieldElement>,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let product = a.clone() * b.clone();
+        let expected = a.evaluate(&point) * b.evaluate(&point);
+        prop_assert_eq!(expected, product.evaluate(&point));
+    }
+
+    #[proptest]
+    fn polynomial_minus_itself_is_zero(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+    ) {
+        prop_assert!((a.clone() - a).is_zero());
+    }
+
+    #[proptest]
+    fn adding_polynomials_in_different_number_of_variables_extends_variable_count(
+        #[strategy(arbitrary_mpolynomial(2))] a: MPolynomial<BFieldElement>,
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] b: MPolynomial<BFieldElement>,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let sum = a.clone() + b.clone();
+        prop_assert_eq!(VARIABLE_COUNT, sum.variable_count());
+
+        let expected = a.evaluate(&point[..2]) + b.evaluate(&point);
+        prop_assert_eq!(expected, sum.evaluate(&point));
+    }
+
+    #[proptest(cases = 50)]
+    fn mod_pow_is_repeated_multiplication(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(0_u32..5)] pow: u32,
+    ) {
+        let one = MPolynomial::from_constant(BFieldElement::ONE, VARIABLE_COUNT);
+        let expected = (0..pow).fold(one, |acc, _| acc * a.clone());
+        prop_assert_eq!(expected, a.mod_pow(pow));
+    }
+
+    #[proptest]
+    fn partial_evaluation_then_evaluation_is_evaluation(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(0..VARIABLE_COUNT)] variable: usize,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+        ignored_value: BFieldElement,
+    ) {
+        let partially_evaluated = a.partial_evaluate(variable, point[variable]);
+        prop_assert!(partially_evaluated.degree_in(variable) <= 0);
+
+        let mut other_point = point.clone();
+        other_point[variable] = ignored_value;
+        prop_assert_eq!(
+            a.evaluate(&point),
+            partially_evaluated.evaluate(&other_point)
+        );
+    }
+
+    #[proptest]
+    fn symbolic_evaluation_then_evaluation_is_evaluation_in_evaluated_point(
+        #[strategy(arbitrary_mpolynomial(VARIABLE_COUNT))] a: MPolynomial<BFieldElement>,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] univariates: Vec<Polynomial<BFieldElement>>,
+        x: BFieldElement,
+    ) {
+        let composition = a.evaluate_symbolic(&univariates);
+        let point = univariates.iter().map(|p| p.evaluate(x)).collect_vec();
+        prop_assert_eq!(a.evaluate(&point), composition.evaluate(x));
+
+        let degrees = univariates.iter().map(|p| p.degree()).collect_vec();
+        prop_assert!(composition.degree() <= a.symbolic_degree_bound(&degrees));
+    }
+
+    #[proptest]
+    fn lifting_univariate_polynomial_preserves_evaluation(
+        polynomial: Polynomial<BFieldElement>,
+        #[strategy(0..VARIABLE_COUNT)] variable: usize,
+        #[strategy(vec(arb(), VARIABLE_COUNT))] point: Vec<BFieldElement>,
+    ) {
+        let lifted = MPolynomial::from_univariate(&polynomial, variable, VARIABLE_COUNT);
+        prop_assert_eq!(polynomial.degree(), lifted.degree());
+        prop_assert_eq!(
+            polynomial.evaluate(point[variable]),
+            lifted.evaluate(&point)
+        );
+    }
+
+    #[proptest]
+    fn formal_derivative_of_lifted_polynomial_is_lifted_formal_derivative(
+        polynomial: Polynomial<BFieldElement>,
+        #[strategy(0..VARIABLE_COUNT)] variable: usize,
+    ) {
+        let lifted = MPolynomial::from_univariate(&polynomial, variable, VARIABLE_COUNT);
+        let derivative = polynomial.formal_derivative();
+        let lifted_derivative = MPolynomial::from_univariate(&derivative, variable, VARIABLE_COUNT);
+        prop_assert_eq!(lifted_derivative, lifted.formal_derivative(variable));
+    }
+}
@@ -1,13 +1,23 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
 use std::ops::MulAssign;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+use rayon::prelude::*;
 
 use num_traits::ConstOne;
 use num_traits::ConstZero;
 use rand_distr::num_traits::One;
 
 use super::b_field_element::BFieldElement;
+use super::packed_b_field_element;
 use super::traits::FiniteField;
 use super::traits::Inverse;
 use super::traits::ModPowU32;
+use super::traits::PrimitiveRootOfUnity;
 
 /// ## Perform NTT on slices of prime-field elements
 ///
@@ -45,54 +55,21 @@ use super::traits::ModPowU32;
 /// This transform is performed in-place.
 ///
 /// If called on an empty array, returns an empty array.
-#[allow(clippy::many_single_char_names)]
-pub fn ntt<FF: FiniteField + MulAssign<BFieldElement>>(
-    x: &mut [FF],
-    omega: BFieldElement,
-    log_2_of_n: u32,
-) {
-    let n = x.len() as u32;
-
+///
+/// The twiddle factors are taken from an [`NttPlan`] that is looked up in, or
+/// added to, a global [cache](NttPlan::cached).
+pub fn ntt<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField, log_2_of_n: u32) {
     // `n` must be a power of 2, or be zero
     debug_assert!(
-        n == 1 << log_2_of_n || n == 0 && log_2_of_n == 0,
-        "2^log2(n) == n || n == 0 && log_2_of_n == 0 must evaluate to true, but n was {n} and log_2_of_n was {log_2_of_n}"
+        x.len() == 1 << log_2_of_n || x.is_empty() && log_2_of_n == 0,
+        "2^log2(n) == n || n == 0 && log_2_of_n == 0 must evaluate to true, but n was {} and log_2_of_n was {log_2_of_n}",
+        x.len()
     );
-
-    // `omega` must be a primitive root of unity of order `n`
-    debug_assert!(
-        omega.mod_pow_u32(n).is_one(),
-        "Got {omega} which is not a {n}th root of 1"
-    );
-    debug_assert!(!omega.mod_pow_u32(n / 2).is_one() || n == 0 || n == 1);
-
-    for k in 0..n {
-        let rk = bitreverse(k, log_2_of_n);
-        if k < rk {
-            x.swap(rk as usize, k as usize);
-        }
+    if x.is_empty() {
+        return;
     }
 
-    let mut m = 1;
-    for _ in 0..log_2_of_n {
-        let w_m = omega.mod_pow_u32(n / (2 * m));
-        let mut k = 0;
-        while k < n {
-            let mut w = BFieldElement::ONE;
-            for j in 0..m {
-                let u = x[(k + j) as usize];
-                let mut v = x[(k + j + m) as usize];
-                v *= w;
-                x[(k + j) as usize] = u + v;
-                x[(k + j + m) as usize] = u - v;
-                w *= w_m;
-            }
-
-            k += 2 * m;
-        }
-
-        m *= 2;
-    }
+    NttPlan::cached(omega, log_2_of_n).forward(x);
 }
 
 /// ## Perform INTT on slices of prime-field elements
@@ -109,19 +86,885 @@ pub fn ntt<FF: FiniteField + MulAssign<BFieldElement>>(
 /// ```
 ///
 /// This transform is performed in-place.
-pub fn intt<FF: FiniteField + MulAssign<BFieldElement>>(
-    x: &mut [FF],
-    omega: BFieldElement,
+///
+/// Like [`ntt`], this uses a globally [cached](NttPlan::cached) [`NttPlan`]. In
+/// fact, it uses the very same plan as [`ntt`] for the same `omega`.
+pub fn intt<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField, log_2_of_n: u32) {
+    debug_assert!(
+        x.len() == 1 << log_2_of_n || x.is_empty() && log_2_of_n == 0,
+        "2^log2(n) == n || n == 0 && log_2_of_n == 0 must evaluate to true, but n was {} and log_2_of_n was {log_2_of_n}",
+        x.len()
+    );
+    if x.is_empty() {
+        return;
+    }
+
+    NttPlan::cached(omega, log_2_of_n).inverse(x);
+}
+
+/// The memory layout of a matrix that is stored in a flat slice.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MatrixLayout {
+    /// Consecutive elements of a row are adjacent in memory.
+    RowMajor,
+
+    /// Consecutive elements of a column are adjacent in memory.
+    ColumnMajor,
+}
+
+/// Perform the [NTT](ntt) on every column of a matrix in-place.
+///
+/// The matrix has 2^`log_2_of_n` rows and is stored in `matrix` according to
+/// the given `layout`. All columns share the same globally
+/// [cached](NttPlan::cached) [`NttPlan`] and are transformed in parallel.
+///
+/// # Panics
+///
+/// Panics if the length of `matrix` is not a multiple of 2^`log_2_of_n`.
+pub fn batch_ntt<FF: FiniteField>(
+    matrix: &mut [FF],
+    layout: MatrixLayout,
+    omega: FF::BaseField,
     log_2_of_n: u32,
 ) {
-    let n = BFieldElement::new(u64::try_from(x.len()).unwrap());
-    let n_inv_or_zero = n.inverse_or_zero();
-    ntt::<FF>(x, omega.inverse(), log_2_of_n);
+    NttPlan::cached(omega, log_2_of_n).batch_forward(matrix, layout);
+}
+
+/// Perform the [INTT](intt) on every column of a matrix in-place. See
+/// [`batch_ntt`] for details.
+///
+/// # Panics
+///
+/// Panics if the length of `matrix` is not a multiple of 2^`log_2_of_n`.
+pub fn batch_intt<FF: FiniteField>(
+    matrix: &mut [FF],
+    layout: MatrixLayout,
+    omega: FF::BaseField,
+    log_2_of_n: u32,
+) {
+    NttPlan::cached(omega, log_2_of_n).batch_inverse(matrix, layout);
+}
+
+/// Interpret every column of a matrix as the coefficients of a polynomial and
+/// evaluate it in-place on the coset `offset·⟨omega⟩`. See [`batch_ntt`] and
+/// [`NttPlan::forward_coset`] for details.
+///
+/// # Panics
+///
+/// Panics if the length of `matrix` is not a multiple of 2^`log_2_of_n`.
+pub fn batch_coset_ntt<FF: FiniteField>(
+    matrix: &mut [FF],
+    layout: MatrixLayout,
+    offset: FF::BaseField,
+    omega: FF::BaseField,
+    log_2_of_n: u32,
+) {
+    NttPlan::cached(omega, log_2_of_n).batch_forward_coset(matrix, layout, offset);
+}
+
+/// The inverse of [`batch_coset_ntt`].
+///
+/// # Panics
+///
+/// - If the length of `matrix` is not a multiple of 2^`log_2_of_n`.
+/// - If `offset` is zero.
+pub fn batch_coset_intt<FF: FiniteField>(
+    matrix: &mut [FF],
+    layout: MatrixLayout,
+    offset: FF::BaseField,
+    omega: FF::BaseField,
+    log_2_of_n: u32,
+) {
+    NttPlan::cached(omega, log_2_of_n).batch_inverse_coset(matrix, layout, offset);
+}
+
+/// The slice length from which on [`NttPlan::forward`] and [`NttPlan::inverse`]
+/// switch to the parallel four-step algorithm. Can be overridden with the
+/// environment variable `TWENTY_FIRST_NTT_PARALLELIZATION_CUTOFF`.
+pub const DEFAULT_PARALLELIZATION_CUTOFF: usize = 1 << 16;
+
+lazy_static! {
+    static ref PARALLELIZATION_CUTOFF: usize =
+        std::env::var("TWENTY_FIRST_NTT_PARALLELIZATION_CUTOFF")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_PARALLELIZATION_CUTOFF);
+    /// For every field, a map from (`omega`, `log_2_of_n`) to the plan. Only
+    /// [cacheable](NttPlan::is_cacheable) plans are added.
+    static ref NTT_PLAN_CACHE: RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Precomputed data for [number theoretic transforms](ntt) of one fixed length
+/// `n` = 2^`log_2_of_n` with respect to one fixed primitive `n`th root of unity
+/// `omega`.
+///
+/// A plan holds the twiddle factors, _i.e._, the powers of `omega`, as well as
+/// the bit-reversal permutation of length `n`. The twiddle factors live in the
+/// field `B`, which defaults to [`BFieldElement`]. One plan can transform slices
+/// of any field whose [base field](FiniteField::BaseField) is `B`, for example
+/// [`XFieldElement`][xfe]s.
+///
+/// Building a plan costs about as much as one transform. Plans are immutable and
+/// can be shared across threads, making it worthwhile to build a plan once and
+/// reuse it. The free functions [`ntt`] and [`intt`] do this automatically by
+/// going through the global [cache](Self::cached).
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::ntt::NttPlan;
+/// let plan = NttPlan::for_length(4).unwrap();
+/// let mut values = bfe_vec![1, 2, 3, 4];
+/// plan.forward(&mut values);
+/// plan.inverse(&mut values);
+/// assert_eq!(bfe_vec![1, 2, 3, 4], values);
+/// ```
+///
+/// [xfe]: crate::math::x_field_element::XFieldElement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NttPlan<B: FiniteField = BFieldElement> {
+    omega: B,
+    log_2_of_n: u32,
+
+    /// `omega^i` for all `i` in `0..n/2`
+    powers_of_omega: Vec<B>,
+
+    /// The bit-reversal of every index in `0..n`
+    bit_reversal: Vec<u32>,
+
+    n_inverse: B,
+}
+
+type NttPlanCache<B> = HashMap<(B, u32), Arc<NttPlan<B>>>;
+
+/// The largest `log_2_of_n` for which [`NttPlan::cached`] keeps plans in the
+/// global cache. Building a plan costs less than one transform of the same
+/// length, so the relative cost of rebuilding larger plans is small.
+///
+/// A plan of length n over [`BFieldElement`]s occupies about 8·n bytes. Since
+/// at most one plan per length is cached, the cache holds at most about 16 MiB
+/// per field.
+pub const MAX_CACHED_LOG_2_OF_N: u32 = 20;
+
+impl NttPlan {
+    /// Remove all plans, for all fields, from the global [cache](Self::cached).
+    pub fn clear_cache() {
+        NTT_PLAN_CACHE.write().unwrap().clear();
+    }
+}
+
+impl<B: FiniteField> NttPlan<B> {
+    /// Build a plan for transforms of length 2^`log_2_of_n` with respect to
+    /// `omega`, which must be a primitive root of unity of that order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_2_of_n` is 32 or larger.
+    pub fn new(omega: B, log_2_of_n: u32) -> Self {
+        assert!(
+            log_2_of_n < u32::BITS,
+            "NTT length 2^{log_2_of_n} is too large"
+        );
+        let n = 1_u32 << log_2_of_n;
+
+        // `omega` must be a primitive root of unity of order `n`
+        debug_assert!(
+            omega.mod_pow_u32(n).is_one(),
+            "Got {omega} which is not a {n}th root of 1"
+        );
+        debug_assert!(!omega.mod_pow_u32(n / 2).is_one() || n == 1);
+
+        let mut powers_of_omega = Vec::with_capacity((n / 2) as usize);
+        let mut power = B::ONE;
+        for _ in 0..n / 2 {
+            powers_of_omega.push(power);
+            power *= omega;
+        }
+
+        let bit_reversal = (0..n).map(|k| bitreverse(k, log_2_of_n)).collect();
+        let n_inverse = B::from(n.into()).inverse();
+
+        Self {
+            omega,
+            log_2_of_n,
+            powers_of_omega,
+            bit_reversal,
+            n_inverse,
+        }
+    }
+
+    /// Build a plan for transforms of length `n` with respect to the
+    /// [canonical](PrimitiveRootOfUnity) primitive `n`th root of unity.
+    ///
+    /// Returns [`None`] if no such root of unity exists, in particular if `n` is
+    /// not a power of two.
+    pub fn for_length(n: usize) -> Option<Self> {
+        if !n.is_power_of_two() {
+            return None;
+        }
+        let omega = B::primitive_root_of_unity(n.try_into().ok()?)?;
+        Some(Self::new(omega, n.ilog2()))
+    }
+
+    /// The plan for transforms of length 2^`log_2_of_n` with respect to `omega`
+    /// from the global cache. If the cache contains no such plan, it is
+    /// [built](Self::new) and added.
+    ///
+    /// Only plans for the [canonical](PrimitiveRootOfUnity) primitive root of
+    /// unity of their order and of length at most 2^[`MAX_CACHED_LOG_2_OF_N`] are
+    /// cached. This bounds the cache to at most one plan per length and field.
+    /// Other plans are built on every call. Cached plans are never evicted
+    /// implicitly. See [`clear_cache`] to free the memory they occupy.
+    ///
+    /// [`clear_cache`]: Self::clear_cache
+    pub fn cached(omega: B, log_2_of_n: u32) -> Arc<Self> {
+        if !Self::is_cacheable(omega, log_2_of_n) {
+            return Arc::new(Self::new(omega, log_2_of_n));
+        }
+
+        let key = (omega, log_2_of_n);
+        let field = TypeId::of::<B>();
+        if let Some(plan) = NTT_PLAN_CACHE
+            .read()
+            .unwrap()
+            .get(&field)
+            .and_then(|plans| plans.downcast_ref::<NttPlanCache<B>>())
+            .and_then(|plans| plans.get(&key))
+        {
+            return Arc::clone(plan);
+        }
+
+        // Build the plan without holding the lock. Should another thread have
+        // added the same plan in the meantime, its plan is kept.
+        let plan = Arc::new(Self::new(omega, log_2_of_n));
+        let mut cache = NTT_PLAN_CACHE.write().unwrap();
+        let plans = cache
+            .entry(field)
+            .or_insert_with(|| Box::new(NttPlanCache::<B>::new()))
+            .downcast_mut::<NttPlanCache<B>>()
+            .expect("plans should be stored by the type of their field");
+        Arc::clone(plans.entry(key).or_insert(plan))
+    }
+
+    /// Whether the plan for `omega` and `log_2_of_n` may be added to the global
+    /// [cache](Self::cached).
+    fn is_cacheable(omega: B, log_2_of_n: u32) -> bool {
+        log_2_of_n <= MAX_CACHED_LOG_2_OF_N
+            && B::primitive_root_of_unity(1 << log_2_of_n) == Some(omega)
+    }
+
+    /// The root of unity the transforms are with respect to.
+    pub fn omega(&self) -> B {
+        self.omega
+    }
+
+    pub fn log_2_of_n(&self) -> u32 {
+        self.log_2_of_n
+    }
+
+    /// The length of slices this plan can transform.
+    pub fn len(&self) -> usize {
+        self.bit_reversal.len()
+    }
+
+    /// Always `false`: every plan transforms slices of length at least 1.
+    pub fn is_empty(&self) -> bool {
+        self.bit_reversal.is_empty()
+    }
+
+    /// Perform the [NTT](ntt) in-place.
+    ///
+    /// For slices of length at least the [parallelization
+    /// cutoff](DEFAULT_PARALLELIZATION_CUTOFF), this uses the [parallel four-step
+    /// algorithm](Self::parallel_forward).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not [the plan's length](Self::len).
+    pub fn forward<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF]) {
+        if self.len() >= *PARALLELIZATION_CUTOFF {
+            self.parallel_forward(x);
+        } else {
+            self.sequential(x, Direction::Forward);
+        }
+    }
+
+    /// Perform the [INTT](intt) in-place.
+    ///
+    /// For slices of length at least the [parallelization
+    /// cutoff](DEFAULT_PARALLELIZATION_CUTOFF), this uses the [parallel four-step
+    /// algorithm](Self::parallel_inverse).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not [the plan's length](Self::len).
+    pub fn inverse<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF]) {
+        if self.len() >= *PARALLELIZATION_CUTOFF {
+            self.parallel_inverse(x);
+        } else {
+            self.sequential(x, Direction::Inverse);
+        }
+    }
+
+    /// Perform the [NTT](ntt) in-place using the [four-step algorithm][4step],
+    /// parallelized with [rayon].
+    ///
+    /// The slice is viewed as a matrix with about √n rows and columns. All
+    /// columns are transformed independently, the result is multiplied by
+    /// twiddle factors, and all rows are transformed independently. The sub-NTTs
+    /// are short enough to be cache-friendly, and they run in parallel.
+    /// Transposing the matrix between steps keeps all memory accesses of the
+    /// sub-NTTs contiguous, which makes this the six-step variant, strictly
+    /// speaking. It requires a scratch buffer the size of `x`.
+    ///
+    /// The result is identical to that of [`forward`](Self::forward). Unless
+    /// the slice is large, the overhead of this method outweighs its benefits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not [the plan's length](Self::len).
+    ///
+    /// [4step]: https://en.wikipedia.org/wiki/Bailey%27s_FFT_algorithm
+    pub fn parallel_forward<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF]) {
+        self.four_step(x, Direction::Forward);
+    }
+
+    /// Perform the [INTT](intt) in-place using the [four-step
+    /// algorithm](Self::parallel_forward), parallelized with [rayon].
+    ///
+    /// The result is identical to that of [`inverse`](Self::inverse).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not [the plan's length](Self::len).
+    pub fn parallel_inverse<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF]) {
+        self.four_step(x, Direction::Inverse);
+    }
+
+    /// Interpret `x` as the coefficients of a polynomial and evaluate it in-place
+    /// on the coset `offset·⟨omega⟩`, _i.e._, on the points `offset·omega^i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` is not [the plan's length](Self::len).
+    pub fn forward_coset<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF], offset: B) {
+        Self::scale_by_powers(x, offset);
+        self.forward(x);
+    }
+
+    /// The inverse of [`forward_coset`](Self::forward_coset): interpret `x` as the
+    /// values of a polynomial on the coset `offset·⟨omega⟩` and interpolate them
+    /// in-place.
+    ///
+    /// # Panics
+    ///
+    /// - If the length of `x` is not [the plan's length](Self::len).
+    /// - If `offset` is zero.
+    pub fn inverse_coset<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF], offset: B) {
+        self.inverse(x);
+        Self::scale_by_powers(x, offset.inverse());
+    }
+
+    /// Perform the [NTT](ntt) on every column of a matrix in-place. The columns
+    /// are transformed in parallel.
+    ///
+    /// The number of rows of the matrix is [the plan's length](Self::len); the
+    /// number of columns is derived from the length of `matrix`. If the matrix is
+    /// stored in [row-major](MatrixLayout::RowMajor) layout, it is transposed
+    /// into a temporary buffer and back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `matrix` is not a multiple of [the plan's
+    /// length](Self::len).
+    pub fn batch_forward<FF: FiniteField<BaseField = B>>(
+        &self,
+        matrix: &mut [FF],
+        layout: MatrixLayout,
+    ) {
+        self.for_each_column(matrix, layout, |column| self.forward(column));
+    }
+
+    /// Perform the [INTT](intt) on every column of a matrix in-place. See
+    /// [`batch_forward`](Self::batch_forward) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `matrix` is not a multiple of [the plan's
+    /// length](Self::len).
+    pub fn batch_inverse<FF: FiniteField<BaseField = B>>(
+        &self,
+        matrix: &mut [FF],
+        layout: MatrixLayout,
+    ) {
+        self.for_each_column(matrix, layout, |column| self.inverse(column));
+    }
+
+    /// Perform [`forward_coset`](Self::forward_coset) on every column of a
+    /// matrix in-place. See [`batch_forward`](Self::batch_forward) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `matrix` is not a multiple of [the plan's
+    /// length](Self::len).
+    pub fn batch_forward_coset<FF: FiniteField<BaseField = B>>(
+        &self,
+        matrix: &mut [FF],
+        layout: MatrixLayout,
+        offset: B,
+    ) {
+        self.for_each_column(matrix, layout, |column| {
+            self.forward_coset(column, offset);
+        });
+    }
+
+    /// Perform [`inverse_coset`](Self::inverse_coset) on every column of a
+    /// matrix in-place. See [`batch_forward`](Self::batch_forward) for details.
+    ///
+    /// # Panics
+    ///
+    /// - If the length of `matrix` is not a multiple of [the plan's
+    ///   length](Self::len).
+    /// - If `offset` is zero.
+    pub fn batch_inverse_coset<FF: FiniteField<BaseField = B>>(
+        &self,
+        matrix: &mut [FF],
+        layout: MatrixLayout,
+        offset: B,
+    ) {
+        self.for_each_column(matrix, layout, |column| {
+            self.inverse_coset(column, offset);
+        });
+    }
+
+    fn for_each_column<FF, F>(&self, matrix: &mut [FF], layout: MatrixLayout, transform: F)
+    where
+        FF: FiniteField,
+        F: Fn(&mut [FF]) + Send + Sync,
+    {
+        let n = self.len();
+        assert_eq!(
+            0,
+            matrix.len() % n,
+            "matrix with {} elements cannot have columns of length {n}",
+            matrix.len()
+        );
+        if matrix.is_empty() {
+            return;
+        }
+
+        match layout {
+            MatrixLayout::ColumnMajor => matrix.par_chunks_exact_mut(n).for_each(transform),
+            MatrixLayout::RowMajor => {
+                let num_columns = matrix.len() / n;
+                let mut columns = vec![FF::ZERO; matrix.len()];
+                transpose(matrix, &mut columns, num_columns);
+                columns.par_chunks_exact_mut(n).for_each(transform);
+                transpose(&columns, matrix, n);
+            }
+        }
+    }
+
+    /// The iterative radix-2 transform, including the final scaling of the
+    /// inverse.
+    fn sequential<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF], direction: Direction) {
+        self.transform(x, direction);
+        if direction == Direction::Inverse {
+            for elem in x.iter_mut() {
+                *elem *= self.n_inverse;
+            }
+        }
+    }
+
+    /// Write n = n1·n2 and index the input by j = j1 + n1·j2 and the output by
+    /// k = k2 + n2·k1. Then
+    ///
+    /// X[k2 + n2·k1] = Σ_j1 ω_n1^(j1·k1) · ω^(j1·k2) · Σ_j2 ω_n2^(j2·k2) · x[j1 + n1·j2]
+    ///
+    /// where ω_n1 = ω^n2 and ω_n2 = ω^n1. The inner sums are NTTs of length n2,
+    /// the outer sums are NTTs of length n1.
+    ///
+    /// The inverse follows the same pattern with ω^-1. Since the inverse sub-NTTs
+    /// scale by 1/n1 and 1/n2, respectively, no additional scaling is needed.
+    fn four_step<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF], direction: Direction) {
+        let n = self.len();
+        assert_eq!(
+            n,
+            x.len(),
+            "NTT plan of length {n} cannot transform slice of length {}",
+            x.len()
+        );
+
+        let log_2_of_n1 = self.log_2_of_n / 2;
+        let log_2_of_n2 = self.log_2_of_n - log_2_of_n1;
+        let n1 = 1 << log_2_of_n1;
+        let n2 = 1 << log_2_of_n2;
+        let outer_plan = Self::cached(self.omega.mod_pow_u32(n2 as u32), log_2_of_n1);
+        let inner_plan = Self::cached(self.omega.mod_pow_u32(n1 as u32), log_2_of_n2);
+        let omega = match direction {
+            Direction::Forward => self.omega,
+            Direction::Inverse => self.omega.inverse(),
+        };
+
+        // `x` is an n2×n1 matrix in row-major order; its columns are the inputs
+        // to the inner NTTs
+        let mut scratch = vec![FF::ZERO; n];
+        transpose(x, &mut scratch, n1);
+        scratch
+            .par_chunks_exact_mut(n2)
+            .enumerate()
+            .for_each(|(j1, row)| {
+                inner_plan.sequential(row, direction);
+                Self::scale_by_powers(row, omega.mod_pow_u32(j1 as u32));
+            });
+
+        transpose(&scratch, x, n2);
+        x.par_chunks_exact_mut(n1)
+            .for_each(|row| outer_plan.sequential(row, direction));
+
+        // row k2, column k1 holds X[k2 + n2·k1]
+        transpose(x, &mut scratch, n1);
+        x.copy_from_slice(&scratch);
+    }
+
+    fn scale_by_powers<FF: MulAssign<B>>(x: &mut [FF], factor: B) {
+        let mut power = B::ONE;
+        for elem in x.iter_mut() {
+            *elem *= power;
+            power *= factor;
+        }
+    }
+
+    /// The iterative radix-2 transform without the final scaling of the inverse.
+    fn transform<FF: FiniteField<BaseField = B>>(&self, x: &mut [FF], direction: Direction) {
+        let n = self.len();
+        assert_eq!(
+            n,
+            x.len(),
+            "NTT plan of length {n} cannot transform slice of length {}",
+            x.len()
+        );
+
+        if let (Some(x), Some(powers_of_omega)) = (
+            packed_b_field_element::as_b_field_elements_mut(x),
+            packed_b_field_element::as_b_field_elements(&self.powers_of_omega),
+        ) {
+            self.packed_forward_transform(x, powers_of_omega);
+
+            // X[-k] = X[n-k], where the inverse transform computes X[-k]
+            if direction == Direction::Inverse {
+                x[1..].reverse();
+            }
+            return;
+        }
+
+        self.bit_reverse(x);
+        let mut m = 1;
+        while m < n {
+            // the twiddle factors of this layer are the powers of omega^stride
+            let stride = n / (2 * m);
+            for k in (0..n).step_by(2 * m) {
+                for j in 0..m {
+                    let u = x[k + j];
+                    let mut v = x[k + j + m];
+                    v *= self.twiddle(j * stride, direction);
+                    x[k + j] = u + v;
+                    x[k + j + m] = u - v;
+                }
+            }
+
+            m *= 2;
+        }
+    }
+
+    /// Like [`transform`](Self::transform) in the forward direction, but using
+    /// [packed butterflies](packed_b_field_element::butterflies) for all layers
+    /// that are at least as wide as a [packed element][width].
+    ///
+    /// [width]: packed_b_field_element::WIDTH
+    fn packed_forward_transform(&self, x: &mut [BFieldElement], powers_of_omega: &[BFieldElement]) {
+        let n = self.len();
+        self.bit_reverse(x);
+        let mut m = 1;
+        while m < n {
+            let stride = n / (2 * m);
+            for block in x.chunks_exact_mut(2 * m) {
+                let (lo, hi) = block.split_at_mut(m);
+                if m >= packed_b_field_element::WIDTH {
+                    packed_b_field_element::butterflies(lo, hi, powers_of_omega, stride);
+                    continue;
+                }
+                for (j, (l, h)) in lo.iter_mut().zip(hi).enumerate() {
+                    let u = *l;
+                    let v = *h * powers_of_omega[j * stride];
+                    *l = u + v;
+                    *h = u - v;
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    fn bit_reverse<T>(&self, x: &mut [T]) {
+        for (k, &rk) in self.bit_reversal.iter().enumerate() {
+            let rk = rk as usize;
+            if k < rk {
+                x.swap(rk, k);
+            }
+        }
+    }
+
+    /// `omega^exponent` or `omega^-exponent`, depending on the direction. The
+    /// exponent must be smaller than n/2.
+    #[inline]
+    fn twiddle(&self, exponent: usize, direction: Direction) -> B {
+        match (direction, exponent) {
+            (Direction::Forward, _) => self.powers_of_omega[exponent],
+            (Direction::Inverse, 0) => B::ONE,
+
+            // omega^-i = omega^(n-i) = omega^(n/2) · omega^(n/2-i) = -omega^(n/2-i)
+            (Direction::Inverse, _) => -self.powers_of_omega[self.powers_of_omega.len() - exponent],
+        }
+    }
+}
+
+/// Write the transpose of the row-major matrix `source`, which has
+/// `num_source_cols` many columns, to `destination`.
+///
+/// Works on blocks of destination rows to keep the reads from `source` somewhat
+/// local.
+fn transpose<FF: Copy + Send + Sync>(
+    source: &[FF],
+    destination: &mut [FF],
+    num_source_cols: usize,
+) {
+    const BLOCK_SIZE: usize = 16;
+
+    debug_assert_eq!(source.len(), destination.len());
+    let num_source_rows = source.len() / num_source_cols;
+    destination
+        .par_chunks_mut(BLOCK_SIZE * num_source_rows)
+        .enumerate()
+        .for_each(|(block_index, block)| {
+            let first_col = block_index * BLOCK_SIZE;
+            let num_cols = block.len() / num_source_rows;
+            for row in 0..num_source_rows {
+                let source_row = &source[row * num_source_cols..][first_col..][..num_cols];
+                for (col, &elem) in source_row.iter().enumerate() {
+                    block[col * num_source_rows + row] = elem;
+                }
+            }
+        });
+}
+
+/// The odd prime factors that [`ntt_any_length`] handles with dedicated
+/// butterflies. All other odd prime factors are handled by Bluestein's
+/// algorithm.
+const MIXED_RADICES: [usize; 3] = [3, 5, 17];
+
+/// ## Perform NTT on slices of any admissible length
+///
+/// Like [`ntt`], but `x` can have any length `n` for which a primitive `n`th
+/// root of unity exists, _i.e._, any divisor of 2^32·3·5·17·257·65537. `omega`
+/// must be a primitive `n`th root of unity, for example the one returned by
+/// [`BFieldElement::primitive_root_of_unity_of_any_order`].
+///
+/// - If `n` is a power of two, this is the same as [`ntt`].
+/// - If `n` is of the form 2^k·3^a·5^b·17^c, a mixed-radix Cooley-Tukey
+///   algorithm with radix-3, radix-5, and radix-17 butterflies is used. The
+///   remaining power of two is delegated to [`ntt`].
+/// - For all other lengths, [Bluestein's algorithm][bluestein] reduces the
+///   transform to a convolution of power-of-two length.
+///
+/// This transform is performed in-place.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::ntt::intt_any_length;
+/// # use twenty_first::math::ntt::ntt_any_length;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// let omega = BFieldElement::primitive_root_of_unity_of_any_order(6).unwrap();
+/// let mut values = bfe_vec![1, 2, 3, 4, 5, 6];
+/// ntt_any_length(&mut values, omega);
+/// intt_any_length(&mut values, omega);
+/// assert_eq!(bfe_vec![1, 2, 3, 4, 5, 6], values);
+/// ```
+///
+/// [bluestein]: https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein's_algorithm
+pub fn ntt_any_length<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField) {
+    let n = x.len();
+
+    // `omega` must be a primitive root of unity of order `n`
+    debug_assert!(
+        omega.mod_pow_u32(n as u32).is_one(),
+        "Got {omega} which is not a {n}th root of 1"
+    );
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        ntt(x, omega, n.ilog2());
+        return;
+    }
+
+    let transformed = if is_mixed_radix_length(n) {
+        mixed_radix_ntt(x, omega)
+    } else {
+        bluestein_ntt(x, omega)
+    };
+    x.copy_from_slice(&transformed);
+}
+
+/// ## Perform INTT on slices of any admissible length
+///
+/// The inverse of [`ntt_any_length`].
+///
+/// This transform is performed in-place.
+pub fn intt_any_length<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField) {
+    if x.is_empty() {
+        return;
+    }
+
+    ntt_any_length(x, omega.inverse());
+    let n_inverse = FF::BaseField::from(x.len() as u64).inverse();
     for elem in x.iter_mut() {
-        *elem *= n_inv_or_zero
+        *elem *= n_inverse;
     }
 }
 
+fn is_mixed_radix_length(mut n: usize) -> bool {
+    for radix in MIXED_RADICES {
+        while n.is_multiple_of(radix) {
+            n /= radix;
+        }
+    }
+    n.is_power_of_two()
+}
+
+/// Decimation-in-time Cooley-Tukey for lengths n = radix·m. With Y_s the NTT
+/// of the `s`th of the `radix` many interleaved subsequences of `x`,
+///
+/// X[k + m·q] = Σ_s ω_radix^(s·q) · ω^(s·k) · Y_s[k]
+///
+/// where ω_radix = ω^m is a primitive `radix`th root of unity.
+fn mixed_radix_ntt<FF: FiniteField>(x: &[FF], omega: FF::BaseField) -> Vec<FF> {
+    let n = x.len();
+    if n.is_power_of_two() {
+        let mut x = x.to_vec();
+        ntt(&mut x, omega, n.ilog2());
+        return x;
+    }
+
+    let radix = MIXED_RADICES
+        .into_iter()
+        .find(|&r| n.is_multiple_of(r))
+        .unwrap();
+    let m = n / radix;
+
+    let omega_m = omega.mod_pow_u32(radix as u32);
+    let sub_transforms = (0..radix)
+        .into_par_iter()
+        .map(|s| {
+            let subsequence = x.iter().skip(s).step_by(radix).copied().collect::<Vec<_>>();
+            mixed_radix_ntt(&subsequence, omega_m)
+        })
+        .collect::<Vec<_>>();
+
+    let omega_radix = omega.mod_pow_u32(m as u32);
+    let powers_of_omega_radix = (0..radix as u32)
+        .map(|i| omega_radix.mod_pow_u32(i))
+        .collect::<Vec<_>>();
+
+    let mut result = vec![FF::ZERO; n];
+    let mut twiddled = vec![FF::ZERO; radix];
+    let mut omega_k = FF::BaseField::ONE;
+    for k in 0..m {
+        let mut twiddle = FF::BaseField::ONE;
+        for (twiddled_value, sub_transform) in twiddled.iter_mut().zip(&sub_transforms) {
+            *twiddled_value = sub_transform[k];
+            *twiddled_value *= twiddle;
+            twiddle *= omega_k;
+        }
+
+        for q in 0..radix {
+            let mut sum = FF::ZERO;
+            for (s, &twiddled_value) in twiddled.iter().enumerate() {
+                let mut term = twiddled_value;
+                term *= powers_of_omega_radix[s * q % radix];
+                sum += term;
+            }
+            result[k + m * q] = sum;
+        }
+
+        omega_k *= omega;
+    }
+
+    result
+}
+
+/// Bluestein's algorithm. The identity j·k = C(j+k, 2) - C(j, 2) - C(k, 2),
+/// where C(i, 2) = i·(i-1)/2 is a binomial coefficient, turns the NTT into a
+/// correlation:
+///
+/// X[k] = ω^-C(k, 2) · Σ_j (x[j] · ω^-C(j, 2)) · ω^C(j+k, 2)
+///
+/// The correlation is computed as a convolution of power-of-two length. Unlike
+/// the more common variant based on j·k = (j² + k² - (k-j)²)/2, this does not
+/// require a square root of ω.
+fn bluestein_ntt<FF: FiniteField>(x: &[FF], omega: FF::BaseField) -> Vec<FF> {
+    let n = x.len();
+    let chirp = |root: FF::BaseField, len: usize| {
+        // ω^C(i+1, 2) = ω^C(i, 2) · ω^i
+        let mut chirp = Vec::with_capacity(len);
+        let mut root_c = FF::BaseField::ONE;
+        let mut root_i = FF::BaseField::ONE;
+        for _ in 0..len {
+            chirp.push(root_c);
+            root_c *= root_i;
+            root_i *= root;
+        }
+        chirp
+    };
+    let chirp_up = chirp(omega, 2 * n - 1);
+    let chirp_down = chirp(omega.inverse(), n);
+
+    let convolution_length = (2 * n - 1).next_power_of_two();
+    let convolution_omega = FF::BaseField::primitive_root_of_unity(convolution_length as u64)
+        .expect("NTT length should be small enough for Bluestein's algorithm");
+    let plan = NttPlan::cached(convolution_omega, convolution_length.ilog2());
+
+    // reversing the first operand turns the correlation into a convolution
+    let mut lhs = vec![FF::ZERO; convolution_length];
+    for (j, (&x_j, &chirp_j)) in x.iter().zip(&chirp_down).enumerate() {
+        lhs[n - 1 - j] = x_j;
+        lhs[n - 1 - j] *= chirp_j;
+    }
+    let mut rhs = vec![FF::BaseField::ZERO; convolution_length];
+    rhs[..chirp_up.len()].copy_from_slice(&chirp_up);
+
+    plan.forward(&mut lhs);
+    plan.forward(&mut rhs);
+    for (l, r) in lhs.iter_mut().zip(rhs) {
+        *l *= r;
+    }
+    plan.inverse(&mut lhs);
+
+    lhs.into_iter()
+        .skip(n - 1)
+        .zip(chirp_down)
+        .map(|(mut value, chirp_k)| {
+            value *= chirp_k;
+            value
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Inverse,
+}
+
 #[inline]
 pub fn bitreverse_usize(mut n: usize, l: usize) -> usize {
     let mut r = 0;
@@ -155,7 +998,7 @@ pub fn bitreverse_order<FF>(array: &mut [FF]) {
 ///
 /// In that case, be sure to use the matching `intt_noswap` and
 /// don't forget to unscale by n, e.g. using `unscale`.
-pub fn ntt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], omega: BFieldElement) {
+pub fn ntt_noswap<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField) {
     let n: usize = x.len();
 
     // `n` must be a power of 2
@@ -173,8 +1016,8 @@ pub fn ntt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], omeg
         logn += 1;
     }
 
-    let mut powers_of_omega_bitreversed = vec![BFieldElement::ZERO; n];
-    let mut omegai = BFieldElement::ONE;
+    let mut powers_of_omega_bitreversed = vec![FF::BaseField::ZERO; n];
+    let mut omegai = FF::BaseField::ONE;
     for i in 0..n / 2 {
         powers_of_omega_bitreversed[bitreverse_usize(i, logn - 1)] = omegai;
         omegai *= omega;
@@ -202,7 +1045,7 @@ pub fn ntt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], omeg
 
 /// Compute the inverse NTT, assuming that the array is presented in
 /// bitreversed order. Also, don't unscale by n afterwards.
-pub fn intt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], omega: BFieldElement) {
+pub fn intt_noswap<FF: FiniteField>(x: &mut [FF], omega: FF::BaseField) {
     let n = x.len();
     let omega_inverse = omega.inverse();
 
@@ -228,7 +1071,7 @@ pub fn intt_noswap<FF: FiniteField + MulAssign<BFieldElement>>(x: &mut [FF], ome
         let w_m = omega_inverse.mod_pow_u32((n / (2 * m)).try_into().unwrap());
         let mut k = 0;
         while k < n {
-            let mut w = BFieldElement::ONE;
+            let mut w = FF::BaseField::ONE;
             for j in 0..m {
                 let u = x[k + j];
                 let mut v = x[k + j + m];
@@ -543,4 +1386,318 @@ mod fast_ntt_attempt_tests {
             assert_eq!(a1, a2);
         }
     }
+
+    fn naive_dft<FF: FiniteField + MulAssign<BFieldElement>>(
+        coefficients: &[FF],
+        points: impl IntoIterator<Item = BFieldElement>,
+    ) -> Vec<FF> {
+        let polynomial = Polynomial::new(coefficients.to_vec());
+        points
+            .into_iter()
+            .map(|p| {
+                let mut point = FF::ONE;
+                point *= p;
+                polynomial.evaluate(point)
+            })
+            .collect()
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_plan_agrees_with_naive_evaluation(
+        #[strategy(0_u32..9)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<XFieldElement>,
+    ) {
+        let plan: NttPlan = NttPlan::for_length(1 << log_2_of_n).unwrap();
+        let omega = plan.omega();
+        let points = (0..plan.len()).map(|i| omega.mod_pow(i as u64));
+
+        let mut values = coefficients.clone();
+        plan.forward(&mut values);
+        prop_assert_eq!(naive_dft(&coefficients, points), values.clone());
+
+        plan.inverse(&mut values);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 20)]
+    fn ntt_plan_coset_transforms_agree_with_naive_evaluation(
+        #[strategy(0_u32..9)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<BFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+    ) {
+        let plan: NttPlan = NttPlan::for_length(1 << log_2_of_n).unwrap();
+        let omega = plan.omega();
+        let points = (0..plan.len()).map(|i| offset * omega.mod_pow(i as u64));
+
+        let mut values = coefficients.clone();
+        plan.forward_coset(&mut values, offset);
+        prop_assert_eq!(naive_dft(&coefficients, points), values.clone());
+
+        plan.inverse_coset(&mut values, offset);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 10)]
+    fn ntt_plan_agrees_with_free_functions_for_non_canonical_root(
+        #[strategy(1_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] coefficients: Vec<BFieldElement>,
+    ) {
+        // any odd power of a primitive root of unity is a primitive root of unity
+        let n = 1 << log_2_of_n;
+        let omega = BFieldElement::primitive_root_of_unity(n)
+            .unwrap()
+            .mod_pow(3);
+        let plan = NttPlan::new(omega, log_2_of_n);
+
+        let mut plan_values = coefficients.clone();
+        plan.forward(&mut plan_values);
+        let mut free_function_values = coefficients.clone();
+        ntt(&mut free_function_values, omega, log_2_of_n);
+        prop_assert_eq!(&plan_values, &free_function_values);
+
+        plan.inverse(&mut plan_values);
+        intt(&mut free_function_values, omega, log_2_of_n);
+        prop_assert_eq!(plan_values, free_function_values);
+    }
+
+    #[test]
+    fn ntt_plan_for_length_that_is_not_a_power_of_two_does_not_exist() {
+        for n in [0, 3, 5, 6, 7, 12, 100] {
+            assert!(NttPlan::<BFieldElement>::for_length(n).is_none());
+        }
+    }
+
+    #[test]
+    fn cached_ntt_plans_are_shared() {
+        let omega = BFieldElement::primitive_root_of_unity(1 << 5).unwrap();
+        let plan = NttPlan::cached(omega, 5);
+        let same_plan = NttPlan::cached(omega, 5);
+        assert!(Arc::ptr_eq(&plan, &same_plan));
+        assert_eq!(NttPlan::new(omega, 5), *plan);
+
+        let other_omega = omega.mod_pow(3);
+        let other_plan = NttPlan::cached(other_omega, 5);
+        assert!(!Arc::ptr_eq(&plan, &other_plan));
+    }
+
+    #[test]
+    fn ntt_plans_for_non_canonical_roots_of_unity_are_not_cached() {
+        let omega = BFieldElement::primitive_root_of_unity(1 << 5)
+            .unwrap()
+            .mod_pow(3);
+        let plan = NttPlan::cached(omega, 5);
+        let same_plan = NttPlan::cached(omega, 5);
+        assert!(!Arc::ptr_eq(&plan, &same_plan));
+        assert_eq!(plan, same_plan);
+    }
+
+    #[test]
+    fn sub_plans_of_cached_ntt_plans_use_canonical_roots_of_unity() {
+        let omega = BFieldElement::primitive_root_of_unity(1 << 20).unwrap();
+        for k in 0..20 {
+            let sub_omega = omega.mod_pow(1 << k);
+            assert!(NttPlan::is_cacheable(sub_omega, 20 - k));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot transform slice of length")]
+    fn ntt_plan_cannot_transform_slice_of_wrong_length() {
+        let plan = NttPlan::for_length(8).unwrap();
+        plan.forward(&mut [BFieldElement::ZERO; 4]);
+    }
+
+    #[proptest(cases = 20)]
+    fn parallel_ntt_is_equivalent_to_ntt_for_b_field_elements(
+        #[strategy(0_u32..13)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] input: Vec<BFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let plan = NttPlan::new(omega, log_2_of_n);
+
+        let mut parallel_values = input.clone();
+        plan.parallel_forward(&mut parallel_values);
+        let mut values = input.clone();
+        ntt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(&values, &parallel_values);
+
+        plan.parallel_inverse(&mut parallel_values);
+        intt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(&values, &parallel_values);
+        prop_assert_eq!(input, parallel_values);
+    }
+
+    #[proptest(cases = 20)]
+    fn parallel_ntt_is_equivalent_to_ntt_for_x_field_elements(
+        #[strategy(0_u32..13)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] input: Vec<XFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let plan = NttPlan::new(omega, log_2_of_n);
+
+        let mut parallel_values = input.clone();
+        plan.parallel_forward(&mut parallel_values);
+        let mut values = input.clone();
+        ntt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(&values, &parallel_values);
+
+        plan.parallel_inverse(&mut parallel_values);
+        intt(&mut values, omega, log_2_of_n);
+        prop_assert_eq!(&values, &parallel_values);
+        prop_assert_eq!(input, parallel_values);
+    }
+
+    #[test]
+    fn ntt_above_parallelization_cutoff_is_equivalent_to_sequential_ntt() {
+        let n = DEFAULT_PARALLELIZATION_CUTOFF;
+        let plan = NttPlan::for_length(n).unwrap();
+        let input: Vec<XFieldElement> = random_elements(n);
+
+        let mut values = input.clone();
+        plan.forward(&mut values);
+        let mut sequential_values = input.clone();
+        plan.sequential(&mut sequential_values, Direction::Forward);
+        assert_eq!(sequential_values, values);
+
+        plan.inverse(&mut values);
+        plan.sequential(&mut sequential_values, Direction::Inverse);
+        assert_eq!(sequential_values, values);
+        assert_eq!(input, values);
+    }
+
+    fn column_wise_ntt<FF: FiniteField>(
+        columns: &[Vec<FF>],
+        offset: FF::BaseField,
+    ) -> Vec<Vec<FF>> {
+        let plan = NttPlan::for_length(columns[0].len()).unwrap();
+        let mut columns = columns.to_vec();
+        for column in &mut columns {
+            plan.forward_coset(column, offset);
+        }
+        columns
+    }
+
+    fn to_row_major<FF: Copy>(columns: &[Vec<FF>]) -> Vec<FF> {
+        (0..columns[0].len())
+            .flat_map(|row| columns.iter().map(move |column| column[row]))
+            .collect()
+    }
+
+    #[proptest(cases = 20)]
+    fn batch_ntt_is_equivalent_to_column_wise_ntt(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(vec(arb(), 1 << #log_2_of_n), 1..10))] columns: Vec<Vec<XFieldElement>>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let expected = column_wise_ntt(&columns, BFieldElement::ONE);
+
+        let mut column_major = columns.concat();
+        batch_ntt(
+            &mut column_major,
+            MatrixLayout::ColumnMajor,
+            omega,
+            log_2_of_n,
+        );
+        prop_assert_eq!(expected.concat(), column_major.clone());
+
+        let mut row_major = to_row_major(&columns);
+        batch_ntt(&mut row_major, MatrixLayout::RowMajor, omega, log_2_of_n);
+        prop_assert_eq!(to_row_major(&expected), row_major.clone());
+
+        batch_intt(
+            &mut column_major,
+            MatrixLayout::ColumnMajor,
+            omega,
+            log_2_of_n,
+        );
+        prop_assert_eq!(columns.concat(), column_major);
+
+        batch_intt(&mut row_major, MatrixLayout::RowMajor, omega, log_2_of_n);
+        prop_assert_eq!(to_row_major(&columns), row_major);
+    }
+
+    #[proptest(cases = 20)]
+    fn batch_coset_ntt_is_equivalent_to_column_wise_coset_ntt(
+        #[strategy(0_u32..8)] log_2_of_n: u32,
+        #[strategy(vec(vec(arb(), 1 << #log_2_of_n), 1..10))] columns: Vec<Vec<BFieldElement>>,
+        #[strategy(arb())]
+        #[filter(!#offset.is_zero())]
+        offset: BFieldElement,
+        #[strategy(prop_oneof![Just(MatrixLayout::RowMajor), Just(MatrixLayout::ColumnMajor)])]
+        layout: MatrixLayout,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let flatten = |cols: &[Vec<_>]| match layout {
+            MatrixLayout::RowMajor => to_row_major(cols),
+            MatrixLayout::ColumnMajor => cols.concat(),
+        };
+
+        let mut matrix = flatten(&columns);
+        batch_coset_ntt(&mut matrix, layout, offset, omega, log_2_of_n);
+        prop_assert_eq!(flatten(&column_wise_ntt(&columns, offset)), matrix.clone());
+
+        batch_coset_intt(&mut matrix, layout, offset, omega, log_2_of_n);
+        prop_assert_eq!(flatten(&columns), matrix);
+    }
+
+    #[test]
+    fn batch_ntt_on_empty_matrix_does_nothing() {
+        let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+        let mut matrix: Vec<BFieldElement> = vec![];
+        batch_ntt(&mut matrix, MatrixLayout::RowMajor, omega, 3);
+        assert!(matrix.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot have columns of length")]
+    fn batch_ntt_on_matrix_with_incomplete_column_panics() {
+        let omega = BFieldElement::primitive_root_of_unity(8).unwrap();
+        let mut matrix = [BFieldElement::ZERO; 12];
+        batch_ntt(&mut matrix, MatrixLayout::ColumnMajor, omega, 3);
+    }
+
+    #[proptest(cases = 10)]
+    fn ntt_of_any_length_agrees_with_naive_evaluation(
+        #[strategy(prop_oneof![
+            Just(3_usize), Just(5), Just(6), Just(12), Just(15), Just(17), Just(24), Just(51),
+            Just(85), Just(96), Just(255), Just(257), Just(514), Just(771)
+        ])]
+        n: usize,
+        #[strategy(vec(arb(), #n))] coefficients: Vec<XFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity_of_any_order(n as u64).unwrap();
+        let points = (0..n).map(|i| omega.mod_pow(i as u64));
+
+        let mut values = coefficients.clone();
+        ntt_any_length(&mut values, omega);
+        prop_assert_eq!(naive_dft(&coefficients, points), values.clone());
+
+        intt_any_length(&mut values, omega);
+        prop_assert_eq!(coefficients, values);
+    }
+
+    #[proptest(cases = 10)]
+    fn ntt_of_any_length_agrees_with_ntt_for_powers_of_two(
+        #[strategy(0_u32..10)] log_2_of_n: u32,
+        #[strategy(vec(arb(), 1 << #log_2_of_n))] input: Vec<BFieldElement>,
+    ) {
+        let omega = BFieldElement::primitive_root_of_unity(1 << log_2_of_n).unwrap();
+        let mut values = input.clone();
+        ntt(&mut values, omega, log_2_of_n);
+        let mut any_length_values = input;
+        ntt_any_length(&mut any_length_values, omega);
+        prop_assert_eq!(values, any_length_values);
+    }
+
+    #[test]
+    fn mixed_radix_and_bluestein_ntt_agree() {
+        for n in [3, 5, 15, 17, 48, 85, 255, 3 * 5 * 17 * 4] {
+            let omega = BFieldElement::primitive_root_of_unity_of_any_order(n as u64).unwrap();
+            let input: Vec<BFieldElement> = random_elements(n);
+            assert!(is_mixed_radix_length(n));
+            assert_eq!(mixed_radix_ntt(&input, omega), bluestein_ntt(&input, omega));
+        }
+    }
 }
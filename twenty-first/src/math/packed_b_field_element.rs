@@ -0,0 +1,907 @@
+use std::any::TypeId;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+use std::sync::OnceLock;
+
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use num_traits::Zero;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::traits::Inverse;
+
+/// The number of lanes of a [`PackedBFieldElement`].
+pub const WIDTH: usize = 8;
+
+/// [`WIDTH`] many [`BFieldElement`]s, on which arithmetic is performed
+/// lane-wise using SIMD instructions where available.
+///
+/// On `x86_64`, the instruction set is selected at runtime: AVX-512 if the CPU
+/// supports it, AVX2 otherwise. On all other platforms, and on CPUs supporting
+/// neither, a portable implementation is used. All implementations produce
+/// identical results.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::packed_b_field_element::PackedBFieldElement;
+/// let a = PackedBFieldElement::new(bfe_array![1, 2, 3, 4, 5, 6, 7, 8]);
+/// let b = PackedBFieldElement::splat(bfe!(10));
+/// assert_eq!(bfe_array![10, 20, 30, 40, 50, 60, 70, 80], (a * b).to_array());
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PackedBFieldElement([BFieldElement; WIDTH]);
+
+impl PackedBFieldElement {
+    pub const ZERO: Self = Self([BFieldElement::ZERO; WIDTH]);
+    pub const ONE: Self = Self([BFieldElement::ONE; WIDTH]);
+
+    pub const fn new(lanes: [BFieldElement; WIDTH]) -> Self {
+        Self(lanes)
+    }
+
+    /// All lanes set to the same `element`.
+    pub const fn splat(element: BFieldElement) -> Self {
+        Self([element; WIDTH])
+    }
+
+    /// The first [`WIDTH`] elements of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice contains fewer than [`WIDTH`] elements.
+    pub fn from_slice(slice: &[BFieldElement]) -> Self {
+        Self(slice[..WIDTH].try_into().unwrap())
+    }
+
+    /// Write all lanes to the first [`WIDTH`] elements of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice contains fewer than [`WIDTH`] elements.
+    pub fn write_to_slice(self, slice: &mut [BFieldElement]) {
+        slice[..WIDTH].copy_from_slice(&self.0);
+    }
+
+    pub const fn to_array(self) -> [BFieldElement; WIDTH] {
+        self.0
+    }
+
+    #[must_use]
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// The lane-wise multiplicative inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is zero.
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self(self.0.map(|lane| lane.inverse()))
+    }
+
+    #[inline(always)]
+    fn raw(self) -> [u64; WIDTH] {
+        std::array::from_fn(|i| self.0[i].raw_u64())
+    }
+
+    #[inline(always)]
+    fn from_raw(raw: [u64; WIDTH]) -> Self {
+        Self(std::array::from_fn(|i| BFieldElement::from_raw_u64(raw[i])))
+    }
+
+    fn lane_wise(
+        self,
+        rhs: Self,
+        operation: fn(BFieldElement, BFieldElement) -> BFieldElement,
+    ) -> Self {
+        Self(std::array::from_fn(|i| operation(self.0[i], rhs.0[i])))
+    }
+}
+
+impl From<[BFieldElement; WIDTH]> for PackedBFieldElement {
+    fn from(lanes: [BFieldElement; WIDTH]) -> Self {
+        Self::new(lanes)
+    }
+}
+
+impl From<PackedBFieldElement> for [BFieldElement; WIDTH] {
+    fn from(packed: PackedBFieldElement) -> Self {
+        packed.to_array()
+    }
+}
+
+impl Zero for PackedBFieldElement {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self == &Self::ZERO
+    }
+}
+
+impl ConstZero for PackedBFieldElement {
+    const ZERO: Self = Self::ZERO;
+}
+
+/// The instruction set to use for packed arithmetic.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Backend {
+    Portable,
+
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+
+    #[cfg(target_arch = "x86_64")]
+    Avx512,
+}
+
+impl Backend {
+    /// The fastest instruction set supported by the CPU, detected once.
+    #[inline]
+    fn get() -> Self {
+        static BACKEND: OnceLock<Backend> = OnceLock::new();
+        *BACKEND.get_or_init(Self::detect)
+    }
+
+    fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx512f") {
+                return Self::Avx512;
+            }
+            if is_x86_feature_detected!("avx2") {
+                return Self::Avx2;
+            }
+        }
+        Self::Portable
+    }
+}
+
+macro_rules! dispatch_binary_operation {
+    ($self:ident, $rhs:ident, $operation:ident) => {
+        match Backend::get() {
+            Backend::Portable => $self.lane_wise($rhs, |a, b| a.$operation(b)),
+
+            // SAFETY: the CPU supports the required instruction set
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => Self::from_raw(unsafe { avx2::$operation($self.raw(), $rhs.raw()) }),
+
+            // SAFETY: the CPU supports the required instruction set
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 => {
+                Self::from_raw(unsafe { avx512::$operation($self.raw(), $rhs.raw()) })
+            }
+        }
+    };
+}
+
+impl Add for PackedBFieldElement {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        dispatch_binary_operation!(self, rhs, add)
+    }
+}
+
+impl Sub for PackedBFieldElement {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        dispatch_binary_operation!(self, rhs, sub)
+    }
+}
+
+impl Mul for PackedBFieldElement {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        dispatch_binary_operation!(self, rhs, mul)
+    }
+}
+
+impl Mul<BFieldElement> for PackedBFieldElement {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: BFieldElement) -> Self {
+        self * Self::splat(rhs)
+    }
+}
+
+impl Neg for PackedBFieldElement {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl AddAssign for PackedBFieldElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for PackedBFieldElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for PackedBFieldElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<BFieldElement> for PackedBFieldElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: BFieldElement) {
+        *self = *self * rhs;
+    }
+}
+
+/// Set `lo[i]` to `lo[i] + hi[i]·t` and `hi[i]` to `lo[i] - hi[i]·t` with
+/// twiddle factor `t = twiddles[i·stride]` for all `i`, _i.e._, perform the
+/// butterflies of one block of one layer of a radix-2 NTT.
+///
+/// # Panics
+///
+/// Panics if `lo` and `hi` are not of equal length, or if `twiddles` is too
+/// short.
+pub(crate) fn butterflies(
+    lo: &mut [BFieldElement],
+    hi: &mut [BFieldElement],
+    twiddles: &[BFieldElement],
+    stride: usize,
+) {
+    assert_eq!(lo.len(), hi.len());
+    if let Some(last) = lo.len().checked_sub(1) {
+        assert!(last * stride < twiddles.len());
+    }
+    match Backend::get() {
+        Backend::Portable => portable::butterflies(lo, hi, twiddles, stride),
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => unsafe { avx2::butterflies(lo, hi, twiddles, stride) },
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => unsafe { avx512::butterflies(lo, hi, twiddles, stride) },
+    }
+}
+
+/// Call `f`, compiled for the instruction set used by [`PackedBFieldElement`]s.
+///
+/// Arithmetic on [`PackedBFieldElement`]s inside `f` can then be inlined,
+/// which keeps the lanes in vector registers across operations. Any other code
+/// in `f` may be vectorized using the same instruction set.
+#[inline]
+pub(crate) fn with_backend<R>(f: impl FnOnce() -> R) -> R {
+    match Backend::get() {
+        Backend::Portable => f(),
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => unsafe { avx2::call(f) },
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => unsafe { avx512::call(f) },
+    }
+}
+
+/// Multiply every element of the slice by `scalar`.
+pub(crate) fn scale(elements: &mut [BFieldElement], scalar: BFieldElement) {
+    match Backend::get() {
+        Backend::Portable => portable::scale(elements, scalar),
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => unsafe { avx2::scale(elements, scalar) },
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => unsafe { avx512::scale(elements, scalar) },
+    }
+}
+
+/// [Montgomery's batch inversion](crate::math::traits::FiniteField::batch_inversion),
+/// run on [`WIDTH`] interleaved sub-sequences at once.
+///
+/// # Panics
+///
+/// Panics if any element is zero.
+pub(crate) fn batch_inversion(mut elements: Vec<BFieldElement>) -> Vec<BFieldElement> {
+    assert!(
+        !elements.iter().any(|e| e.is_zero()),
+        "Cannot do batch inversion on zero"
+    );
+    let num_elements = elements.len();
+    elements.resize(num_elements.next_multiple_of(WIDTH), BFieldElement::ONE);
+
+    let packed_elements = elements
+        .chunks_exact(WIDTH)
+        .map(PackedBFieldElement::from_slice)
+        .collect::<Vec<_>>();
+    let mut partial_products = Vec::with_capacity(packed_elements.len());
+    let mut accumulator = PackedBFieldElement::ONE;
+    for &packed_element in &packed_elements {
+        partial_products.push(accumulator);
+        accumulator *= packed_element;
+    }
+
+    accumulator = accumulator.inverse();
+    for (i, chunk) in elements.chunks_exact_mut(WIDTH).enumerate().rev() {
+        let inverse = accumulator * partial_products[i];
+        accumulator *= packed_elements[i];
+        inverse.write_to_slice(chunk);
+    }
+
+    elements.truncate(num_elements);
+    elements
+}
+
+/// The slice as a slice of [`BFieldElement`]s if `T` is [`BFieldElement`],
+/// [`None`] otherwise. Allows generic code to use the packed routines for the
+/// base field.
+pub(crate) fn as_b_field_elements<T: 'static>(slice: &[T]) -> Option<&[BFieldElement]> {
+    if !is_b_field_element::<T>() {
+        return None;
+    }
+
+    // SAFETY: `T` is `BFieldElement`
+    Some(unsafe { std::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) })
+}
+
+/// Like [`as_b_field_elements`], but for mutable slices.
+pub(crate) fn as_b_field_elements_mut<T: 'static>(slice: &mut [T]) -> Option<&mut [BFieldElement]> {
+    if !is_b_field_element::<T>() {
+        return None;
+    }
+
+    // SAFETY: `T` is `BFieldElement`
+    Some(unsafe { std::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) })
+}
+
+/// Whether `T` is [`BFieldElement`].
+fn is_b_field_element<T: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<BFieldElement>()
+}
+
+mod portable {
+    use super::*;
+
+    pub(super) fn butterflies(
+        lo: &mut [BFieldElement],
+        hi: &mut [BFieldElement],
+        twiddles: &[BFieldElement],
+        stride: usize,
+    ) {
+        for ((l, h), &twiddle) in lo.iter_mut().zip(hi).zip(twiddles.iter().step_by(stride)) {
+            let u = *l;
+            let v = *h * twiddle;
+            *l = u + v;
+            *h = u - v;
+        }
+    }
+
+    pub(super) fn scale(elements: &mut [BFieldElement], scalar: BFieldElement) {
+        for element in elements {
+            *element *= scalar;
+        }
+    }
+}
+
+/// The arithmetic mirrors that of [`BFieldElement`] but operates on the raw
+/// Montgomery representation. Since `x86_64` lacks unsigned 64-bit comparisons
+/// before AVX-512, they are emulated by flipping the sign bit.
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use std::arch::x86_64::*;
+
+    use super::*;
+
+    const LANES: usize = 4;
+    const P: i64 = BFieldElement::P as i64;
+
+    /// 2^64 mod P
+    const EPSILON: i64 = u32::MAX as i64;
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(elements: &[BFieldElement]) -> __m256i {
+        load_strided(elements, 1)
+    }
+
+    /// Load the elements at indices `0, stride, 2·stride, …`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load_strided(elements: &[BFieldElement], stride: usize) -> __m256i {
+        let raw: [u64; LANES] = std::array::from_fn(|i| elements[i * stride].raw_u64());
+        _mm256_loadu_si256(raw.as_ptr().cast())
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(elements: &mut [BFieldElement], vector: __m256i) {
+        let mut raw = [0_u64; LANES];
+        _mm256_storeu_si256(raw.as_mut_ptr().cast(), vector);
+        for (element, raw_element) in elements[..LANES].iter_mut().zip(raw) {
+            *element = BFieldElement::from_raw_u64(raw_element);
+        }
+    }
+
+    /// Lane-wise `a > b`, interpreting lanes as unsigned integers.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn greater_than(a: __m256i, b: __m256i) -> __m256i {
+        let sign_bit = _mm256_set1_epi64x(i64::MIN);
+        _mm256_cmpgt_epi64(_mm256_xor_si256(a, sign_bit), _mm256_xor_si256(b, sign_bit))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add_vectors(a: __m256i, b: __m256i) -> __m256i {
+        let sum = _mm256_add_epi64(a, b);
+        let carry = greater_than(a, sum);
+        let sum = _mm256_add_epi64(sum, _mm256_and_si256(carry, _mm256_set1_epi64x(EPSILON)));
+        let p = _mm256_set1_epi64x(P);
+        let is_below_p = greater_than(p, sum);
+        _mm256_sub_epi64(sum, _mm256_andnot_si256(is_below_p, p))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sub_vectors(a: __m256i, b: __m256i) -> __m256i {
+        let difference = _mm256_sub_epi64(a, b);
+        let borrow = greater_than(b, a);
+        _mm256_sub_epi64(
+            difference,
+            _mm256_and_si256(borrow, _mm256_set1_epi64x(EPSILON)),
+        )
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_vectors(a: __m256i, b: __m256i) -> __m256i {
+        // 64×64 → 128 bit multiplication from 32×32 → 64 bit multiplications
+        let a_hi = _mm256_srli_epi64::<32>(a);
+        let b_hi = _mm256_srli_epi64::<32>(b);
+        let lo_lo = _mm256_mul_epu32(a, b);
+        let lo_hi = _mm256_mul_epu32(a, b_hi);
+        let hi_lo = _mm256_mul_epu32(a_hi, b);
+        let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+        let low_32_bits = _mm256_set1_epi64x(u32::MAX.into());
+        let t = _mm256_add_epi64(hi_lo, _mm256_srli_epi64::<32>(lo_lo));
+        let u = _mm256_add_epi64(lo_hi, _mm256_and_si256(t, low_32_bits));
+        let product_lo = _mm256_or_si256(
+            _mm256_slli_epi64::<32>(u),
+            _mm256_and_si256(lo_lo, low_32_bits),
+        );
+        let product_hi = _mm256_add_epi64(
+            _mm256_add_epi64(hi_hi, _mm256_srli_epi64::<32>(t)),
+            _mm256_srli_epi64::<32>(u),
+        );
+
+        montgomery_reduce(product_lo, product_hi)
+    }
+
+    /// See [`BFieldElement::montyred`].
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn montgomery_reduce(x_lo: __m256i, x_hi: __m256i) -> __m256i {
+        let a = _mm256_add_epi64(x_lo, _mm256_slli_epi64::<32>(x_lo));
+        let overflow = greater_than(x_lo, a);
+
+        // `overflow` is -1 in lanes that overflowed
+        let b = _mm256_add_epi64(_mm256_sub_epi64(a, _mm256_srli_epi64::<32>(a)), overflow);
+
+        let r = _mm256_sub_epi64(x_hi, b);
+        let borrow = greater_than(b, x_hi);
+        _mm256_sub_epi64(r, _mm256_and_si256(borrow, _mm256_set1_epi64x(EPSILON)))
+    }
+
+    macro_rules! packed_operation {
+        ($name:ident, $vector_operation:ident) => {
+            #[inline]
+            #[target_feature(enable = "avx2")]
+            pub(super) unsafe fn $name(a: [u64; WIDTH], b: [u64; WIDTH]) -> [u64; WIDTH] {
+                let mut result = [0; WIDTH];
+                for ((a, b), result) in a
+                    .chunks_exact(LANES)
+                    .zip(b.chunks_exact(LANES))
+                    .zip(result.chunks_exact_mut(LANES))
+                {
+                    let a = _mm256_loadu_si256(a.as_ptr().cast());
+                    let b = _mm256_loadu_si256(b.as_ptr().cast());
+                    let c = $vector_operation(a, b);
+                    _mm256_storeu_si256(result.as_mut_ptr().cast(), c);
+                }
+                result
+            }
+        };
+    }
+
+    packed_operation!(add, add_vectors);
+    packed_operation!(sub, sub_vectors);
+    packed_operation!(mul, mul_vectors);
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn call<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn butterflies(
+        lo: &mut [BFieldElement],
+        hi: &mut [BFieldElement],
+        twiddles: &[BFieldElement],
+        stride: usize,
+    ) {
+        let num_packed = lo.len() / LANES * LANES;
+        let mut lo_chunks = lo.chunks_exact_mut(LANES);
+        let mut hi_chunks = hi.chunks_exact_mut(LANES);
+        for (i, (l, h)) in (&mut lo_chunks).zip(&mut hi_chunks).enumerate() {
+            let twiddle = load_strided(&twiddles[i * LANES * stride..], stride);
+            let u = load(l);
+            let v = mul_vectors(load(h), twiddle);
+            store(l, add_vectors(u, v));
+            store(h, sub_vectors(u, v));
+        }
+
+        portable::butterflies(
+            lo_chunks.into_remainder(),
+            hi_chunks.into_remainder(),
+            twiddles.get(num_packed * stride..).unwrap_or_default(),
+            stride,
+        );
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn scale(elements: &mut [BFieldElement], scalar: BFieldElement) {
+        let scalar_vector = _mm256_set1_epi64x(scalar.raw_u64() as i64);
+        let mut chunks = elements.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            store(chunk, mul_vectors(load(chunk), scalar_vector));
+        }
+        portable::scale(chunks.into_remainder(), scalar);
+    }
+}
+
+/// Like the AVX2 implementation, but with twice the lanes and native unsigned
+/// comparisons.
+#[cfg(target_arch = "x86_64")]
+mod avx512 {
+    use std::arch::x86_64::*;
+
+    use super::*;
+
+    const LANES: usize = 8;
+    const P: i64 = BFieldElement::P as i64;
+
+    /// 2^64 mod P
+    const EPSILON: i64 = u32::MAX as i64;
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load(elements: &[BFieldElement]) -> __m512i {
+        load_strided(elements, 1)
+    }
+
+    /// Load the elements at indices `0, stride, 2·stride, …`.
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load_strided(elements: &[BFieldElement], stride: usize) -> __m512i {
+        let raw: [u64; LANES] = std::array::from_fn(|i| elements[i * stride].raw_u64());
+        _mm512_loadu_si512(raw.as_ptr().cast())
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn store(elements: &mut [BFieldElement], vector: __m512i) {
+        let mut raw = [0_u64; LANES];
+        _mm512_storeu_si512(raw.as_mut_ptr().cast(), vector);
+        for (element, raw_element) in elements[..LANES].iter_mut().zip(raw) {
+            *element = BFieldElement::from_raw_u64(raw_element);
+        }
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn add_vectors(a: __m512i, b: __m512i) -> __m512i {
+        let sum = _mm512_add_epi64(a, b);
+        let carry = _mm512_cmplt_epu64_mask(sum, a);
+        let sum = _mm512_mask_add_epi64(sum, carry, sum, _mm512_set1_epi64(EPSILON));
+        let p = _mm512_set1_epi64(P);
+        let is_at_least_p = _mm512_cmpge_epu64_mask(sum, p);
+        _mm512_mask_sub_epi64(sum, is_at_least_p, sum, p)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn sub_vectors(a: __m512i, b: __m512i) -> __m512i {
+        let difference = _mm512_sub_epi64(a, b);
+        let borrow = _mm512_cmplt_epu64_mask(a, b);
+        _mm512_mask_sub_epi64(difference, borrow, difference, _mm512_set1_epi64(EPSILON))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn mul_vectors(a: __m512i, b: __m512i) -> __m512i {
+        // 64×64 → 128 bit multiplication from 32×32 → 64 bit multiplications
+        let a_hi = _mm512_srli_epi64::<32>(a);
+        let b_hi = _mm512_srli_epi64::<32>(b);
+        let lo_lo = _mm512_mul_epu32(a, b);
+        let lo_hi = _mm512_mul_epu32(a, b_hi);
+        let hi_lo = _mm512_mul_epu32(a_hi, b);
+        let hi_hi = _mm512_mul_epu32(a_hi, b_hi);
+
+        let low_32_bits = _mm512_set1_epi64(u32::MAX.into());
+        let t = _mm512_add_epi64(hi_lo, _mm512_srli_epi64::<32>(lo_lo));
+        let u = _mm512_add_epi64(lo_hi, _mm512_and_si512(t, low_32_bits));
+        let product_lo = _mm512_or_si512(
+            _mm512_slli_epi64::<32>(u),
+            _mm512_and_si512(lo_lo, low_32_bits),
+        );
+        let product_hi = _mm512_add_epi64(
+            _mm512_add_epi64(hi_hi, _mm512_srli_epi64::<32>(t)),
+            _mm512_srli_epi64::<32>(u),
+        );
+
+        montgomery_reduce(product_lo, product_hi)
+    }
+
+    /// See [`BFieldElement::montyred`].
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn montgomery_reduce(x_lo: __m512i, x_hi: __m512i) -> __m512i {
+        let a = _mm512_add_epi64(x_lo, _mm512_slli_epi64::<32>(x_lo));
+        let overflow = _mm512_cmplt_epu64_mask(a, x_lo);
+        let b = _mm512_sub_epi64(a, _mm512_srli_epi64::<32>(a));
+        let b = _mm512_mask_sub_epi64(b, overflow, b, _mm512_set1_epi64(1));
+
+        let r = _mm512_sub_epi64(x_hi, b);
+        let borrow = _mm512_cmplt_epu64_mask(x_hi, b);
+        _mm512_mask_sub_epi64(r, borrow, r, _mm512_set1_epi64(EPSILON))
+    }
+
+    macro_rules! packed_operation {
+        ($name:ident, $vector_operation:ident) => {
+            #[inline]
+            #[target_feature(enable = "avx512f")]
+            pub(super) unsafe fn $name(a: [u64; WIDTH], b: [u64; WIDTH]) -> [u64; WIDTH] {
+                let a = _mm512_loadu_si512(a.as_ptr().cast());
+                let b = _mm512_loadu_si512(b.as_ptr().cast());
+                let mut result = [0; WIDTH];
+                _mm512_storeu_si512(result.as_mut_ptr().cast(), $vector_operation(a, b));
+                result
+            }
+        };
+    }
+
+    packed_operation!(add, add_vectors);
+    packed_operation!(sub, sub_vectors);
+    packed_operation!(mul, mul_vectors);
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn call<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn butterflies(
+        lo: &mut [BFieldElement],
+        hi: &mut [BFieldElement],
+        twiddles: &[BFieldElement],
+        stride: usize,
+    ) {
+        let num_packed = lo.len() / LANES * LANES;
+        let mut lo_chunks = lo.chunks_exact_mut(LANES);
+        let mut hi_chunks = hi.chunks_exact_mut(LANES);
+        for (i, (l, h)) in (&mut lo_chunks).zip(&mut hi_chunks).enumerate() {
+            let twiddle = load_strided(&twiddles[i * LANES * stride..], stride);
+            let u = load(l);
+            let v = mul_vectors(load(h), twiddle);
+            store(l, add_vectors(u, v));
+            store(h, sub_vectors(u, v));
+        }
+
+        portable::butterflies(
+            lo_chunks.into_remainder(),
+            hi_chunks.into_remainder(),
+            twiddles.get(num_packed * stride..).unwrap_or_default(),
+            stride,
+        );
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn scale(elements: &mut [BFieldElement], scalar: BFieldElement) {
+        let scalar_vector = _mm512_set1_epi64(scalar.raw_u64() as i64);
+        let mut chunks = elements.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            store(chunk, mul_vectors(load(chunk), scalar_vector));
+        }
+        portable::scale(chunks.into_remainder(), scalar);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::traits::FiniteField;
+    use crate::math::x_field_element::XFieldElement;
+
+    type RawOperation = fn([u64; WIDTH], [u64; WIDTH]) -> [u64; WIDTH];
+
+    /// The raw binary operations `(add, sub, mul)` of all backends the CPU
+    /// supports, except the portable one.
+    fn simd_backends() -> Vec<(RawOperation, RawOperation, RawOperation)> {
+        #[allow(unused_mut)]
+        let mut backends: Vec<(RawOperation, RawOperation, RawOperation)> = vec![];
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx2") {
+            backends.push((
+                |a, b| unsafe { avx2::add(a, b) },
+                |a, b| unsafe { avx2::sub(a, b) },
+                |a, b| unsafe { avx2::mul(a, b) },
+            ));
+        }
+
+        // SAFETY: the CPU supports the required instruction set
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx512f") {
+            backends.push((
+                |a, b| unsafe { avx512::add(a, b) },
+                |a, b| unsafe { avx512::sub(a, b) },
+                |a, b| unsafe { avx512::mul(a, b) },
+            ));
+        }
+
+        backends
+    }
+
+    fn assert_lane_wise_agreement(a: [BFieldElement; WIDTH], b: [BFieldElement; WIDTH]) {
+        let packed_a = PackedBFieldElement::new(a);
+        let packed_b = PackedBFieldElement::new(b);
+
+        let sum = (packed_a + packed_b).to_array();
+        let difference = (packed_a - packed_b).to_array();
+        let product = (packed_a * packed_b).to_array();
+        let square = packed_a.square().to_array();
+        let negation = (-packed_a).to_array();
+        for i in 0..WIDTH {
+            assert_eq!(a[i] + b[i], sum[i]);
+            assert_eq!(a[i] - b[i], difference[i]);
+            assert_eq!(a[i] * b[i], product[i]);
+            assert_eq!(a[i].square(), square[i]);
+            assert_eq!(-a[i], negation[i]);
+        }
+
+        for (add, sub, mul) in simd_backends() {
+            let (raw_a, raw_b) = (packed_a.raw(), packed_b.raw());
+            let simd_sum = PackedBFieldElement::from_raw(add(raw_a, raw_b));
+            let simd_difference = PackedBFieldElement::from_raw(sub(raw_a, raw_b));
+            let simd_product = PackedBFieldElement::from_raw(mul(raw_a, raw_b));
+            assert_eq!(sum, simd_sum.to_array());
+            assert_eq!(difference, simd_difference.to_array());
+            assert_eq!(product, simd_product.to_array());
+        }
+    }
+
+    #[proptest]
+    fn packed_arithmetic_agrees_with_scalar_arithmetic(
+        #[strategy(arb())] a: [BFieldElement; WIDTH],
+        #[strategy(arb())] b: [BFieldElement; WIDTH],
+    ) {
+        assert_lane_wise_agreement(a, b);
+    }
+
+    #[test]
+    fn packed_arithmetic_agrees_with_scalar_arithmetic_on_edge_cases() {
+        let edge_cases = [
+            0,
+            1,
+            2,
+            u64::from(u32::MAX),
+            1 << 32,
+            BFieldElement::P - 2,
+            BFieldElement::P - 1,
+        ]
+        .map(BFieldElement::new);
+
+        for &a in &edge_cases {
+            for &b in &edge_cases {
+                let lanes_a = std::array::from_fn(|i| edge_cases[i % edge_cases.len()] * a);
+                assert_lane_wise_agreement(lanes_a, [b; WIDTH]);
+                assert_lane_wise_agreement([a; WIDTH], [b; WIDTH]);
+            }
+        }
+    }
+
+    #[proptest]
+    fn packed_butterflies_agree_with_scalar_butterflies(
+        #[strategy(0_usize..50)] _len: usize,
+        #[strategy(1_usize..5)] stride: usize,
+        #[strategy(vec(arb(), #_len))] lo: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #_len))] hi: Vec<BFieldElement>,
+        #[strategy(vec(arb(), #_len * #stride))] twiddles: Vec<BFieldElement>,
+    ) {
+        let (mut expected_lo, mut expected_hi) = (lo.clone(), hi.clone());
+        for ((l, h), &twiddle) in expected_lo
+            .iter_mut()
+            .zip(&mut expected_hi)
+            .zip(twiddles.iter().step_by(stride))
+        {
+            let v = *h * twiddle;
+            (*l, *h) = (*l + v, *l - v);
+        }
+
+        let (mut packed_lo, mut packed_hi) = (lo, hi);
+        butterflies(&mut packed_lo, &mut packed_hi, &twiddles, stride);
+        prop_assert_eq!(expected_lo, packed_lo);
+        prop_assert_eq!(expected_hi, packed_hi);
+    }
+
+    #[proptest]
+    fn packed_scaling_agrees_with_scalar_scaling(
+        #[strategy(vec(arb(), 0..50))] elements: Vec<BFieldElement>,
+        #[strategy(arb())] scalar: BFieldElement,
+    ) {
+        let expected = elements.iter().map(|&e| e * scalar).collect::<Vec<_>>();
+        let mut scaled = elements;
+        scale(&mut scaled, scalar);
+        prop_assert_eq!(expected, scaled);
+    }
+
+    #[proptest]
+    fn packed_batch_inversion_agrees_with_individual_inversion(
+        #[strategy(vec(arb(), 0..50))]
+        #[filter(!#elements.iter().any(|e| e.is_zero()))]
+        elements: Vec<BFieldElement>,
+    ) {
+        let expected = elements.iter().map(|e| e.inverse()).collect::<Vec<_>>();
+        prop_assert_eq!(expected, BFieldElement::batch_inversion(elements));
+    }
+
+    #[test]
+    fn only_b_field_elements_are_recognized_as_such() {
+        assert!(as_b_field_elements(&[BFieldElement::ONE]).is_some());
+        assert!(as_b_field_elements(&[XFieldElement::ONE]).is_none());
+        assert!(as_b_field_elements(&[1_u64]).is_none());
+        assert!(as_b_field_elements_mut(&mut [BFieldElement::ONE]).is_some());
+        assert!(as_b_field_elements_mut(&mut [XFieldElement::ONE]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot do batch inversion on zero")]
+    fn packed_batch_inversion_of_zero_panics() {
+        let mut elements = vec![BFieldElement::ONE; 10];
+        elements[9] = BFieldElement::ZERO;
+        BFieldElement::batch_inversion(elements);
+    }
+}
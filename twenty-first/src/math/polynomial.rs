@@ -25,8 +25,15 @@ use rayon::prelude::*;
 
 use super::traits::PrimitiveRootOfUnity;
 use super::zerofier_tree::ZerofierTree;
+use crate::bfe_vec;
+use crate::math::ntt::batch_ntt;
 use crate::math::ntt::intt;
+use crate::math::ntt::intt_any_length;
 use crate::math::ntt::ntt;
+use crate::math::ntt::ntt_any_length;
+use crate::math::ntt::MatrixLayout;
+use crate::math::other::random_elements;
+use crate::math::packed_b_field_element;
 use crate::math::traits::FiniteField;
 use crate::math::traits::ModPowU32;
 use crate::prelude::BFieldElement;
@@ -139,7 +146,7 @@ impl<FF: FiniteField> Eq for Polynomial<FF> {}
 
 impl<FF> Polynomial<FF>
 where
-    FF: FiniteField + MulAssign<BFieldElement>,
+    FF: FiniteField,
 {
     /// [Fast multiplication](Self::multiply) is slower than [naïve multiplication](Self::mul)
     /// for polynomials of degree less than this threshold.
@@ -239,7 +246,7 @@ where
 
         let result_degree: u64 = 2 * self.degree() as u64;
         let order = (result_degree + 1).next_power_of_two();
-        let root_res = BFieldElement::primitive_root_of_unity(order);
+        let root_res = FF::BaseField::primitive_root_of_unity(order);
         let root =
             root_res.unwrap_or_else(|| panic!("primitive root for order {order} should exist"));
 
@@ -352,7 +359,7 @@ where
         };
         let order = (degree + 1).next_power_of_two();
         let order_u64 = u64::try_from(order).unwrap();
-        let root = BFieldElement::primitive_root_of_unity(order_u64).unwrap();
+        let root = FF::BaseField::primitive_root_of_unity(order_u64).unwrap();
 
         let mut lhs_coefficients = self.coefficients.to_vec();
         let mut rhs_coefficients = other.coefficients.to_vec();
@@ -708,12 +715,12 @@ where
     pub fn batch_fast_interpolate(
         domain: &[FF],
         values_matrix: &Vec<Vec<FF>>,
-        primitive_root: BFieldElement,
+        primitive_root: FF::BaseField,
         root_order: usize,
     ) -> Vec<Self> {
         debug_assert_eq!(
             primitive_root.mod_pow_u32(root_order as u32),
-            BFieldElement::ONE,
+            FF::BaseField::ONE,
             "Supplied element “primitive_root” must have supplied order.\
             Supplied element was: {primitive_root:?}\
             Supplied order was: {root_order:?}"
@@ -903,6 +910,9 @@ where
 
     /// Fast evaluate on a coset domain, which is the group generated by `generator^i * offset`.
     ///
+    /// The order of the domain does not need to be a power of two; see
+    /// [`ntt_any_length`] for the supported orders.
+    ///
     /// # Performance
     ///
     /// If possible, use a [base field element](BFieldElement) as the offset.
@@ -914,7 +924,7 @@ where
     pub fn fast_coset_evaluate<S>(
         &self,
         offset: S,
-        generator: BFieldElement,
+        generator: FF::BaseField,
         order: usize,
     ) -> Vec<FF>
     where
@@ -935,12 +945,73 @@ where
 
         let mut coefficients = self.scale(offset).coefficients;
         coefficients.resize(order, FF::ZERO);
-
-        let log_2_of_n = coefficients.len().ilog2();
-        ntt::<FF>(&mut coefficients, generator, log_2_of_n);
+        ntt_any_length::<FF>(&mut coefficients, generator);
         coefficients
     }
 
+    /// Evaluate all polynomials on the same coset domain, which is the group generated by
+    /// `generator^i * offset`. The `i`th returned codeword belongs to the `i`th polynomial.
+    ///
+    /// The result is the same as calling [`Self::fast_coset_evaluate`] on every polynomial,
+    /// but the polynomials are evaluated in parallel using a [batch NTT](batch_ntt).
+    ///
+    /// # Performance
+    ///
+    /// If possible, use a [base field element](BFieldElement) as the offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the order of the domain generated by the `generator` is smaller than or equal to
+    /// the degree of any of the polynomials. In particular, the empty domain, _i.e._, `order` 0,
+    /// is only supported if all polynomials are zero, in which case all codewords are empty.
+    pub fn batch_coset_evaluate<S>(
+        polynomials: &[Self],
+        offset: S,
+        generator: FF::BaseField,
+        order: usize,
+    ) -> Vec<Vec<FF>>
+    where
+        S: Clone + One,
+        FF: Mul<S, Output = FF>,
+    {
+        // see `fast_coset_evaluate` for the reason behind this limitation
+        assert!(
+            polynomials.iter().all(|p| (order as isize) > p.degree()),
+            "`Polynomial::batch_coset_evaluate` is currently limited to domains of order \
+            greater than the degree of every polynomial."
+        );
+
+        // only zero polynomials can be evaluated on the empty domain
+        if order == 0 {
+            return vec![vec![]; polynomials.len()];
+        }
+
+        let mut codewords = Vec::with_capacity(order * polynomials.len());
+        for polynomial in polynomials {
+            let mut coefficients = polynomial.scale(offset.clone()).coefficients;
+            coefficients.resize(order, FF::ZERO);
+            codewords.extend(coefficients);
+        }
+
+        if order.is_power_of_two() {
+            let log_2_of_n = order.ilog2();
+            batch_ntt(
+                &mut codewords,
+                MatrixLayout::ColumnMajor,
+                generator,
+                log_2_of_n,
+            );
+        } else {
+            codewords
+                .par_chunks_exact_mut(order)
+                .for_each(|codeword| ntt_any_length(codeword, generator));
+        }
+        codewords
+            .chunks_exact(order)
+            .map(|codeword| codeword.to_vec())
+            .collect()
+    }
+
     /// The inverse of [`Self::fast_coset_evaluate`].
     ///
     /// # Performance
@@ -951,15 +1022,13 @@ where
     ///
     /// Panics if the length of `values` does not equal the order of the domain generated by the
     /// `generator`.
-    pub fn fast_coset_interpolate<S>(offset: S, generator: BFieldElement, values: &[FF]) -> Self
+    pub fn fast_coset_interpolate<S>(offset: S, generator: FF::BaseField, values: &[FF]) -> Self
     where
         S: Clone + One + Inverse,
         FF: Mul<S, Output = FF>,
     {
-        let length = values.len();
         let mut mut_values = values.to_vec();
-
-        intt(&mut mut_values, generator, length.ilog2());
+        intt_any_length(&mut mut_values, generator);
         let poly = Polynomial::new(mut_values);
 
         poly.scale(offset.inverse())
@@ -1040,7 +1109,7 @@ where
         // final NTT domain
         let full_domain_length =
             ((1 << (num_rounds + 1)) * self.degree() as usize).next_power_of_two();
-        let full_omega = BFieldElement::primitive_root_of_unity(full_domain_length as u64).unwrap();
+        let full_omega = FF::BaseField::primitive_root_of_unity(full_domain_length as u64).unwrap();
         let log_full_domain_length = full_domain_length.ilog2();
 
         let mut self_ntt = self.coefficients.clone();
@@ -1054,12 +1123,12 @@ where
         let lde = |v: &mut [FF], old_domain_length: usize, new_domain_length: usize| {
             intt(
                 &mut v[..old_domain_length],
-                BFieldElement::primitive_root_of_unity(old_domain_length as u64).unwrap(),
+                FF::BaseField::primitive_root_of_unity(old_domain_length as u64).unwrap(),
                 old_domain_length.ilog2(),
             );
             ntt(
                 &mut v[..new_domain_length],
-                BFieldElement::primitive_root_of_unity(new_domain_length as u64).unwrap(),
+                FF::BaseField::primitive_root_of_unity(new_domain_length as u64).unwrap(),
                 new_domain_length.ilog2(),
             );
         };
@@ -1073,7 +1142,7 @@ where
         f_ntt.resize(full_domain_length, FF::from(0));
         ntt(
             &mut f_ntt[..current_domain_length],
-            BFieldElement::primitive_root_of_unity(current_domain_length as u64).unwrap(),
+            FF::BaseField::primitive_root_of_unity(current_domain_length as u64).unwrap(),
             current_domain_length.ilog2(),
         );
 
@@ -1096,7 +1165,7 @@ where
 
         intt(
             &mut f_ntt[..current_domain_length],
-            BFieldElement::primitive_root_of_unity(current_domain_length as u64).unwrap(),
+            FF::BaseField::primitive_root_of_unity(current_domain_length as u64).unwrap(),
             current_domain_length.ilog2(),
         );
         Polynomial::new(f_ntt)
@@ -1284,7 +1353,7 @@ where
         let domain_length = shift_ntt.len();
         assert!(domain_length.is_power_of_two());
         let log_domain_length = domain_length.ilog2();
-        let omega = BFieldElement::primitive_root_of_unity(domain_length as u64).unwrap();
+        let omega = FF::BaseField::primitive_root_of_unity(domain_length as u64).unwrap();
         let chunk_size = domain_length - tail_length;
 
         if self.coefficients.len() < chunk_size + tail_length {
@@ -1357,7 +1426,7 @@ where
         let mut shift_factor_ntt = ntt_friendly_multiple.coefficients[..n].to_vec();
         ntt(
             &mut shift_factor_ntt,
-            BFieldElement::primitive_root_of_unity(n as u64).unwrap(),
+            FF::BaseField::primitive_root_of_unity(n as u64).unwrap(),
             n.ilog2(),
         );
         (shift_factor_ntt, m)
@@ -1449,10 +1518,10 @@ where
     #[doc(hidden)]
     pub fn fast_modular_coset_interpolate_preprocess(
         n: usize,
-        offset: BFieldElement,
+        offset: FF::BaseField,
         modulus: &Polynomial<FF>,
     ) -> ModularInterpolationPreprocessingData<FF> {
-        let omega = BFieldElement::primitive_root_of_unity(n as u64).unwrap();
+        let omega = FF::BaseField::primitive_root_of_unity(n as u64).unwrap();
         // a list of polynomials whose ith element is X^(2^i) mod m(X)
         let modular_squares = (0..n.ilog2())
             .scan(
@@ -1465,14 +1534,14 @@ where
             )
             .collect_vec();
         let even_zerofiers = (0..n.ilog2())
-            .map(|i| offset.inverse().mod_pow(1u64 << i))
+            .map(|i| offset.inverse().mod_pow_u32(1 << i))
             .zip(modular_squares.iter())
-            .map(|(lc, sq)| sq.scalar_mul(FF::from(lc.value())) - Polynomial::one())
+            .map(|(lc, sq)| sq.scalar_mul(FF::from_base_field(lc)) - Polynomial::one())
             .collect_vec();
         let odd_zerofiers = (0..n.ilog2())
-            .map(|i| (offset * omega).inverse().mod_pow(1u64 << i))
+            .map(|i| (offset * omega).inverse().mod_pow_u32(1 << i))
             .zip(modular_squares.iter())
-            .map(|(lc, sq)| sq.scalar_mul(FF::from(lc.value())) - Polynomial::one())
+            .map(|(lc, sq)| sq.scalar_mul(FF::from_base_field(lc)) - Polynomial::one())
             .collect_vec();
 
         // precompute NTT-friendly multiple of the modulus
@@ -1491,7 +1560,7 @@ where
     /// size-n subgroup that is identified by some offset.
     fn fast_modular_coset_interpolate(
         values: &[FF],
-        offset: BFieldElement,
+        offset: FF::BaseField,
         modulus: &Polynomial<FF>,
     ) -> Self {
         let preprocessing_data =
@@ -1509,7 +1578,7 @@ where
     #[doc(hidden)]
     pub fn fast_modular_coset_interpolate_with_zerofiers_and_ntt_friendly_multiple(
         values: &[FF],
-        offset: BFieldElement,
+        offset: FF::BaseField,
         modulus: &Polynomial<FF>,
         preprocessed: &ModularInterpolationPreprocessingData<FF>,
     ) -> Self {
@@ -1517,11 +1586,11 @@ where
             panic!("cannot reduce modulo zero")
         };
         let n = values.len();
-        let omega = BFieldElement::primitive_root_of_unity(n as u64).unwrap();
+        let omega = FF::BaseField::primitive_root_of_unity(n as u64).unwrap();
 
         if n < Self::FAST_MODULAR_COSET_INTERPOLATE_CUTOFF_THRESHOLD_PREFER_LAGRANGE {
             let domain = (0..n)
-                .scan(FF::from(offset.value()), |acc: &mut FF, _| {
+                .scan(FF::from_base_field(offset), |acc: &mut FF, _| {
                     let yld = *acc;
                     *acc *= omega;
                     Some(yld)
@@ -1535,7 +1604,7 @@ where
             let interpolant = Polynomial::new(coefficients);
 
             return interpolant
-                .scale(FF::from(offset.inverse().value()))
+                .scale(FF::from_base_field(offset.inverse()))
                 .reduce_by_ntt_friendly_modulus(
                     &preprocessed.shift_coefficients,
                     preprocessed.tail_length,
@@ -1575,9 +1644,9 @@ where
         // 3. Batch-invert zerofiers on opposite domains.
         // The batch-inversion is actually not performed because we already know
         // the result: {(-2)^-1, (-2)^-1, (-2)^-1, ...}.
-        const MINUS_TWO_INVERSE: BFieldElement = BFieldElement::MINUS_TWO_INVERSE;
-        let even_zerofier_on_odd_domain_inverted = vec![FF::from(MINUS_TWO_INVERSE.value()); n / 2];
-        let odd_zerofier_on_even_domain_inverted = vec![FF::from(MINUS_TWO_INVERSE.value()); n / 2];
+        let minus_two_inverse = (-FF::from(2)).inverse();
+        let even_zerofier_on_odd_domain_inverted = vec![minus_two_inverse; n / 2];
+        let odd_zerofier_on_even_domain_inverted = vec![minus_two_inverse; n / 2];
 
         // 4. Construct interpolation values through Hadamard products.
         let mut odd_domain_targets = even_zerofier_on_odd_domain_inverted;
@@ -1605,7 +1674,7 @@ where
     /// Extrapolate a Reed-Solomon codeword, defined relative to a coset of the
     /// subgroup of order n (codeword length), in new points.
     pub fn coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword: &[FF],
         points: &[FF],
     ) -> Vec<FF> {
@@ -1624,7 +1693,7 @@ where
     }
 
     fn naive_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword: &[FF],
         points: &[FF],
     ) -> Vec<FF> {
@@ -1633,16 +1702,16 @@ where
         let mut coefficients = codeword.to_vec();
         intt(
             &mut coefficients,
-            BFieldElement::primitive_root_of_unity(n as u64).unwrap(),
+            FF::BaseField::primitive_root_of_unity(n as u64).unwrap(),
             logn,
         );
         let interpolant =
-            Polynomial::new(coefficients).scale(FF::from(domain_offset.inverse().value()));
+            Polynomial::new(coefficients).scale(FF::from_base_field(domain_offset.inverse()));
         interpolant.batch_evaluate(points)
     }
 
     fn fast_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword: &[FF],
         points: &[FF],
     ) -> Vec<FF> {
@@ -1680,7 +1749,7 @@ where
     /// # Panics
     /// Panics if the `codeword_length` is not a power of two.
     pub fn batch_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1693,7 +1762,7 @@ where
     }
 
     fn batch_fast_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1724,7 +1793,7 @@ where
     }
 
     fn batch_naive_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1739,11 +1808,11 @@ where
                 let mut coefficients = codewords[i * n..(i + 1) * n].to_vec();
                 intt(
                     &mut coefficients,
-                    BFieldElement::primitive_root_of_unity(n as u64).unwrap(),
+                    FF::BaseField::primitive_root_of_unity(n as u64).unwrap(),
                     logn,
                 );
                 Polynomial::new(coefficients)
-                    .scale(FF::from(domain_offset.inverse().value()))
+                    .scale(FF::from_base_field(domain_offset.inverse()))
                     .reduce_by_ntt_friendly_modulus(&shift_coefficients, tail_length)
                     .divide_and_conquer_batch_evaluate(&zerofier_tree)
             })
@@ -1752,7 +1821,7 @@ where
 
     /// Parallel version of [`batch_coset_extrapolate`](Self::batch_coset_extrapolate).
     pub fn par_batch_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1775,7 +1844,7 @@ where
     }
 
     fn par_batch_fast_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1807,7 +1876,7 @@ where
     }
 
     fn par_batch_naive_coset_extrapolate(
-        domain_offset: BFieldElement,
+        domain_offset: FF::BaseField,
         codeword_length: usize,
         codewords: &[FF],
         points: &[FF],
@@ -1823,11 +1892,11 @@ where
                 let mut coefficients = codewords[i * n..(i + 1) * n].to_vec();
                 intt(
                     &mut coefficients,
-                    BFieldElement::primitive_root_of_unity(n as u64).unwrap(),
+                    FF::BaseField::primitive_root_of_unity(n as u64).unwrap(),
                     logn,
                 );
                 Polynomial::new(coefficients)
-                    .scale(FF::from(domain_offset.inverse().value()))
+                    .scale(FF::from_base_field(domain_offset.inverse()))
                     .reduce_by_ntt_friendly_modulus(&shift_coefficients, tail_length)
                     .divide_and_conquer_batch_evaluate(&zerofier_tree)
             })
@@ -1909,6 +1978,255 @@ impl Polynomial<BFieldElement> {
     }
 }
 
+/// Root finding and factorization. Since the characteristic of the
+/// [base field](BFieldElement) vastly exceeds the degree of any polynomial that
+/// fits into memory, every polynomial with vanishing
+/// [formal derivative](Polynomial::formal_derivative) is constant.
+impl Polynomial<BFieldElement> {
+    /// The distinct roots of `self`, in ascending order of their
+    /// [values](BFieldElement::value).
+    ///
+    /// Computes the product of all linear factors as the gcd of `self` and
+    /// x^P - x, then splits it using the [Cantor–Zassenhaus] algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let x_squared_minus_7 = Polynomial::new(bfe_vec![-7, 0, 1]); // 7 is not a square
+    /// let polynomial = Polynomial::zerofier(&bfe_array![7, 3, 3]) * x_squared_minus_7;
+    /// assert_eq!(bfe_vec![3, 7], polynomial.roots());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, since every field element is one of its roots.
+    ///
+    /// [Cantor–Zassenhaus]: https://en.wikipedia.org/wiki/Cantor%E2%80%93Zassenhaus_algorithm
+    pub fn roots(&self) -> Vec<BFieldElement> {
+        assert!(!self.is_zero(), "Every field element is a root of zero.");
+        if self.degree() == 0 {
+            return vec![];
+        }
+
+        let monic = self.monic();
+        let x = Self::new(bfe_vec![0, 1]);
+        let x_to_the_p = x.pow_mod(BFieldElement::P.into(), &monic);
+        let (product_of_linear_factors, _, _) = Self::xgcd(x_to_the_p - x, monic);
+
+        let mut roots = product_of_linear_factors
+            .equal_degree_factorization(1)
+            .into_iter()
+            .map(|linear_factor| -linear_factor.coefficients[0])
+            .collect_vec();
+        roots.sort_by_key(BFieldElement::value);
+        roots
+    }
+
+    /// Whether `self` is irreducible, _i.e._, is not constant and is not the
+    /// product of two non-constant polynomials.
+    ///
+    /// Uses [Rabin's test]: a polynomial f of degree n is irreducible if and
+    /// only if f divides x^(P^n) - x, and gcd(x^(P^(n/q)) - x, f) = 1 for every
+    /// prime divisor q of n.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// assert!(XFieldElement::shah_polynomial().is_irreducible());
+    /// assert!(!Polynomial::new(bfe_vec![-1, 0, 1]).is_irreducible());
+    /// ```
+    ///
+    /// [Rabin's test]: https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields#Rabin's_test_of_irreducibility
+    pub fn is_irreducible(&self) -> bool {
+        let Ok(degree) = usize::try_from(self.degree()) else {
+            return false;
+        };
+        if degree == 0 {
+            return false;
+        }
+
+        let monic = self.monic();
+        let x = Self::new(bfe_vec![0, 1]);
+        let iterated_frobenius_of_x = |num_iterations| {
+            (0..num_iterations).fold(x.clone(), |acc, _| {
+                acc.pow_mod(BFieldElement::P.into(), &monic)
+            })
+        };
+
+        let mut n = degree;
+        let mut prime_divisors = vec![];
+        for candidate in 2..=degree {
+            if n % candidate == 0 {
+                prime_divisors.push(candidate);
+            }
+            while n % candidate == 0 {
+                n /= candidate;
+            }
+        }
+
+        let coprime_to_self = |poly: Self| Self::xgcd(poly, monic.clone()).0.degree() == 0;
+        prime_divisors
+            .into_iter()
+            .all(|q| coprime_to_self(iterated_frobenius_of_x(degree / q) - x.clone()))
+            && (iterated_frobenius_of_x(degree) - x.clone())
+                .reduce(&monic)
+                .is_zero()
+    }
+
+    /// The factorization of `self` into monic irreducible polynomials, together
+    /// with their multiplicities. The leading coefficient of `self` is not part
+    /// of the factorization. The factors are sorted by degree, then by
+    /// coefficients.
+    ///
+    /// Performs a square-free decomposition, then a distinct-degree
+    /// factorization of each square-free part, and finally splits the factors of
+    /// equal degree using the [Cantor–Zassenhaus] algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let x_plus_1 = Polynomial::new(bfe_vec![1, 1]);
+    /// let shah = XFieldElement::shah_polynomial();
+    /// let polynomial = x_plus_1.clone() * x_plus_1.clone() * shah.clone();
+    /// assert_eq!(vec![(x_plus_1, 2), (shah, 1)], polynomial.factor());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// [Cantor–Zassenhaus]: https://en.wikipedia.org/wiki/Cantor%E2%80%93Zassenhaus_algorithm
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "Cannot factor zero.");
+
+        let mut factors = self
+            .monic()
+            .square_free_decomposition()
+            .into_iter()
+            .flat_map(|(square_free_factor, multiplicity)| {
+                square_free_factor
+                    .distinct_degree_factorization()
+                    .into_iter()
+                    .flat_map(|(factor, degree)| factor.equal_degree_factorization(degree))
+                    .map(move |irreducible_factor| (irreducible_factor, multiplicity))
+            })
+            .collect_vec();
+        factors.sort_by_cached_key(|(factor, _)| {
+            let coefficients = factor.coefficients.iter().map(BFieldElement::value);
+            (factor.degree(), coefficients.rev().collect_vec())
+        });
+        factors
+    }
+
+    /// `self` divided by its leading coefficient.
+    fn monic(&self) -> Self {
+        let mut monic = self.clone();
+        monic.normalize();
+        if let Some(leading_coefficient) = monic.leading_coefficient() {
+            monic.scalar_mul_mut(leading_coefficient.inverse());
+        }
+        monic
+    }
+
+    /// `self`^`exponent` mod `modulus`, using square-and-multiply.
+    fn pow_mod(&self, exponent: BigInt, modulus: &Self) -> Self {
+        let base = self.reduce(modulus);
+        let mut acc = Self::one().reduce(modulus);
+        for i in (0..exponent.bits()).rev() {
+            acc = acc.multiply(&acc).reduce(modulus);
+            if exponent.bit(i) {
+                acc = acc.multiply(&base).reduce(modulus);
+            }
+        }
+        acc
+    }
+
+    /// The square-free decomposition of the monic polynomial `self`, _i.e._,
+    /// square-free, pairwise coprime polynomials f_i such that `self` is the
+    /// product of all f_i^i. Uses [Yun's algorithm], omitting trivial f_i.
+    ///
+    /// [Yun's algorithm]: https://en.wikipedia.org/wiki/Square-free_polynomial#Yun's_algorithm
+    fn square_free_decomposition(&self) -> Vec<(Self, usize)> {
+        let mut decomposition = vec![];
+        let (mut c, _, _) = Self::xgcd(self.clone(), self.formal_derivative());
+        let mut w = self.divide(&c).0;
+        let mut multiplicity = 1;
+        while w.degree() > 0 {
+            let (y, _, _) = Self::xgcd(w.clone(), c.clone());
+            let square_free_factor = w.divide(&y).0;
+            if square_free_factor.degree() > 0 {
+                decomposition.push((square_free_factor.monic(), multiplicity));
+            }
+            c = c.divide(&y).0;
+            w = y;
+            multiplicity += 1;
+        }
+        decomposition
+    }
+
+    /// Group the irreducible factors of the square-free, monic polynomial `self`
+    /// by their degree. Returns the products of all irreducible factors of the
+    /// same degree, together with that degree.
+    fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let x = Self::new(bfe_vec![0, 1]);
+        let mut remaining = self.clone();
+        let mut x_to_the_p_to_the_i = x.clone();
+        let mut factorization = vec![];
+        let mut degree = 1;
+        while remaining.degree() >= 2 * degree as isize {
+            x_to_the_p_to_the_i = x_to_the_p_to_the_i.pow_mod(BFieldElement::P.into(), &remaining);
+            let (factor, _, _) =
+                Self::xgcd(x_to_the_p_to_the_i.clone() - x.clone(), remaining.clone());
+            if factor.degree() > 0 {
+                remaining = remaining.divide(&factor).0;
+                x_to_the_p_to_the_i = x_to_the_p_to_the_i.reduce(&remaining);
+                factorization.push((factor, degree));
+            }
+            degree += 1;
+        }
+        if remaining.degree() > 0 {
+            let remaining_degree = remaining.degree() as usize;
+            factorization.push((remaining, remaining_degree));
+        }
+        factorization
+    }
+
+    /// Split the square-free, monic polynomial `self`, all of whose irreducible
+    /// factors have the given degree, into these irreducible factors using the
+    /// [Cantor–Zassenhaus] algorithm.
+    ///
+    /// [Cantor–Zassenhaus]: https://en.wikipedia.org/wiki/Cantor%E2%80%93Zassenhaus_algorithm
+    fn equal_degree_factorization(&self, degree: usize) -> Vec<Self> {
+        let self_degree = self.degree();
+        if self_degree <= 0 {
+            return vec![];
+        }
+        if self_degree == degree as isize {
+            return vec![self.clone()];
+        }
+
+        // For a random polynomial a, a^((P^d - 1)/2) - 1 vanishes on roughly
+        // half of the irreducible factors of degree d.
+        let exponent: BigInt = (BigInt::from(BFieldElement::P).pow(degree as u32) - 1) / 2;
+        let split = loop {
+            let random_polynomial = Self::new(random_elements(self_degree as usize));
+            let candidate = random_polynomial.pow_mod(exponent.clone(), self) - Self::one();
+            let (gcd, _, _) = Self::xgcd(candidate, self.clone());
+            if gcd.degree() > 0 && gcd.degree() < self_degree {
+                break gcd;
+            }
+        };
+
+        let cofactor = self.divide(&split).0;
+        let mut factors = split.equal_degree_factorization(degree);
+        factors.extend(cofactor.equal_degree_factorization(degree));
+        factors
+    }
+}
+
 impl<const N: usize, FF, E> From<[E; N]> for Polynomial<FF>
 where
     FF: FiniteField,
@@ -2162,6 +2480,19 @@ impl<FF: FiniteField> Polynomial<FF> {
         S: Clone,
         FF: MulAssign<S>,
     {
+        let mut scalar_in_ff = [FF::ONE];
+        scalar_in_ff[0] *= scalar.clone();
+        if let Some(&[base_field_scalar]) =
+            packed_b_field_element::as_b_field_elements(&scalar_in_ff)
+        {
+            if let Some(coefficients) =
+                packed_b_field_element::as_b_field_elements_mut(&mut self.coefficients)
+            {
+                packed_b_field_element::scale(coefficients, base_field_scalar);
+                return;
+            }
+        }
+
         for coefficient in &mut self.coefficients {
             *coefficient *= scalar.clone();
         }
@@ -2315,7 +2646,49 @@ impl<FF: FiniteField> Sub for Polynomial<FF> {
     }
 }
 
+/// A 2×2 matrix of polynomials, mapping a pair of consecutive remainders of the
+/// Euclidean algorithm to a later such pair.
+type EuclideanMatrix<FF> = [[Polynomial<FF>; 2]; 2];
+
 impl<FF: FiniteField> Polynomial<FF> {
+    /// [Fast xgcd](Self::fast_xgcd) is slower than [naïve xgcd](Self::naive_xgcd)
+    /// for polynomials of degree less than this threshold.
+    ///
+    /// Extracted from `cargo bench --bench xgcd`, which measured, for
+    /// polynomials of degree 2^k and 2^k - 1:
+    ///
+    /// | k  | naïve    | fast     |
+    /// |---:|---------:|---------:|
+    /// |  9 |  2.98 ms |  3.55 ms |
+    /// | 10 |  9.15 ms |  8.22 ms |
+    /// | 11 | 37.14 ms | 19.93 ms |
+    /// | 12 | 126.3 ms | 45.74 ms |
+    const FAST_XGCD_CUTOFF_THRESHOLD: isize = 1 << 10;
+
+    /// Inside [fast xgcd](Self::fast_xgcd), the degree below which the
+    /// recursive half-GCD falls back to the plain Euclidean algorithm.
+    ///
+    /// Extracted from `cargo bench --bench xgcd`.
+    const HALF_GCD_CUTOFF_THRESHOLD: isize = {
+        if cfg!(test) {
+            1 << 3
+        } else {
+            1 << 7
+        }
+    };
+
+    /// Inside [fast xgcd](Self::fast_xgcd), the degree of quotient and divisor
+    /// below which division falls back to [long division](Self::naive_divide).
+    /// Matches the point where [multiplication](Self::multiply) starts using
+    /// NTTs.
+    const FAST_DIVIDE_CUTOFF_THRESHOLD: isize = {
+        if cfg!(test) {
+            1 << 3
+        } else {
+            Self::FAST_MULTIPLY_CUTOFF_THRESHOLD
+        }
+    };
+
     /// Extended Euclidean algorithm with polynomials. Computes the greatest
     /// common divisor `gcd` as a monic polynomial, as well as the corresponding
     /// Bézout coefficients `a` and `b`, satisfying `gcd = a·x + b·y`
@@ -2330,7 +2703,20 @@ impl<FF: FiniteField> Polynomial<FF> {
     /// let (gcd, a, b) = Polynomial::xgcd(x.clone(), y.clone());
     /// assert_eq!(gcd, a * x + b * y);
     /// ```
-    pub fn xgcd(mut x: Self, mut y: Self) -> (Self, Self, Self) {
+    pub fn xgcd(x: Self, y: Self) -> (Self, Self, Self) {
+        if x.degree().max(y.degree()) < Self::FAST_XGCD_CUTOFF_THRESHOLD {
+            Self::naive_xgcd(x, y)
+        } else {
+            Self::fast_xgcd(x, y)
+        }
+    }
+
+    /// Use [Self::xgcd] instead. Only `pub` to allow benchmarking; not considered
+    /// part of the public API.
+    ///
+    /// The quadratic extended Euclidean algorithm.
+    #[doc(hidden)]
+    pub fn naive_xgcd(mut x: Self, mut y: Self) -> (Self, Self, Self) {
         let (mut a_factor, mut a1) = (Self::one(), Self::zero());
         let (mut b_factor, mut b1) = (Self::zero(), Self::one());
 
@@ -2357,6 +2743,181 @@ impl<FF: FiniteField> Polynomial<FF> {
         let [x, a, b] = [x, a_factor, b_factor].map(normalize);
         (x, a, b)
     }
+
+    /// Extended Euclidean algorithm with polynomials, using the half-GCD
+    /// algorithm. Produces the same result as [`xgcd`](Self::xgcd).
+    ///
+    /// Prefer [`xgcd`](Self::xgcd), which chooses the fastest strategy. This
+    /// method is asymptotically faster than the quadratic Euclidean algorithm,
+    /// running in time O(M(n)·log(n)), where M(n) is the time it takes to
+    /// [multiply](Self::fast_multiply) two polynomials of degree n. For
+    /// polynomials of low degree, it is slower.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use twenty_first::prelude::*;
+    /// let x = Polynomial::new(bfe_vec![1, 2, 3, 4, 5, 6, 7]);
+    /// let y = Polynomial::new(bfe_vec![1, 1]);
+    /// let (gcd, a, b) = Polynomial::fast_xgcd(x.clone(), y.clone());
+    /// assert_eq!(gcd, a * x + b * y);
+    /// ```
+    pub fn fast_xgcd(x: Self, y: Self) -> (Self, Self, Self) {
+        let mut matrix = Self::identity_matrix();
+        let (mut a, mut b) = (x, y);
+
+        // Alternate between one Euclidean step, which ensures that the degree
+        // of `a` exceeds that of `b`, and one half-GCD step.
+        while !b.is_zero() {
+            let (quotient, remainder) = a.fast_divide(&b);
+            matrix = Self::euclidean_step(matrix, &quotient);
+            (a, b) = (b, remainder);
+            if b.is_zero() {
+                break;
+            }
+
+            let half_gcd_matrix = Self::half_gcd(&a, &b);
+            (a, b) = Self::apply_matrix(&half_gcd_matrix, &a, &b);
+            matrix = Self::multiply_matrices(&half_gcd_matrix, &matrix);
+        }
+
+        // normalize result to ensure the gcd, _i.e._, `a` has leading coefficient 1
+        let lc = a.leading_coefficient().unwrap_or(FF::ONE);
+        let normalize = |mut poly: Self| {
+            poly.scalar_mul_mut(lc.inverse());
+            poly
+        };
+
+        let [[a_factor, b_factor], _] = matrix;
+        let [gcd, a_factor, b_factor] = [a, a_factor, b_factor].map(normalize);
+        (gcd, a_factor, b_factor)
+    }
+
+    /// The half-GCD of `a` and `b`, where the degree of `a` must exceed the
+    /// degree of `b`.
+    ///
+    /// Returns the matrix mapping `(a, b)` to the consecutive remainders
+    /// `(c, d)` of the Euclidean algorithm for which deg(c) ≥ ⌈deg(a)/2⌉ >
+    /// deg(d). Since the quotients of the Euclidean algorithm depend only on
+    /// the leading coefficients of the remainders, the first half of them can be
+    /// computed recursively from the leading halves of `a` and `b`.
+    fn half_gcd(a: &Self, b: &Self) -> EuclideanMatrix<FF> {
+        let m = (a.degree() + 1) / 2;
+        if b.degree() < m {
+            return Self::identity_matrix();
+        }
+        if a.degree() < Self::HALF_GCD_CUTOFF_THRESHOLD {
+            return Self::naive_half_gcd(a.clone(), b.clone(), m);
+        }
+
+        let first_shift = m as usize;
+        let matrix = Self::half_gcd(
+            &a.divide_by_x_to_the_n(first_shift),
+            &b.divide_by_x_to_the_n(first_shift),
+        );
+        let (c, d) = Self::apply_matrix(&matrix, a, b);
+        if d.degree() < m {
+            return matrix;
+        }
+
+        let (quotient, e) = c.fast_divide(&d);
+        let matrix = Self::euclidean_step(matrix, &quotient);
+
+        let second_shift = (2 * m - d.degree()) as usize;
+        let second_matrix = Self::half_gcd(
+            &d.divide_by_x_to_the_n(second_shift),
+            &e.divide_by_x_to_the_n(second_shift),
+        );
+        Self::multiply_matrices(&second_matrix, &matrix)
+    }
+
+    /// The half-GCD computed by the plain Euclidean algorithm: run it until the
+    /// degree of the remainder drops below `degree_bound`.
+    fn naive_half_gcd(mut a: Self, mut b: Self, degree_bound: isize) -> EuclideanMatrix<FF> {
+        let mut matrix = Self::identity_matrix();
+        while b.degree() >= degree_bound {
+            let (quotient, remainder) = a.naive_divide(&b);
+            matrix = Self::euclidean_step(matrix, &quotient);
+            (a, b) = (b, remainder);
+        }
+        matrix
+    }
+
+    /// Division with remainder, computing the quotient through Newton
+    /// iteration on the reversed divisor.
+    ///
+    /// In [fast xgcd](Self::fast_xgcd), the degree of the dividend can greatly
+    /// exceed the degree of the divisor, in which case [long
+    /// division](Self::naive_divide) is quadratic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `divisor` is zero.
+    fn fast_divide(&self, divisor: &Self) -> (Self, Self) {
+        let quotient_degree = self.degree() - divisor.degree();
+        if quotient_degree < Self::FAST_DIVIDE_CUTOFF_THRESHOLD
+            || divisor.degree() < Self::FAST_DIVIDE_CUTOFF_THRESHOLD
+        {
+            return self.naive_divide(divisor);
+        }
+
+        // With k = deg(self) - deg(divisor), the reversed quotient is
+        // rev(self) · rev(divisor)^(-1) mod x^(k+1).
+        let precision = quotient_degree as usize + 1;
+        let divisor_inverse = divisor
+            .reverse()
+            .mod_x_to_the_n(precision)
+            .formal_power_series_inverse_newton(precision);
+        let mut quotient = self
+            .reverse()
+            .mod_x_to_the_n(precision)
+            .multiply(&divisor_inverse)
+            .coefficients;
+        quotient.resize(precision, FF::ZERO);
+        quotient.reverse();
+        let quotient = Self::new(quotient);
+
+        let remainder = self.clone() - quotient.multiply(divisor);
+        (quotient, remainder)
+    }
+
+    /// `self` divided by x^n, discarding the remainder.
+    fn divide_by_x_to_the_n(&self, n: usize) -> Self {
+        let n = n.min(self.coefficients.len());
+        Self::new(self.coefficients[n..].to_vec())
+    }
+
+    fn identity_matrix() -> EuclideanMatrix<FF> {
+        [[Self::one(), Self::zero()], [Self::zero(), Self::one()]]
+    }
+
+    /// Left-multiply the matrix by the matrix of one step of the Euclidean
+    /// algorithm with the given quotient, _i.e._, by [[0, 1], [1, -quotient]].
+    fn euclidean_step(matrix: EuclideanMatrix<FF>, quotient: &Self) -> EuclideanMatrix<FF> {
+        let [row_0, row_1] = matrix;
+        let next_row = [0, 1].map(|i| row_0[i].clone() - quotient.multiply(&row_1[i]));
+        [row_1, next_row]
+    }
+
+    fn apply_matrix(matrix: &EuclideanMatrix<FF>, a: &Self, b: &Self) -> (Self, Self) {
+        let [c, d] = matrix
+            .each_ref()
+            .map(|[m_0, m_1]| m_0.multiply(a) + m_1.multiply(b));
+        (c, d)
+    }
+
+    fn multiply_matrices(
+        lhs: &EuclideanMatrix<FF>,
+        rhs: &EuclideanMatrix<FF>,
+    ) -> EuclideanMatrix<FF> {
+        let [[r_00, r_01], [r_10, r_11]] = rhs;
+        lhs.each_ref().map(|[l_0, l_1]| {
+            [
+                l_0.multiply(r_00) + l_1.multiply(r_10),
+                l_0.multiply(r_01) + l_1.multiply(r_11),
+            ]
+        })
+    }
 }
 
 impl<FF: FiniteField> Polynomial<FF> {
@@ -3289,6 +3850,62 @@ mod test_polynomials {
         prop_assert_eq!(fast_values, fast_coset_values);
     }
 
+    #[proptest]
+    fn batch_coset_evaluation_and_fast_coset_evaluation_are_identical(
+        #[strategy(vec(arb(), 0..5))] polynomials: Vec<Polynomial<XFieldElement>>,
+        offset: BFieldElement,
+        #[strategy(0..8usize)]
+        #[map(|x: usize| 1 << x)]
+        // due to current limitation in `Polynomial::batch_coset_evaluate`
+        #[filter(#polynomials.iter().all(|p| (#root_order as isize) > p.degree()))]
+        root_order: usize,
+    ) {
+        let root_of_unity = BFieldElement::primitive_root_of_unity(root_order as u64).unwrap();
+        let codewords = polynomials
+            .iter()
+            .map(|p| p.fast_coset_evaluate(offset, root_of_unity, root_order))
+            .collect_vec();
+        let batch_codewords =
+            Polynomial::batch_coset_evaluate(&polynomials, offset, root_of_unity, root_order);
+        prop_assert_eq!(codewords, batch_codewords);
+    }
+
+    #[proptest(cases = 20)]
+    fn batch_coset_evaluation_of_zero_polynomials_on_empty_domain_gives_empty_codewords(
+        #[strategy(0_usize..5)] num_polynomials: usize,
+        offset: BFieldElement,
+    ) {
+        let polynomials = vec![Polynomial::<XFieldElement>::zero(); num_polynomials];
+        let codewords = Polynomial::batch_coset_evaluate(&polynomials, offset, bfe!(1), 0);
+        prop_assert_eq!(
+            vec![Vec::<XFieldElement>::new(); num_polynomials],
+            codewords
+        );
+    }
+
+    #[proptest(cases = 20)]
+    fn fast_coset_evaluation_and_interpolation_support_domains_of_non_power_of_two_order(
+        #[strategy(0_usize..6)] _log_2_of_power_of_two_factor: usize,
+        #[strategy(prop_oneof![Just(3_usize), Just(5), Just(15), Just(17)])] _odd_factor: usize,
+        #[strategy(Just(#_odd_factor << #_log_2_of_power_of_two_factor))] order: usize,
+        #[strategy(vec(arb(), #order))] coefficients: Vec<BFieldElement>,
+        #[filter(!#offset.is_zero())] offset: BFieldElement,
+    ) {
+        let polynomial = Polynomial::new(coefficients);
+        let generator = BFieldElement::primitive_root_of_unity_of_any_order(order as u64).unwrap();
+        let domain = coset_domain_of_size_from_generator_with_offset(order, generator, offset);
+
+        let values = polynomial.fast_coset_evaluate(offset, generator, order);
+        prop_assert_eq!(polynomial.batch_evaluate(&domain), values.clone());
+
+        let interpolant = Polynomial::fast_coset_interpolate(offset, generator, &values);
+        prop_assert_eq!(polynomial.clone(), interpolant);
+
+        let batch_values =
+            Polynomial::batch_coset_evaluate(&[polynomial], offset, generator, order);
+        prop_assert_eq!(vec![values], batch_values);
+    }
+
     #[proptest]
     fn fast_coset_interpolation_and_and_fast_interpolation_on_coset_are_identical(
         #[filter(!#offset.is_zero())] offset: BFieldElement,
@@ -3494,6 +4111,178 @@ mod test_polynomials {
         prop_assert_eq!(gcd, a * x + b * y);
     }
 
+    #[proptest]
+    fn fast_xgcd_agrees_with_naive_xgcd(
+        #[strategy(vec(arb(), 0..200))] x: Vec<BFieldElement>,
+        #[strategy(vec(arb(), 0..200))] y: Vec<BFieldElement>,
+    ) {
+        let (x, y) = (Polynomial::new(x), Polynomial::new(y));
+        let fast = Polynomial::fast_xgcd(x.clone(), y.clone());
+        prop_assert_eq!(Polynomial::naive_xgcd(x, y), fast);
+    }
+
+    #[proptest(cases = 50)]
+    fn fast_xgcd_finds_common_factor(
+        #[strategy(vec(arb(), 1..100))] common_factor: Vec<XFieldElement>,
+        #[strategy(vec(arb(), 0..200))] x: Vec<XFieldElement>,
+        #[strategy(vec(arb(), 0..200))] y: Vec<XFieldElement>,
+    ) {
+        let common_factor = Polynomial::new(common_factor);
+        let x = Polynomial::new(x).multiply(&common_factor);
+        let y = Polynomial::new(y).multiply(&common_factor);
+
+        let (gcd, a, b) = Polynomial::fast_xgcd(x.clone(), y.clone());
+        prop_assert_eq!(gcd.clone(), a.multiply(&x) + b.multiply(&y));
+        if !x.is_zero() || !y.is_zero() {
+            prop_assert!(gcd.leading_coefficient().unwrap().is_one());
+            prop_assert!(gcd.reduce(&common_factor).is_zero());
+            prop_assert!(x.reduce(&gcd).is_zero());
+            prop_assert!(y.reduce(&gcd).is_zero());
+        }
+    }
+
+    #[proptest]
+    fn fast_divide_agrees_with_naive_divide(
+        #[strategy(vec(arb(), 0..200))] dividend: Vec<BFieldElement>,
+        #[strategy(vec(arb(), 1..50))]
+        #[filter(!Polynomial::new(#divisor.clone()).is_zero())]
+        divisor: Vec<BFieldElement>,
+    ) {
+        let (dividend, divisor) = (Polynomial::new(dividend), Polynomial::new(divisor));
+        let naive = dividend.naive_divide(&divisor);
+        prop_assert_eq!(naive, dividend.fast_divide(&divisor));
+    }
+
+    #[test]
+    fn fast_xgcd_handles_edge_cases() {
+        let zero = Polynomial::<BFieldElement>::zero;
+        let poly = Polynomial::new(bfe_vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+        for (x, y) in [
+            (zero(), zero()),
+            (poly.clone(), zero()),
+            (zero(), poly.clone()),
+            (poly.clone(), poly.clone()),
+            (poly.clone(), poly.shift_coefficients(20)),
+        ] {
+            let naive = Polynomial::naive_xgcd(x.clone(), y.clone());
+            assert_eq!(naive, Polynomial::fast_xgcd(x, y));
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn roots_of_product_of_linear_factors_are_found(
+        #[strategy(vec(arb(), 0..10))] roots: Vec<BFieldElement>,
+        #[strategy(arb())]
+        #[filter(!#leading_coefficient.is_zero())]
+        leading_coefficient: BFieldElement,
+    ) {
+        // x² - 7 has no roots since 7 is not a square
+        let polynomial = Polynomial::zerofier(&roots)
+            * Polynomial::new(bfe_vec![-7, 0, 1])
+            * Polynomial::from_constant(leading_coefficient);
+
+        let mut expected_roots = roots;
+        expected_roots.sort_by_key(BFieldElement::value);
+        expected_roots.dedup();
+        prop_assert_eq!(expected_roots, polynomial.roots());
+    }
+
+    #[test]
+    fn constant_polynomials_have_no_roots_and_are_not_irreducible() {
+        let constant = Polynomial::from_constant(bfe!(42));
+        assert!(constant.roots().is_empty());
+        assert!(!constant.is_irreducible());
+        assert!(constant.factor().is_empty());
+        assert!(!Polynomial::<BFieldElement>::zero().is_irreducible());
+    }
+
+    #[test]
+    #[should_panic(expected = "Every field element is a root of zero.")]
+    fn finding_roots_of_zero_panics() {
+        let _ = Polynomial::<BFieldElement>::zero().roots();
+    }
+
+    #[test]
+    fn irreducibility_of_known_polynomials_is_recognized() {
+        let irreducible = [
+            Polynomial::new(bfe_vec![1, 1]),
+            Polynomial::new(bfe_vec![-7, 0, 1]),
+            XFieldElement::shah_polynomial(),
+            Polynomial::new(bfe_vec![-7, 0, 0, 0, 1]),
+            Polynomial::new(bfe_vec![-3, 0, 0, 0, 0, 1]),
+        ];
+        for polynomial in irreducible {
+            assert!(polynomial.is_irreducible(), "{polynomial}");
+        }
+
+        let reducible = [
+            Polynomial::new(bfe_vec![-1, 0, 1]),
+            Polynomial::new(bfe_vec![-16, 0, 0, 0, 1]),
+            XFieldElement::shah_polynomial() * XFieldElement::shah_polynomial(),
+            Polynomial::new(bfe_vec![-7, 0, 1]) * Polynomial::new(bfe_vec![2, 0, 0, 1]),
+        ];
+        for polynomial in reducible {
+            assert!(!polynomial.is_irreducible(), "{polynomial}");
+        }
+    }
+
+    #[proptest(cases = 10)]
+    fn factorization_multiplies_to_monic_polynomial(
+        #[strategy(vec(arb(), 2..12))] coefficients: Vec<BFieldElement>,
+        #[strategy(1_usize..4)] repetitions: usize,
+    ) {
+        let polynomial = Polynomial::new(coefficients);
+        let polynomial =
+            (0..repetitions).fold(Polynomial::one(), |acc, _| acc * polynomial.clone());
+        prop_assume!(polynomial.degree() > 0);
+
+        let factors = polynomial.factor();
+        let product = factors
+            .iter()
+            .fold(Polynomial::one(), |acc, (factor, multiplicity)| {
+                (0..*multiplicity).fold(acc, |acc, _| acc * factor.clone())
+            });
+        let leading_coefficient = polynomial.leading_coefficient().unwrap();
+        prop_assert_eq!(
+            polynomial,
+            product * Polynomial::from_constant(leading_coefficient)
+        );
+
+        for (factor, multiplicity) in factors {
+            prop_assert!(factor.is_irreducible());
+            prop_assert!(factor.leading_coefficient().unwrap().is_one());
+            prop_assert_eq!(0, multiplicity % repetitions);
+        }
+    }
+
+    #[test]
+    fn factorization_of_known_polynomial_is_correct() {
+        let x = Polynomial::new(bfe_vec![0, 1]);
+        let x_minus_5 = Polynomial::new(bfe_vec![-5, 1]);
+        let x2_minus_7 = Polynomial::new(bfe_vec![-7, 0, 1]);
+        let shah = XFieldElement::shah_polynomial();
+        let quartic = Polynomial::new(bfe_vec![-7, 0, 0, 0, 1]);
+
+        let polynomial = x.clone()
+            * x_minus_5.clone()
+            * x_minus_5.clone()
+            * x_minus_5.clone()
+            * x2_minus_7.clone()
+            * shah.clone()
+            * shah.clone()
+            * quartic.clone()
+            * Polynomial::from_constant(bfe!(3));
+        let expected = vec![
+            (x, 1),
+            (x_minus_5, 3),
+            (x2_minus_7, 1),
+            (shah, 2),
+            (quartic, 1),
+        ];
+        assert_eq!(expected, polynomial.factor());
+        assert_eq!(bfe_vec![0, 5], polynomial.roots());
+    }
+
     #[proptest]
     fn add_assign_is_equivalent_to_adding_and_assigning(
         a: Polynomial<BFieldElement>,
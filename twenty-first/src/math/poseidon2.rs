@@ -0,0 +1,821 @@
+//! The [Poseidon2](https://eprint.iacr.org/2023/323) permutation over the
+//! [`BFieldElement`]s, and the sponge and hash function based on it.
+//!
+//! The instance has state width 16, S-box x^7, 8 full rounds, and 22 partial
+//! rounds. It is the instance of the [reference implementation][reference] for
+//! this field and width: the permutations agree. The round constants are sampled
+//! from the Grain LFSR. The diagonal of the partial rounds' matrix is sampled
+//! from the same LFSR, continuing after the round constants, until the
+//! sufficient condition of the paper for the absence of arbitrarily long
+//! subspace trails holds.
+//!
+//! Sponge and hash function follow the same conventions as [`Tip5`]: the rate
+//! is overwritten when absorbing, the capacity is set to all ones for
+//! [fixed-length](Domain::FixedLength) inputs, and variable-length inputs are
+//! padded with a one followed by zeros.
+//!
+//! [`Tip5`]: crate::prelude::Tip5
+//! [reference]: https://github.com/HorizenLabs/poseidon2
+
+use arbitrary::Arbitrary;
+use bfieldcodec_derive::BFieldCodec;
+use get_size::GetSize;
+use itertools::Itertools;
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::digest::Digest;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::algebraic_hasher::Domain;
+use crate::util_types::algebraic_hasher::Sponge;
+
+pub const STATE_SIZE: usize = 16;
+pub const CAPACITY: usize = 6;
+pub const RATE: usize = 10;
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 22;
+
+/// The round constants of the full rounds. To verify their correctness, see the
+/// test “round_constants_are_correct.”
+pub const EXTERNAL_ROUND_CONSTANTS: [[BFieldElement; STATE_SIZE]; NUM_FULL_ROUNDS] = [
+    [
+        BFieldElement::new(1579613653969377219),
+        BFieldElement::new(15509510893087893340),
+        BFieldElement::new(10090715174060125222),
+        BFieldElement::new(5625716564419252202),
+        BFieldElement::new(3006005019077469174),
+        BFieldElement::new(18314693207014427912),
+        BFieldElement::new(10170571510627764565),
+        BFieldElement::new(2027625550790675754),
+        BFieldElement::new(3983470257916202094),
+        BFieldElement::new(3423470109396435354),
+        BFieldElement::new(3450488264035752368),
+        BFieldElement::new(3151070045406026687),
+        BFieldElement::new(13462781804006123550),
+        BFieldElement::new(13288575772684627216),
+        BFieldElement::new(13745549378090937523),
+        BFieldElement::new(7780139165529418388),
+    ],
+    [
+        BFieldElement::new(11699769881923825909),
+        BFieldElement::new(4349260652212695338),
+        BFieldElement::new(8228348357294662898),
+        BFieldElement::new(12037145371429260559),
+        BFieldElement::new(7743383997471469184),
+        BFieldElement::new(7842006993218978420),
+        BFieldElement::new(18369471830829996810),
+        BFieldElement::new(10157815501749214163),
+        BFieldElement::new(11128005290408113568),
+        BFieldElement::new(12272917402811871099),
+        BFieldElement::new(106820976709770637),
+        BFieldElement::new(6178857985076893256),
+        BFieldElement::new(14036787039817180193),
+        BFieldElement::new(1659458828486927362),
+        BFieldElement::new(15938183887757779615),
+        BFieldElement::new(10060334973073197601),
+    ],
+    [
+        BFieldElement::new(12437259172286625042),
+        BFieldElement::new(3143835545170851304),
+        BFieldElement::new(14361575850007152549),
+        BFieldElement::new(11810209965918630788),
+        BFieldElement::new(17667700303515849362),
+        BFieldElement::new(13451932870729425780),
+        BFieldElement::new(17496644301504593329),
+        BFieldElement::new(1778669202480014256),
+        BFieldElement::new(11124488067381312952),
+        BFieldElement::new(14273583775934080755),
+        BFieldElement::new(13257379154210125715),
+        BFieldElement::new(15182093233826554989),
+        BFieldElement::new(5343815959848316783),
+        BFieldElement::new(5696193414571279556),
+        BFieldElement::new(6089787229781325813),
+        BFieldElement::new(3717595218375990594),
+    ],
+    [
+        BFieldElement::new(12355842859741995537),
+        BFieldElement::new(14160921043125059199),
+        BFieldElement::new(13682913322779819091),
+        BFieldElement::new(12343504462597575239),
+        BFieldElement::new(14249643564311509087),
+        BFieldElement::new(15849758561085927402),
+        BFieldElement::new(12186564686511703262),
+        BFieldElement::new(690520822232718983),
+        BFieldElement::new(684200944081655198),
+        BFieldElement::new(6008958449718781068),
+        BFieldElement::new(3098079696536837500),
+        BFieldElement::new(12083506578988585239),
+        BFieldElement::new(6489163969976034646),
+        BFieldElement::new(1186411428667285248),
+        BFieldElement::new(11192474480021157091),
+        BFieldElement::new(17095617549195818058),
+    ],
+    [
+        BFieldElement::new(15766898731627159621),
+        BFieldElement::new(6714370142978148338),
+        BFieldElement::new(5906664861467821209),
+        BFieldElement::new(2197120128337974909),
+        BFieldElement::new(9400421283898153864),
+        BFieldElement::new(16740473708727660723),
+        BFieldElement::new(7287335615015370599),
+        BFieldElement::new(12399217337488717232),
+        BFieldElement::new(12655834778591227242),
+        BFieldElement::new(7516917516498792203),
+        BFieldElement::new(17291898461659961460),
+        BFieldElement::new(3483661079725019706),
+        BFieldElement::new(18038566855851505564),
+        BFieldElement::new(6166154478921479976),
+        BFieldElement::new(8824538844032254328),
+        BFieldElement::new(17138720803294251067),
+    ],
+    [
+        BFieldElement::new(4158286797471309733),
+        BFieldElement::new(1589482450994236426),
+        BFieldElement::new(4836112672767737407),
+        BFieldElement::new(6108317238076822824),
+        BFieldElement::new(11174425919404088598),
+        BFieldElement::new(2755155124184744585),
+        BFieldElement::new(13202523778257173956),
+        BFieldElement::new(6643835433125335951),
+        BFieldElement::new(2748965361441888477),
+        BFieldElement::new(14297205492001640506),
+        BFieldElement::new(12595977886586773319),
+        BFieldElement::new(257624298448087449),
+        BFieldElement::new(2744326799038764281),
+        BFieldElement::new(9097808034015176417),
+        BFieldElement::new(5523099006222423939),
+        BFieldElement::new(7256201493271212720),
+    ],
+    [
+        BFieldElement::new(9738621014989498753),
+        BFieldElement::new(237412858668409460),
+        BFieldElement::new(6405904454922718574),
+        BFieldElement::new(16343835012250148340),
+        BFieldElement::new(9342245617122491936),
+        BFieldElement::new(824922755340087111),
+        BFieldElement::new(5398764608665671277),
+        BFieldElement::new(16030622638137836774),
+        BFieldElement::new(4921899302518525668),
+        BFieldElement::new(10676667276121395674),
+        BFieldElement::new(16304445044992359788),
+        BFieldElement::new(587812931475326787),
+        BFieldElement::new(7627897473303434798),
+        BFieldElement::new(2633542348821978290),
+        BFieldElement::new(5714662808802647771),
+        BFieldElement::new(15665352327749112015),
+    ],
+    [
+        BFieldElement::new(8041781683128153420),
+        BFieldElement::new(13172746155287932121),
+        BFieldElement::new(6611413049825339003),
+        BFieldElement::new(9076340683484238287),
+        BFieldElement::new(12295614633782496176),
+        BFieldElement::new(11414162089917699064),
+        BFieldElement::new(13658053783093222498),
+        BFieldElement::new(16216654076620539925),
+        BFieldElement::new(17782598995085730495),
+        BFieldElement::new(18362903988365127450),
+        BFieldElement::new(11596821426909532371),
+        BFieldElement::new(13499421621575350806),
+        BFieldElement::new(15256312875610823807),
+        BFieldElement::new(1558929959921731273),
+        BFieldElement::new(17567284148478398281),
+        BFieldElement::new(4615393526180652407),
+    ],
+];
+
+/// The round constants of the partial rounds. To verify their correctness, see
+/// the test “round_constants_are_correct.”
+pub const INTERNAL_ROUND_CONSTANTS: [BFieldElement; NUM_PARTIAL_ROUNDS] = [
+    BFieldElement::new(2949845317987848448),
+    BFieldElement::new(6935557324168256069),
+    BFieldElement::new(2055390472619727748),
+    BFieldElement::new(12399309956582731760),
+    BFieldElement::new(3088058410657255303),
+    BFieldElement::new(8790959064194804235),
+    BFieldElement::new(4582664136559626757),
+    BFieldElement::new(14821962908959377092),
+    BFieldElement::new(2002685046256118388),
+    BFieldElement::new(15689593639089632368),
+    BFieldElement::new(10512046881800463697),
+    BFieldElement::new(11973738501993105496),
+    BFieldElement::new(13009070935614847359),
+    BFieldElement::new(12908033183314664096),
+    BFieldElement::new(9530849385991780809),
+    BFieldElement::new(17528314872631229800),
+    BFieldElement::new(17869527288639567155),
+    BFieldElement::new(7829055113315023688),
+    BFieldElement::new(3256047469251174543),
+    BFieldElement::new(3015723851705964382),
+    BFieldElement::new(5177282273995529875),
+    BFieldElement::new(16035152896984012190),
+];
+
+/// The diagonal of the matrix of the partial rounds' linear layer, minus one.
+/// The matrix is the all-ones matrix plus a diagonal matrix with these entries.
+/// Called `MAT_DIAG16_M_1` in the reference implementation. To verify their
+/// correctness, see the test “internal_matrix_is_correct.”
+pub const INTERNAL_MATRIX_DIAGONAL_MINUS_ONE: [BFieldElement; STATE_SIZE] = [
+    BFieldElement::new(16040574633112940480),
+    BFieldElement::new(14263299814608977431),
+    BFieldElement::new(770395855193680981),
+    BFieldElement::new(3459277367440070515),
+    BFieldElement::new(17087697094293314027),
+    BFieldElement::new(6694380135428747348),
+    BFieldElement::new(2034408310088972836),
+    BFieldElement::new(3434575637390274478),
+    BFieldElement::new(6052753985947965968),
+    BFieldElement::new(13608362914817483670),
+    BFieldElement::new(18163707672964630459),
+    BFieldElement::new(14373610220374016704),
+    BFieldElement::new(6226282807566121054),
+    BFieldElement::new(3643354756180461803),
+    BFieldElement::new(13046961313070095543),
+    BFieldElement::new(8594143216561850811),
+];
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, GetSize, BFieldCodec, Arbitrary,
+)]
+pub struct Poseidon2 {
+    pub state: [BFieldElement; STATE_SIZE],
+}
+
+impl Poseidon2 {
+    #[inline]
+    pub const fn new(domain: Domain) -> Self {
+        use Domain::*;
+
+        let mut state = [BFieldElement::ZERO; STATE_SIZE];
+
+        match domain {
+            VariableLength => (),
+            FixedLength => {
+                let mut i = RATE;
+                while i < STATE_SIZE {
+                    state[i] = BFieldElement::ONE;
+                    i += 1;
+                }
+            }
+        }
+
+        Self { state }
+    }
+
+    #[inline(always)]
+    fn sbox(x: BFieldElement) -> BFieldElement {
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        x4 * x2 * x
+    }
+
+    /// Multiply the 4-element `chunk` by the matrix
+    ///
+    /// ```text
+    /// ⎛5 7 1 3⎞
+    /// ⎜4 6 1 1⎟
+    /// ⎜1 3 5 7⎟
+    /// ⎝1 1 4 6⎠
+    /// ```
+    ///
+    /// using the addition chain from the Poseidon2 paper.
+    #[inline(always)]
+    fn m4(chunk: &mut [BFieldElement]) {
+        let t0 = chunk[0] + chunk[1];
+        let t1 = chunk[2] + chunk[3];
+        let t2 = chunk[1] + chunk[1] + t1;
+        let t3 = chunk[3] + chunk[3] + t0;
+        let t1_2 = t1 + t1;
+        let t0_2 = t0 + t0;
+        let t4 = t1_2 + t1_2 + t3;
+        let t5 = t0_2 + t0_2 + t2;
+        let t6 = t3 + t5;
+        let t7 = t2 + t4;
+
+        chunk[0] = t6;
+        chunk[1] = t5;
+        chunk[2] = t7;
+        chunk[3] = t4;
+    }
+
+    /// The linear layer of the full rounds: the circulant block matrix
+    /// circ(2·M4, M4, M4, M4).
+    #[inline(always)]
+    fn external_linear_layer(&mut self) {
+        self.state.chunks_exact_mut(4).for_each(Self::m4);
+
+        let mut sums = [BFieldElement::ZERO; 4];
+        for chunk in self.state.chunks_exact(4) {
+            for (sum, &element) in sums.iter_mut().zip(chunk) {
+                *sum += element;
+            }
+        }
+
+        for chunk in self.state.chunks_exact_mut(4) {
+            for (element, &sum) in chunk.iter_mut().zip(&sums) {
+                *element += sum;
+            }
+        }
+    }
+
+    /// The linear layer of the partial rounds.
+    #[inline(always)]
+    fn internal_linear_layer(&mut self) {
+        let sum = self.state.iter().copied().sum::<BFieldElement>();
+        for (element, &diagonal) in self
+            .state
+            .iter_mut()
+            .zip(&INTERNAL_MATRIX_DIAGONAL_MINUS_ONE)
+        {
+            *element = *element * diagonal + sum;
+        }
+    }
+
+    #[inline(always)]
+    fn external_round(&mut self, round_index: usize) {
+        for (element, &constant) in self
+            .state
+            .iter_mut()
+            .zip(&EXTERNAL_ROUND_CONSTANTS[round_index])
+        {
+            *element = Self::sbox(*element + constant);
+        }
+        self.external_linear_layer();
+    }
+
+    #[inline(always)]
+    fn internal_round(&mut self, round_index: usize) {
+        self.state[0] = Self::sbox(self.state[0] + INTERNAL_ROUND_CONSTANTS[round_index]);
+        self.internal_linear_layer();
+    }
+
+    #[inline(always)]
+    pub fn permutation(&mut self) {
+        self.external_linear_layer();
+        for i in 0..NUM_FULL_ROUNDS / 2 {
+            self.external_round(i);
+        }
+        for i in 0..NUM_PARTIAL_ROUNDS {
+            self.internal_round(i);
+        }
+        for i in NUM_FULL_ROUNDS / 2..NUM_FULL_ROUNDS {
+            self.external_round(i);
+        }
+    }
+
+    /// Hash 10 elements, or two digests. There is no padding because the input
+    /// length is fixed.
+    pub fn hash_10(input: &[BFieldElement; RATE]) -> [BFieldElement; Digest::LEN] {
+        let mut sponge = Self::new(Domain::FixedLength);
+
+        // absorb once
+        sponge.state[..RATE].copy_from_slice(input);
+
+        sponge.permutation();
+
+        // squeeze once
+        sponge.state[..Digest::LEN].try_into().unwrap()
+    }
+}
+
+impl AlgebraicHasher for Poseidon2 {
+    fn hash_pair(left: Digest, right: Digest) -> Digest {
+        let mut sponge = Self::new(Domain::FixedLength);
+        sponge.state[..Digest::LEN].copy_from_slice(&left.values());
+        sponge.state[Digest::LEN..2 * Digest::LEN].copy_from_slice(&right.values());
+
+        sponge.permutation();
+
+        let digest_values = sponge.state[..Digest::LEN].try_into().unwrap();
+        Digest::new(digest_values)
+    }
+}
+
+impl Sponge for Poseidon2 {
+    const RATE: usize = RATE;
+
+    fn init() -> Self {
+        Self::new(Domain::VariableLength)
+    }
+
+    fn absorb(&mut self, input: [BFieldElement; RATE]) {
+        self.state[..RATE]
+            .iter_mut()
+            .zip_eq(&input)
+            .for_each(|(a, &b)| *a = b);
+
+        self.permutation();
+    }
+
+    fn squeeze(&mut self) -> [BFieldElement; RATE] {
+        let produce: [BFieldElement; RATE] = (&self.state[..RATE]).try_into().unwrap();
+        self.permutation();
+
+        produce
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use num_traits::Zero;
+
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::polynomial::Polynomial;
+    use crate::math::traits::Inverse;
+    use crate::math::traits::ModPowU32;
+
+    /// The self-shrinking Grain LFSR used to derive the constants of Poseidon and
+    /// Poseidon2, as in the reference implementation accompanying the papers.
+    struct GrainLfsr {
+        state: VecDeque<bool>,
+    }
+
+    impl GrainLfsr {
+        fn new() -> Self {
+            let field = 1_u64; // prime field
+            let sbox = 0_u64; // x^α with α > 0
+            let field_size = 64_u64;
+            let bits = |value: u64, num_bits: usize| {
+                (0..num_bits)
+                    .rev()
+                    .map(move |i| (value >> i) & 1 == 1)
+                    .collect_vec()
+            };
+            let state = [
+                bits(field, 2),
+                bits(sbox, 4),
+                bits(field_size, 12),
+                bits(STATE_SIZE as u64, 12),
+                bits(NUM_FULL_ROUNDS as u64, 10),
+                bits(NUM_PARTIAL_ROUNDS as u64, 10),
+                vec![true; 30],
+            ]
+            .concat()
+            .into();
+
+            let mut lfsr = Self { state };
+            for _ in 0..160 {
+                lfsr.next_raw_bit();
+            }
+            lfsr
+        }
+
+        fn next_raw_bit(&mut self) -> bool {
+            let s = &self.state;
+            let new_bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+            self.state.pop_front();
+            self.state.push_back(new_bit);
+            new_bit
+        }
+
+        fn next_bit(&mut self) -> bool {
+            loop {
+                let selector = self.next_raw_bit();
+                let bit = self.next_raw_bit();
+                if selector {
+                    return bit;
+                }
+            }
+        }
+
+        fn next_field_element(&mut self) -> BFieldElement {
+            loop {
+                let value = (0..64).fold(0_u64, |acc, _| (acc << 1) | u64::from(self.next_bit()));
+                if value < BFieldElement::P {
+                    return BFieldElement::new(value);
+                }
+            }
+        }
+
+        fn next_state(&mut self) -> [BFieldElement; STATE_SIZE] {
+            [(); STATE_SIZE].map(|()| self.next_field_element())
+        }
+    }
+
+    type Matrix = [[BFieldElement; STATE_SIZE]; STATE_SIZE];
+
+    fn internal_matrix(diagonal_minus_one: &[BFieldElement; STATE_SIZE]) -> Matrix {
+        let mut matrix = [[BFieldElement::ONE; STATE_SIZE]; STATE_SIZE];
+        for (i, &d) in diagonal_minus_one.iter().enumerate() {
+            matrix[i][i] += d;
+        }
+        matrix
+    }
+
+    fn matrix_mul(a: &Matrix, b: &Matrix) -> Matrix {
+        let mut product = [[BFieldElement::ZERO; STATE_SIZE]; STATE_SIZE];
+        for i in 0..STATE_SIZE {
+            for j in 0..STATE_SIZE {
+                product[i][j] = (0..STATE_SIZE).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        product
+    }
+
+    fn determinant(mut matrix: Matrix) -> BFieldElement {
+        let mut det = BFieldElement::ONE;
+        for col in 0..STATE_SIZE {
+            let Some(pivot) = (col..STATE_SIZE).find(|&row| !matrix[row][col].is_zero()) else {
+                return BFieldElement::ZERO;
+            };
+            if pivot != col {
+                matrix.swap(pivot, col);
+                det = -det;
+            }
+            det *= matrix[col][col];
+            let pivot_inverse = matrix[col][col].inverse();
+            let pivot_row = matrix[col];
+            for row in &mut matrix[col + 1..] {
+                let factor = row[col] * pivot_inverse;
+                for (entry, &pivot_entry) in row.iter_mut().zip(&pivot_row).skip(col) {
+                    *entry -= factor * pivot_entry;
+                }
+            }
+        }
+        det
+    }
+
+    fn characteristic_polynomial(matrix: &Matrix) -> Polynomial<BFieldElement> {
+        let domain = (0..=STATE_SIZE as u64)
+            .map(BFieldElement::new)
+            .collect_vec();
+        let values = domain
+            .iter()
+            .map(|&x| {
+                let mut x_minus_matrix = matrix.map(|row| row.map(|entry| -entry));
+                for (i, row) in x_minus_matrix.iter_mut().enumerate() {
+                    row[i] += x;
+                }
+                determinant(x_minus_matrix)
+            })
+            .collect_vec();
+        Polynomial::interpolate(&domain, &values)
+    }
+
+    /// The sufficient condition of the Poseidon2 paper for the absence of
+    /// arbitrarily long subspace trails: the minimal polynomials of the first
+    /// 2·[`STATE_SIZE`] powers of the matrix are irreducible and of maximal
+    /// degree.
+    fn has_no_long_subspace_trails(matrix: &Matrix) -> bool {
+        let mut power = *matrix;
+        for _ in 0..2 * STATE_SIZE {
+            let characteristic_polynomial = characteristic_polynomial(&power);
+            if !characteristic_polynomial.is_irreducible() {
+                return false;
+            }
+            power = matrix_mul(matrix, &power);
+        }
+        true
+    }
+
+    #[test]
+    fn get_size_test() {
+        assert_eq!(
+            STATE_SIZE * BFieldElement::ZERO.get_size(),
+            Poseidon2::init().get_size()
+        );
+    }
+
+    #[test]
+    fn round_constants_are_correct() {
+        let mut lfsr = GrainLfsr::new();
+        let first_external_round_constants = (0..NUM_FULL_ROUNDS / 2)
+            .map(|_| lfsr.next_state())
+            .collect_vec();
+        let internal_round_constants = (0..NUM_PARTIAL_ROUNDS)
+            .map(|_| lfsr.next_field_element())
+            .collect_vec();
+        let last_external_round_constants = (0..NUM_FULL_ROUNDS / 2)
+            .map(|_| lfsr.next_state())
+            .collect_vec();
+        let external_round_constants = [
+            first_external_round_constants,
+            last_external_round_constants,
+        ]
+        .concat();
+
+        println!(
+            "In case you changed something, here are all external round constants:\n{}",
+            external_round_constants
+                .iter()
+                .map(|round| round.iter().map(|c| c.value()).join(", "))
+                .join("\n")
+        );
+        println!(
+            "and all internal round constants:\n{}",
+            internal_round_constants
+                .iter()
+                .map(|c| c.value())
+                .join(", ")
+        );
+
+        assert_eq!(EXTERNAL_ROUND_CONSTANTS.to_vec(), external_round_constants);
+        assert_eq!(INTERNAL_ROUND_CONSTANTS.to_vec(), internal_round_constants);
+    }
+
+    #[test]
+    fn internal_matrix_is_correct() {
+        // continue sampling after all round constants
+        let mut lfsr = GrainLfsr::new();
+        let num_round_constants = NUM_FULL_ROUNDS * STATE_SIZE + NUM_PARTIAL_ROUNDS;
+        for _ in 0..num_round_constants {
+            lfsr.next_field_element();
+        }
+
+        // the LFSR samples the diagonal itself, not the diagonal minus one
+        let diagonal_minus_one = loop {
+            let candidate = lfsr.next_state().map(|d| d - BFieldElement::ONE);
+            if has_no_long_subspace_trails(&internal_matrix(&candidate)) {
+                break candidate;
+            }
+        };
+
+        println!(
+            "In case you changed something, here is the internal matrix' diagonal minus one:\n{}",
+            diagonal_minus_one.iter().map(|c| c.value()).join(", ")
+        );
+        assert_eq!(INTERNAL_MATRIX_DIAGONAL_MINUS_ONE, diagonal_minus_one);
+    }
+
+    #[test]
+    fn external_matrix_is_circulant_block_matrix_of_m4() {
+        let m4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]];
+        for i in 0..STATE_SIZE {
+            let mut sponge = Poseidon2 {
+                state: [BFieldElement::ZERO; STATE_SIZE],
+            };
+            sponge.state[i] = BFieldElement::ONE;
+            sponge.external_linear_layer();
+
+            for (j, &entry) in sponge.state.iter().enumerate() {
+                let block_factor = if i / 4 == j / 4 { 2 } else { 1 };
+                let expected_entry = block_factor * m4[j % 4][i % 4];
+                assert_eq!(BFieldElement::new(expected_entry), entry);
+            }
+        }
+    }
+
+    #[test]
+    fn internal_linear_layer_agrees_with_internal_matrix() {
+        let matrix = internal_matrix(&INTERNAL_MATRIX_DIAGONAL_MINUS_ONE);
+        for i in 0..STATE_SIZE {
+            let mut sponge = Poseidon2 {
+                state: [BFieldElement::ZERO; STATE_SIZE],
+            };
+            sponge.state[i] = BFieldElement::ONE;
+            sponge.internal_linear_layer();
+
+            let column = matrix.map(|row| row[i]);
+            assert_eq!(column, sponge.state);
+        }
+    }
+
+    #[proptest]
+    fn sbox_is_seventh_power(#[strategy(arb())] x: BFieldElement) {
+        prop_assert_eq!(x.mod_pow_u32(7), Poseidon2::sbox(x));
+    }
+
+    #[test]
+    fn sbox_is_permutation() {
+        // x ↦ x^α is a permutation if and only if α is coprime to p - 1
+        let p_minus_one = BFieldElement::P - 1;
+        assert_ne!(0, p_minus_one % 7);
+    }
+
+    /// The known-answer test of the reference implementation: the permutation of
+    /// the state 0, 1, …, 15.
+    #[test]
+    fn permutation_test_vectors() {
+        let mut sponge = Poseidon2 {
+            state: (0..STATE_SIZE as u64)
+                .map(BFieldElement::new)
+                .collect_vec()
+                .try_into()
+                .unwrap(),
+        };
+        sponge.permutation();
+
+        let expected = [
+            0x85c54702470d9756,
+            0xaa53c7a7d52d9898,
+            0x285128096efb0dd7,
+            0xf3fde5edd3050ac8,
+            0xc7b65efd040df908,
+            0x4be3f6c467f57ae9,
+            0x274e9a67b41754fb,
+            0x0f7d39cd5de94dac,
+            0xd0224b9794d0b78c,
+            0x372f6139570042e1,
+            0xce6e8a93dc4ec26c,
+            0xace65e30a4daf7af,
+            0x016f2824cc1ba3db,
+            0x2e8f3af37c434dec,
+            0xc80831bb6e09da01,
+            0x3a7d670bf1a86ee8,
+        ]
+        .map(BFieldElement::new);
+        assert_eq!(expected, sponge.state);
+    }
+
+    /// The reference implementation defines no sponge. These vectors follow from
+    /// the [reference permutation](permutation_test_vectors) and the sponge
+    /// conventions of this module.
+    #[test]
+    fn hash10_test_vectors() {
+        let mut preimage = [BFieldElement::ZERO; RATE];
+        let mut digest: [BFieldElement; Digest::LEN];
+        for i in 0..6 {
+            digest = Poseidon2::hash_10(&preimage);
+            preimage[i..Digest::LEN + i].copy_from_slice(&digest);
+        }
+        digest = Poseidon2::hash_10(&preimage);
+
+        let final_digest = [
+            1521320460417796386,
+            7206055248091537980,
+            12998435305608274949,
+            16316657002188437390,
+            11220515716387371036,
+        ]
+        .map(BFieldElement::new);
+        assert_eq!(final_digest, digest);
+    }
+
+    /// The reference implementation defines no sponge. These vectors follow from
+    /// the [reference permutation](permutation_test_vectors) and the sponge
+    /// conventions of this module.
+    #[test]
+    fn hash_varlen_test_vectors() {
+        let mut digest_sum = [BFieldElement::ZERO; Digest::LEN];
+        for i in 0..20 {
+            let preimage = (0..i).map(BFieldElement::new).collect_vec();
+            let digest = Poseidon2::hash_varlen(&preimage);
+            digest_sum
+                .iter_mut()
+                .zip(digest.values().iter())
+                .for_each(|(s, d)| *s += *d);
+        }
+
+        let expected_sum = [
+            1253216002948129145,
+            2365513937524635740,
+            18386436071038317308,
+            18050687636440073520,
+            16621463669426683610,
+        ]
+        .map(BFieldElement::new);
+        assert_eq!(expected_sum, digest_sum);
+    }
+
+    #[proptest]
+    fn hash_pair_agrees_with_hash_10(
+        #[strategy(arb())] left: Digest,
+        #[strategy(arb())] right: Digest,
+    ) {
+        let input = [left.values(), right.values()].concat().try_into().unwrap();
+        let digest = Poseidon2::hash_pair(left, right);
+        prop_assert_eq!(Poseidon2::hash_10(&input), digest.values());
+    }
+
+    #[proptest]
+    fn hash_varlen_agrees_with_padding_absorbing_and_squeezing(
+        #[strategy(arb())] preimage: Vec<BFieldElement>,
+    ) {
+        let mut sponge = Poseidon2::init();
+        sponge.pad_and_absorb_all(&preimage);
+        let squeeze_result = sponge.squeeze();
+        let digest = Digest::new((&squeeze_result[..Digest::LEN]).try_into().unwrap());
+
+        prop_assert_eq!(digest, Poseidon2::hash_varlen(&preimage));
+    }
+
+    #[test]
+    fn domains_are_separated() {
+        let preimage = [BFieldElement::ZERO; RATE];
+        let fixed_length_digest = Poseidon2::hash_10(&preimage);
+
+        let mut sponge = Poseidon2::new(Domain::VariableLength);
+        sponge.state[..RATE].copy_from_slice(&preimage);
+        sponge.permutation();
+        let variable_length_digest: [_; Digest::LEN] =
+            sponge.state[..Digest::LEN].try_into().unwrap();
+
+        assert_ne!(fixed_length_digest, variable_length_digest);
+    }
+
+    #[test]
+    fn poseidon2_and_tip5_disagree() {
+        let preimage = [BFieldElement::ZERO; RATE];
+        let tip5_digest = crate::math::tip5::Tip5::hash_10(&preimage);
+        assert_ne!(tip5_digest, Poseidon2::hash_10(&preimage));
+    }
+}
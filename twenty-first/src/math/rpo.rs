@@ -0,0 +1,746 @@
+//! The [Rescue-Prime Optimized](https://eprint.iacr.org/2022/1577) (RPO)
+//! permutation over the [`BFieldElement`]s, and the hash function RPO-256 based
+//! on it.
+//!
+//! The instance is the one of [Miden's implementation][miden]: the state has 12
+//! elements, the first [`CAPACITY`] of which are the capacity and the remaining
+//! [`RATE`] the rate. The permutation has 7 rounds, each applying the circulant
+//! MDS matrix with first row [`MDS_MATRIX_FIRST_ROW`], round constants, the S-box
+//! x^7, the MDS matrix again, round constants, and the inverse S-box. The round
+//! constants are derived from SHAKE256 as in the reference implementation of
+//! Rescue-Prime. [`Rpo::hash_elements`] and [`Rpo::merge`] compute RPO-256
+//! digests, which consist of [`DIGEST_LEN`] elements.
+//!
+//! The [`Sponge`] and [`AlgebraicHasher`] interfaces of this crate absorb and
+//! squeeze [10 elements](crate::util_types::algebraic_hasher::RATE) at a time and
+//! produce [`Digest`]s of 5 elements. Therefore:
+//! - [absorbing](Sponge::absorb) overwrites the rate with the first 8 elements,
+//!   permutes, overwrites the first 2 rate elements with the remaining ones, and
+//!   permutes again. [Squeezing](Sponge::squeeze) is analogous.
+//! - [`hash_varlen`](AlgebraicHasher::hash_varlen) returns the RPO-256 digest,
+//!   followed by the next rate element.
+//! - [`hash_pair`](AlgebraicHasher::hash_pair) is the
+//!   [`hash_varlen`](AlgebraicHasher::hash_varlen) of both digests' elements.
+//!
+//! [miden]: https://github.com/0xPolygonMiden/crypto
+
+use arbitrary::Arbitrary;
+use bfieldcodec_derive::BFieldCodec;
+use get_size::GetSize;
+use num_traits::ConstOne;
+use num_traits::ConstZero;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::digest::Digest;
+use crate::util_types::algebraic_hasher;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::algebraic_hasher::Sponge;
+
+pub const STATE_SIZE: usize = 12;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = 8;
+pub const DIGEST_LEN: usize = 4;
+pub const NUM_ROUNDS: usize = 7;
+
+/// The exponent of the S-box.
+pub const ALPHA: u64 = 7;
+
+/// The exponent of the inverse S-box, _i.e._, the inverse of [`ALPHA`] modulo
+/// p - 1.
+pub const ALPHA_INVERSE: u64 = 10540996611094048183;
+
+/// The first row of the circulant MDS matrix. Row `i` is the first row rotated
+/// `i` positions to the right.
+pub const MDS_MATRIX_FIRST_ROW: [u64; STATE_SIZE] = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8];
+
+/// The round constants used in the RPO permutation. Each round uses two sets of
+/// [`STATE_SIZE`] constants. To verify their correctness, see the test
+/// “round_constants_are_correct.”
+pub const ROUND_CONSTANTS: [BFieldElement; 2 * NUM_ROUNDS * STATE_SIZE] = [
+    BFieldElement::new(5789762306288267392),
+    BFieldElement::new(6522564764413701783),
+    BFieldElement::new(17809893479458208203),
+    BFieldElement::new(107145243989736508),
+    BFieldElement::new(6388978042437517382),
+    BFieldElement::new(15844067734406016715),
+    BFieldElement::new(9975000513555218239),
+    BFieldElement::new(3344984123768313364),
+    BFieldElement::new(9959189626657347191),
+    BFieldElement::new(12960773468763563665),
+    BFieldElement::new(9602914297752488475),
+    BFieldElement::new(16657542370200465908),
+    BFieldElement::new(6077062762357204287),
+    BFieldElement::new(15277620170502011191),
+    BFieldElement::new(5358738125714196705),
+    BFieldElement::new(14233283787297595718),
+    BFieldElement::new(13792579614346651365),
+    BFieldElement::new(11614812331536767105),
+    BFieldElement::new(14871063686742261166),
+    BFieldElement::new(10148237148793043499),
+    BFieldElement::new(4457428952329675767),
+    BFieldElement::new(15590786458219172475),
+    BFieldElement::new(10063319113072092615),
+    BFieldElement::new(14200078843431360086),
+    BFieldElement::new(12987190162843096997),
+    BFieldElement::new(653957632802705281),
+    BFieldElement::new(4441654670647621225),
+    BFieldElement::new(4038207883745915761),
+    BFieldElement::new(5613464648874830118),
+    BFieldElement::new(13222989726778338773),
+    BFieldElement::new(3037761201230264149),
+    BFieldElement::new(16683759727265180203),
+    BFieldElement::new(8337364536491240715),
+    BFieldElement::new(3227397518293416448),
+    BFieldElement::new(8110510111539674682),
+    BFieldElement::new(2872078294163232137),
+    BFieldElement::new(6202948458916099932),
+    BFieldElement::new(17690140365333231091),
+    BFieldElement::new(3595001575307484651),
+    BFieldElement::new(373995945117666487),
+    BFieldElement::new(1235734395091296013),
+    BFieldElement::new(14172757457833931602),
+    BFieldElement::new(707573103686350224),
+    BFieldElement::new(15453217512188187135),
+    BFieldElement::new(219777875004506018),
+    BFieldElement::new(17876696346199469008),
+    BFieldElement::new(17731621626449383378),
+    BFieldElement::new(2897136237748376248),
+    BFieldElement::new(18072785500942327487),
+    BFieldElement::new(6200974112677013481),
+    BFieldElement::new(17682092219085884187),
+    BFieldElement::new(10599526828986756440),
+    BFieldElement::new(975003873302957338),
+    BFieldElement::new(8264241093196931281),
+    BFieldElement::new(10065763900435475170),
+    BFieldElement::new(2181131744534710197),
+    BFieldElement::new(6317303992309418647),
+    BFieldElement::new(1401440938888741532),
+    BFieldElement::new(8884468225181997494),
+    BFieldElement::new(13066900325715521532),
+    BFieldElement::new(8023374565629191455),
+    BFieldElement::new(15013690343205953430),
+    BFieldElement::new(4485500052507912973),
+    BFieldElement::new(12489737547229155153),
+    BFieldElement::new(9500452585969030576),
+    BFieldElement::new(2054001340201038870),
+    BFieldElement::new(12420704059284934186),
+    BFieldElement::new(355990932618543755),
+    BFieldElement::new(9071225051243523860),
+    BFieldElement::new(12766199826003448536),
+    BFieldElement::new(9045979173463556963),
+    BFieldElement::new(12934431667190679898),
+    BFieldElement::new(5674685213610121970),
+    BFieldElement::new(5759084860419474071),
+    BFieldElement::new(13943282657648897737),
+    BFieldElement::new(1352748651966375394),
+    BFieldElement::new(17110913224029905221),
+    BFieldElement::new(1003883795902368422),
+    BFieldElement::new(4141870621881018291),
+    BFieldElement::new(8121410972417424656),
+    BFieldElement::new(14300518605864919529),
+    BFieldElement::new(13712227150607670181),
+    BFieldElement::new(17021852944633065291),
+    BFieldElement::new(6252096473787587650),
+    BFieldElement::new(18389244934624494276),
+    BFieldElement::new(16731736864863925227),
+    BFieldElement::new(4440209734760478192),
+    BFieldElement::new(17208448209698888938),
+    BFieldElement::new(8739495587021565984),
+    BFieldElement::new(17000774922218161967),
+    BFieldElement::new(13533282547195532087),
+    BFieldElement::new(525402848358706231),
+    BFieldElement::new(16987541523062161972),
+    BFieldElement::new(5466806524462797102),
+    BFieldElement::new(14512769585918244983),
+    BFieldElement::new(10973956031244051118),
+    BFieldElement::new(4887609836208846458),
+    BFieldElement::new(3027115137917284492),
+    BFieldElement::new(9595098600469470675),
+    BFieldElement::new(10528569829048484079),
+    BFieldElement::new(7864689113198939815),
+    BFieldElement::new(17533723827845969040),
+    BFieldElement::new(5781638039037710951),
+    BFieldElement::new(17024078752430719006),
+    BFieldElement::new(109659393484013511),
+    BFieldElement::new(7158933660534805869),
+    BFieldElement::new(2955076958026921730),
+    BFieldElement::new(7433723648458773977),
+    BFieldElement::new(6982293561042362913),
+    BFieldElement::new(14065426295947720331),
+    BFieldElement::new(16451845770444974180),
+    BFieldElement::new(7139138592091306727),
+    BFieldElement::new(9012006439959783127),
+    BFieldElement::new(14619614108529063361),
+    BFieldElement::new(1394813199588124371),
+    BFieldElement::new(4635111139507788575),
+    BFieldElement::new(16217473952264203365),
+    BFieldElement::new(10782018226466330683),
+    BFieldElement::new(6844229992533662050),
+    BFieldElement::new(7446486531695178711),
+    BFieldElement::new(16308865189192447297),
+    BFieldElement::new(11977192855656444890),
+    BFieldElement::new(12532242556065780287),
+    BFieldElement::new(14594890931430968898),
+    BFieldElement::new(7291784239689209784),
+    BFieldElement::new(5514718540551361949),
+    BFieldElement::new(10025733853830934803),
+    BFieldElement::new(7293794580341021693),
+    BFieldElement::new(6728552937464861756),
+    BFieldElement::new(6332385040983343262),
+    BFieldElement::new(13277683694236792804),
+    BFieldElement::new(2600778905124452676),
+    BFieldElement::new(3736792340494631448),
+    BFieldElement::new(577852220195055341),
+    BFieldElement::new(6689998335515779805),
+    BFieldElement::new(13886063479078013492),
+    BFieldElement::new(14358505101923202168),
+    BFieldElement::new(7744142531772274164),
+    BFieldElement::new(16135070735728404443),
+    BFieldElement::new(12290902521256031137),
+    BFieldElement::new(12059913662657709804),
+    BFieldElement::new(16456018495793751911),
+    BFieldElement::new(4571485474751953524),
+    BFieldElement::new(17200392109565783176),
+    BFieldElement::new(7123075680859040534),
+    BFieldElement::new(1034205548717903090),
+    BFieldElement::new(7717824418247931797),
+    BFieldElement::new(3019070937878604058),
+    BFieldElement::new(11403792746066867460),
+    BFieldElement::new(10280580802233112374),
+    BFieldElement::new(337153209462421218),
+    BFieldElement::new(13333398568519923717),
+    BFieldElement::new(3596153696935337464),
+    BFieldElement::new(8104208463525993784),
+    BFieldElement::new(14345062289456085693),
+    BFieldElement::new(17036731477169661256),
+    BFieldElement::new(17130398059294018733),
+    BFieldElement::new(519782857322261988),
+    BFieldElement::new(9625384390925085478),
+    BFieldElement::new(1664893052631119222),
+    BFieldElement::new(7629576092524553570),
+    BFieldElement::new(3485239601103661425),
+    BFieldElement::new(9755891797164033838),
+    BFieldElement::new(15218148195153269027),
+    BFieldElement::new(16460604813734957368),
+    BFieldElement::new(9643968136937729763),
+    BFieldElement::new(3611348709641382851),
+    BFieldElement::new(18256379591337759196),
+];
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, GetSize, BFieldCodec, Arbitrary,
+)]
+pub struct Rpo {
+    pub state: [BFieldElement; STATE_SIZE],
+}
+
+impl Rpo {
+    #[inline(always)]
+    fn sbox_layer(&mut self) {
+        for element in &mut self.state {
+            let sq = *element * *element;
+            let qu = sq * sq;
+            *element *= sq * qu;
+        }
+    }
+
+    /// Raise every state element to the power [`ALPHA_INVERSE`] with a fixed
+    /// addition chain of 72 multiplications. In binary, the exponent is
+    /// 1001001001001001001001001001000110110110110110110110110110110111.
+    #[inline(always)]
+    fn inverse_sbox_layer(&mut self) {
+        use BFieldElement as B;
+
+        // the exponent of every intermediate result, in binary
+        let x = self.state;
+        let t1 = x.map(|e| e * e); // 10
+        let t2 = t1.map(|e| e * e); // 100
+        let t3 = B::power_accumulator::<STATE_SIZE, 3>(t2, t2); // 100100
+        let t4 = B::power_accumulator::<STATE_SIZE, 6>(t3, t3); // (100)^4
+        let t5 = B::power_accumulator::<STATE_SIZE, 12>(t4, t4); // (100)^8
+        let t6 = B::power_accumulator::<STATE_SIZE, 6>(t5, t3); // (100)^10
+        let t7 = B::power_accumulator::<STATE_SIZE, 31>(t6, t6); // (100)^10 0 (100)^10
+
+        for (i, element) in self.state.iter_mut().enumerate() {
+            let a = t7[i] * t7[i] * t6[i]; // (100)^10 0 (110)^10 0
+            let a = a * a; // (100)^10 0 (110)^10 00
+            let a = a * a; // (100)^10 0 (110)^10 000
+            let b = t1[i] * t2[i] * x[i]; // 111
+            *element = a * b;
+        }
+    }
+
+    /// Multiply the state by the circulant MDS matrix. Since the matrix' entries
+    /// are small integers, the multiplication can be performed on the raw
+    /// Montgomery representation and reduced once. As in Tip5, the high and low
+    /// 32 bits of the raw elements are multiplied separately, so that all sums
+    /// fit into a `u64`. Tip5's [`recursive_cyclic_mul`][rcm] does not apply
+    /// here because it requires the width to be a power of two, which 12 is not.
+    ///
+    /// [rcm]: crate::math::mds::recursive_cyclic_mul
+    #[inline(always)]
+    fn mds_layer(&mut self) {
+        let lo = self.state.map(|element| element.raw_u64() & 0xffffffff);
+        let hi = self.state.map(|element| element.raw_u64() >> 32);
+        for (i, element) in self.state.iter_mut().enumerate() {
+            let (mut lo_sum, mut hi_sum) = (0, 0);
+            for j in 0..STATE_SIZE {
+                let entry = MDS_MATRIX_FIRST_ROW[(STATE_SIZE + j - i) % STATE_SIZE];
+                lo_sum += entry * lo[j];
+                hi_sum += entry * hi[j];
+            }
+
+            // 2^64 = 2^32 - 1 (mod p)
+            let sum = u128::from(lo_sum) + (u128::from(hi_sum) << 32);
+            let (sum_hi, sum_lo) = ((sum >> 64) as u64, sum as u64);
+            let (reduced, overflow) = sum_lo.overflowing_add(sum_hi * 0xffffffff);
+            let reduced = if overflow {
+                reduced + 0xffffffff
+            } else {
+                reduced
+            };
+
+            // keep the raw representation canonical
+            let reduced = if reduced >= BFieldElement::P {
+                reduced - BFieldElement::P
+            } else {
+                reduced
+            };
+            *element = BFieldElement::from_raw_u64(reduced);
+        }
+    }
+
+    #[inline(always)]
+    fn add_round_constants(&mut self, offset: usize) {
+        let round_constants = &ROUND_CONSTANTS[offset..offset + STATE_SIZE];
+        for (element, &constant) in self.state.iter_mut().zip(round_constants) {
+            *element += constant;
+        }
+    }
+
+    #[inline(always)]
+    fn round(&mut self, round_index: usize) {
+        let offset = 2 * round_index * STATE_SIZE;
+
+        self.mds_layer();
+        self.add_round_constants(offset);
+        self.sbox_layer();
+
+        self.mds_layer();
+        self.add_round_constants(offset + STATE_SIZE);
+        self.inverse_sbox_layer();
+    }
+
+    #[inline(always)]
+    pub fn permutation(&mut self) {
+        for i in 0..NUM_ROUNDS {
+            self.round(i);
+        }
+    }
+
+    /// Overwrite the rate with the given elements, starting at the rate's first
+    /// element, and apply the permutation.
+    #[inline]
+    fn overwrite_rate_and_permute(&mut self, elements: &[BFieldElement]) {
+        debug_assert!(elements.len() <= RATE);
+        self.state[CAPACITY..CAPACITY + elements.len()].copy_from_slice(elements);
+        self.permutation();
+    }
+
+    /// The sponge after absorbing the given elements as RPO-256 does: the first
+    /// capacity element is 1 if the number of elements is not a multiple of the
+    /// [`RATE`], and 0 otherwise. In the former case, the elements are padded
+    /// with a one followed by zeros.
+    fn absorb_elements(elements: &[BFieldElement]) -> Self {
+        let mut sponge = Self::default();
+        let remainder_len = elements.len() % RATE;
+        if remainder_len != 0 {
+            sponge.state[0] = BFieldElement::ONE;
+        }
+
+        let mut chunks = elements.chunks_exact(RATE);
+        for chunk in chunks.by_ref() {
+            sponge.overwrite_rate_and_permute(chunk);
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            let mut last_chunk = [BFieldElement::ZERO; RATE];
+            last_chunk[..remainder_len].copy_from_slice(remainder);
+            last_chunk[remainder_len] = BFieldElement::ONE;
+            sponge.overwrite_rate_and_permute(&last_chunk);
+        }
+
+        sponge
+    }
+
+    /// The RPO-256 digest of the given elements.
+    pub fn hash_elements(elements: &[BFieldElement]) -> [BFieldElement; DIGEST_LEN] {
+        let sponge = Self::absorb_elements(elements);
+        sponge.state[CAPACITY..CAPACITY + DIGEST_LEN]
+            .try_into()
+            .unwrap()
+    }
+
+    /// The RPO-256 digest of two RPO-256 digests. There is no padding because the
+    /// input length is fixed.
+    pub fn merge(
+        left: [BFieldElement; DIGEST_LEN],
+        right: [BFieldElement; DIGEST_LEN],
+    ) -> [BFieldElement; DIGEST_LEN] {
+        let mut sponge = Self::default();
+        sponge.overwrite_rate_and_permute(&[left, right].concat());
+        sponge.state[CAPACITY..CAPACITY + DIGEST_LEN]
+            .try_into()
+            .unwrap()
+    }
+}
+
+impl AlgebraicHasher for Rpo {
+    fn hash_pair(left: Digest, right: Digest) -> Digest {
+        Self::hash_varlen(&[left.values(), right.values()].concat())
+    }
+
+    fn hash_varlen(input: &[BFieldElement]) -> Digest {
+        let sponge = Self::absorb_elements(input);
+        let digest_values = sponge.state[CAPACITY..CAPACITY + Digest::LEN]
+            .try_into()
+            .unwrap();
+        Digest::new(digest_values)
+    }
+}
+
+impl Sponge for Rpo {
+    const RATE: usize = algebraic_hasher::RATE;
+
+    fn init() -> Self {
+        Self::default()
+    }
+
+    fn absorb(&mut self, input: [BFieldElement; algebraic_hasher::RATE]) {
+        let (first, second) = input.split_at(RATE);
+        self.overwrite_rate_and_permute(first);
+        self.overwrite_rate_and_permute(second);
+    }
+
+    fn squeeze(&mut self) -> [BFieldElement; algebraic_hasher::RATE] {
+        let mut produce = [BFieldElement::ZERO; algebraic_hasher::RATE];
+        let (first, second) = produce.split_at_mut(RATE);
+        first.copy_from_slice(&self.state[CAPACITY..]);
+        self.permutation();
+        second.copy_from_slice(&self.state[CAPACITY..CAPACITY + second.len()]);
+        self.permutation();
+
+        produce
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use sha3::digest::ExtendableOutput;
+    use sha3::digest::Update;
+    use sha3::Shake256;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::prelude::Tip5;
+    use crate::util_types::merkle_tree::CpuParallel;
+    use crate::util_types::merkle_tree::MerkleTree;
+
+    #[test]
+    fn get_size_test() {
+        assert_eq!(
+            STATE_SIZE * BFieldElement::ZERO.get_size(),
+            Rpo::init().get_size()
+        );
+    }
+
+    #[test]
+    fn round_constants_are_correct() {
+        const BYTES_PER_CONSTANT: usize = 9;
+
+        let seed = format!("RPO({},{STATE_SIZE},{CAPACITY},128)", BFieldElement::P);
+        let mut shake = Shake256::default();
+        shake.update(seed.as_bytes());
+        let bytes = shake.finalize_boxed(BYTES_PER_CONSTANT * ROUND_CONSTANTS.len());
+
+        let round_constants = bytes
+            .chunks_exact(BYTES_PER_CONSTANT)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(0_u128, |acc, &byte| (acc << 8) + u128::from(byte))
+            })
+            .map(|integer| (integer % u128::from(BFieldElement::P)) as u64)
+            .map(BFieldElement::new)
+            .collect_vec();
+
+        assert_eq!(ROUND_CONSTANTS.to_vec(), round_constants);
+    }
+
+    #[test]
+    fn alpha_inverse_is_correct() {
+        let p_minus_one = u128::from(BFieldElement::P - 1);
+        let product = u128::from(ALPHA) * u128::from(ALPHA_INVERSE);
+        assert_eq!(1, product % p_minus_one);
+    }
+
+    #[proptest]
+    fn inverse_sbox_inverts_sbox(#[strategy(arb())] state: [BFieldElement; STATE_SIZE]) {
+        let mut sponge = Rpo { state };
+        sponge.sbox_layer();
+        prop_assert_eq!(state.map(|s| s.mod_pow(ALPHA)), sponge.state);
+
+        sponge.inverse_sbox_layer();
+        prop_assert_eq!(state, sponge.state);
+    }
+
+    #[proptest]
+    fn inverse_sbox_is_exponentiation_by_alpha_inverse(
+        #[strategy(arb())] state: [BFieldElement; STATE_SIZE],
+    ) {
+        let mut sponge = Rpo { state };
+        sponge.inverse_sbox_layer();
+        prop_assert_eq!(state.map(|s| s.mod_pow(ALPHA_INVERSE)), sponge.state);
+    }
+
+    #[test]
+    fn mds_layer_keeps_raw_representation_canonical() {
+        // before the final reduction, the result is larger than P
+        let raw = 0x0199_9999_9800_0001;
+        let mut sponge = Rpo {
+            state: [BFieldElement::from_raw_u64(raw); STATE_SIZE],
+        };
+        sponge.mds_layer();
+
+        let row_sum = MDS_MATRIX_FIRST_ROW.iter().sum::<u64>();
+        let expected = (u128::from(row_sum) * u128::from(raw)) % u128::from(BFieldElement::P);
+        for element in sponge.state {
+            assert_eq!(expected as u64, element.raw_u64());
+        }
+    }
+
+    #[proptest]
+    fn mds_layer_agrees_with_circulant_matrix(
+        #[strategy(arb())] state: [BFieldElement; STATE_SIZE],
+    ) {
+        let mut sponge = Rpo { state };
+        sponge.mds_layer();
+
+        for (i, &element) in sponge.state.iter().enumerate() {
+            let mut row = MDS_MATRIX_FIRST_ROW.map(BFieldElement::new);
+            row.rotate_right(i);
+            let expected = row
+                .iter()
+                .zip(&state)
+                .map(|(&m, &s)| m * s)
+                .sum::<BFieldElement>();
+            prop_assert_eq!(expected, element);
+        }
+    }
+
+    /// The test vectors of Miden's implementation of RPO-256: the digests of the
+    /// first 1, 2, …, 19 elements of the sequence 0, 1, 2, ….
+    #[test]
+    fn hash_elements_test_vectors() {
+        let expected_digests = [
+            [
+                1502364727743950833,
+                5880949717274681448,
+                162790463902224431,
+                6901340476773664264,
+            ],
+            [
+                7478710183745780580,
+                3308077307559720969,
+                3383561985796182409,
+                17205078494700259815,
+            ],
+            [
+                17439912364295172999,
+                17979156346142712171,
+                8280795511427637894,
+                9349844417834368814,
+            ],
+            [
+                5105868198472766874,
+                13090564195691924742,
+                1058904296915798891,
+                18379501748825152268,
+            ],
+            [
+                9133662113608941286,
+                12096627591905525991,
+                14963426595993304047,
+                13290205840019973377,
+            ],
+            [
+                3134262397541159485,
+                10106105871979362399,
+                138768814855329459,
+                15044809212457404677,
+            ],
+            [
+                162696376578462826,
+                4991300494838863586,
+                660346084748120605,
+                13179389528641752698,
+            ],
+            [
+                2242391899857912644,
+                12689382052053305418,
+                235236990017815546,
+                5046143039268215739,
+            ],
+            [
+                9585630502158073976,
+                1310051013427303477,
+                7491921222636097758,
+                9417501558995216762,
+            ],
+            [
+                1994394001720334744,
+                10866209900885216467,
+                13836092831163031683,
+                10814636682252756697,
+            ],
+            [
+                17486854790732826405,
+                17376549265955727562,
+                2371059831956435003,
+                17585704935858006533,
+            ],
+            [
+                11368277489137713825,
+                3906270146963049287,
+                10236262408213059745,
+                78552867005814007,
+            ],
+            [
+                17899847381280262181,
+                14717912805498651446,
+                10769146203951775298,
+                2774289833490417856,
+            ],
+            [
+                3794717687462954368,
+                4386865643074822822,
+                8854162840275334305,
+                7129983987107225269,
+            ],
+            [
+                7244773535611633983,
+                19359923075859320,
+                10898655967774994333,
+                9319339563065736480,
+            ],
+            [
+                4935426252518736883,
+                12584230452580950419,
+                8762518969632303998,
+                18159875708229758073,
+            ],
+            [
+                14871230873837295931,
+                11225255908868362971,
+                18100987641405432308,
+                1559244340089644233,
+            ],
+            [
+                8348203744950016968,
+                4041411241960726733,
+                17584743399305468057,
+                16836952610803537051,
+            ],
+            [
+                16139797453633030050,
+                1090233424040889412,
+                10770255347785669036,
+                16982398877290254028,
+            ],
+        ]
+        .map(|digest| digest.map(BFieldElement::new));
+
+        let elements = (0..19).map(BFieldElement::new).collect_vec();
+        for (i, expected_digest) in expected_digests.into_iter().enumerate() {
+            assert_eq!(expected_digest, Rpo::hash_elements(&elements[..=i]));
+        }
+    }
+
+    #[proptest]
+    fn merge_agrees_with_hashing_elements(
+        #[strategy(arb())] left: [BFieldElement; DIGEST_LEN],
+        #[strategy(arb())] right: [BFieldElement; DIGEST_LEN],
+    ) {
+        let elements = [left, right].concat();
+        prop_assert_eq!(Rpo::hash_elements(&elements), Rpo::merge(left, right));
+    }
+
+    #[proptest]
+    fn hash_varlen_extends_rpo_256_digest(#[strategy(arb())] preimage: Vec<BFieldElement>) {
+        let digest = Rpo::hash_varlen(&preimage);
+        let rpo_256_digest = Rpo::hash_elements(&preimage);
+        prop_assert_eq!(&rpo_256_digest, &digest.values()[..DIGEST_LEN]);
+    }
+
+    #[proptest]
+    fn hash_pair_agrees_with_hash_varlen(
+        #[strategy(arb())] left: Digest,
+        #[strategy(arb())] right: Digest,
+    ) {
+        let input = [left.values(), right.values()].concat();
+        prop_assert_eq!(Rpo::hash_varlen(&input), Rpo::hash_pair(left, right));
+    }
+
+    #[proptest]
+    fn absorbing_overwrites_rate_in_two_steps(
+        #[strategy(arb())] mut sponge: Rpo,
+        #[strategy(arb())] input: [BFieldElement; algebraic_hasher::RATE],
+    ) {
+        let mut expected_sponge = sponge.clone();
+        expected_sponge.state[CAPACITY..].copy_from_slice(&input[..RATE]);
+        expected_sponge.permutation();
+        expected_sponge.state[CAPACITY..CAPACITY + 2].copy_from_slice(&input[RATE..]);
+        expected_sponge.permutation();
+
+        sponge.absorb(input);
+        prop_assert_eq!(expected_sponge, sponge);
+    }
+
+    #[proptest]
+    fn squeezing_reads_rate_in_two_steps(#[strategy(arb())] mut sponge: Rpo) {
+        let mut expected_sponge = sponge.clone();
+        let mut expected_produce = expected_sponge.state[CAPACITY..].to_vec();
+        expected_sponge.permutation();
+        expected_produce.extend_from_slice(&expected_sponge.state[CAPACITY..CAPACITY + 2]);
+        expected_sponge.permutation();
+
+        let produce = sponge.squeeze();
+        prop_assert_eq!(expected_produce, produce.to_vec());
+        prop_assert_eq!(expected_sponge, sponge);
+    }
+
+    #[test]
+    fn rpo_and_tip5_disagree() {
+        let preimage = [BFieldElement::ZERO; algebraic_hasher::RATE];
+        assert_ne!(Tip5::hash_varlen(&preimage), Rpo::hash_varlen(&preimage));
+    }
+
+    #[proptest(cases = 10)]
+    fn merkle_tree_with_rpo_authenticates_leafs(
+        #[strategy(arb())]
+        #[filter(!#leafs.is_empty())]
+        leafs: Vec<Digest>,
+        #[strategy(0..#leafs.len())] leaf_index: usize,
+    ) {
+        let tree = MerkleTree::<Rpo>::new_padded_with_hasher::<CpuParallel>(&leafs)?;
+        let proof = tree.inclusion_proof_for_leaf_indices(&[leaf_index])?;
+        prop_assert!(proof.verify_padded(tree.root(), leafs.len()));
+    }
+}
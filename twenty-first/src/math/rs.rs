@@ -0,0 +1,368 @@
+//! [Reed–Solomon codes][rs] over coset domains, with error-correcting decoding.
+//!
+//! [rs]: https://en.wikipedia.org/wiki/Reed%E2%80%93Solomon_error_correction
+
+use std::ops::Mul;
+use std::result;
+
+use num_traits::One;
+use num_traits::Zero;
+use thiserror::Error;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+use crate::math::traits::ModPowU64;
+use crate::math::traits::PrimitiveRootOfUnity;
+
+type Result<T> = result::Result<T, ReedSolomonError>;
+
+/// A Reed–Solomon code of length `codeword_length` and dimension
+/// `message_length`.
+///
+/// A message of `message_length` many field elements is interpreted as the
+/// coefficients of a polynomial of degree less than `message_length`, and
+/// [encoded](Self::encode) as its evaluations on the coset domain
+/// `{offset · generator^i}` of length `codeword_length`. [Decoding](Self::decode)
+/// recovers the message polynomial as long as at most
+/// [`max_num_errors`](Self::max_num_errors) symbols of the codeword are
+/// corrupted, using [Gao's algorithm][gao].
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::math::rs::ReedSolomon;
+/// let rs = ReedSolomon::new(bfe!(7), 16, 4).unwrap();
+/// let message = xfe_vec![1, 2, 3, 4];
+/// let mut codeword = rs.encode(&message).unwrap();
+/// codeword[3] = xfe!(42);
+/// codeword[11] = xfe!(1337);
+///
+/// let decoded = rs.decode(&codeword).unwrap();
+/// assert_eq!(Polynomial::new(message), decoded);
+/// ```
+///
+/// [gao]: https://www.math.clemson.edu/~sgao/papers/RS.pdf
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReedSolomon {
+    offset: BFieldElement,
+    generator: BFieldElement,
+    codeword_length: usize,
+    message_length: usize,
+}
+
+impl ReedSolomon {
+    /// # Errors
+    ///
+    /// - If the `offset` is zero.
+    /// - If no domain of length `codeword_length` exists.
+    /// - If the `message_length` is zero or exceeds the `codeword_length`.
+    pub fn new(
+        offset: BFieldElement,
+        codeword_length: usize,
+        message_length: usize,
+    ) -> Result<Self> {
+        if offset.is_zero() {
+            return Err(ReedSolomonError::ZeroOffset);
+        }
+        let Some(generator) =
+            BFieldElement::primitive_root_of_unity_of_any_order(codeword_length as u64)
+        else {
+            return Err(ReedSolomonError::NoDomainOfLength(codeword_length));
+        };
+        if message_length == 0 {
+            return Err(ReedSolomonError::EmptyMessage);
+        }
+        if message_length > codeword_length {
+            return Err(ReedSolomonError::MessageLengthExceedsCodewordLength);
+        }
+
+        Ok(Self {
+            offset,
+            generator,
+            codeword_length,
+            message_length,
+        })
+    }
+
+    pub fn offset(&self) -> BFieldElement {
+        self.offset
+    }
+
+    pub fn generator(&self) -> BFieldElement {
+        self.generator
+    }
+
+    pub fn codeword_length(&self) -> usize {
+        self.codeword_length
+    }
+
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    /// The maximal number of corrupted symbols from which a codeword can be
+    /// [decoded](Self::decode), _i.e._, half the minimum distance of the code.
+    pub fn max_num_errors(&self) -> usize {
+        (self.codeword_length - self.message_length) / 2
+    }
+
+    /// Encode the message, interpreted as the coefficients of a polynomial,
+    /// into a codeword.
+    ///
+    /// # Errors
+    ///
+    /// If the message is longer than the [`message_length`](Self::message_length).
+    pub fn encode<FF>(&self, message: &[FF]) -> Result<Vec<FF>>
+    where
+        FF: FiniteField<BaseField = BFieldElement> + Mul<BFieldElement, Output = FF>,
+    {
+        if message.len() > self.message_length {
+            return Err(ReedSolomonError::MessageTooLong {
+                max: self.message_length,
+                actual: message.len(),
+            });
+        }
+
+        let polynomial = Polynomial::new(message.to_vec());
+        Ok(polynomial.fast_coset_evaluate(self.offset, self.generator, self.codeword_length))
+    }
+
+    /// Decode the codeword into the message polynomial, correcting up to
+    /// [`max_num_errors`](Self::max_num_errors) corrupted symbols.
+    ///
+    /// # Errors
+    ///
+    /// - If the length of the codeword does not match the
+    ///   [`codeword_length`](Self::codeword_length).
+    /// - If too many symbols of the codeword are corrupted.
+    pub fn decode<FF>(&self, codeword: &[FF]) -> Result<Polynomial<FF>>
+    where
+        FF: FiniteField<BaseField = BFieldElement> + Mul<BFieldElement, Output = FF>,
+    {
+        if codeword.len() != self.codeword_length {
+            return Err(ReedSolomonError::IncorrectCodewordLength {
+                expected: self.codeword_length,
+                actual: codeword.len(),
+            });
+        }
+
+        // The zerofier of the coset domain {offset · generator^i} of length n is
+        // x^n - offset^n.
+        let n = self.codeword_length;
+        let mut zerofier_coefficients = vec![FF::ZERO; n + 1];
+        zerofier_coefficients[0] = -FF::from_base_field(self.offset.mod_pow_u64(n as u64));
+        zerofier_coefficients[n] = FF::ONE;
+        let zerofier = Polynomial::new(zerofier_coefficients);
+        let interpolant = Polynomial::fast_coset_interpolate(self.offset, self.generator, codeword);
+
+        let degree_bound = (n + self.message_length).div_ceil(2) as isize;
+        let (remainder, bezout_coefficient) = partial_xgcd(zerofier, interpolant, degree_bound);
+
+        // The Bézout coefficient is the error locator polynomial, which divides
+        // the remainder if the number of errors is within the decoding radius.
+        let (message, error) = remainder.divide(&bezout_coefficient);
+        if !error.is_zero() || message.degree() >= self.message_length as isize {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        Ok(message)
+    }
+}
+
+/// The extended Euclidean algorithm on `x` and `y`, stopped as soon as the
+/// remainder's degree drops below the `degree_bound`. Returns that remainder
+/// `r` and the Bézout coefficient `b` of `y`, satisfying `r = a·x + b·y` for
+/// some `a`.
+fn partial_xgcd<FF: FiniteField>(
+    mut x: Polynomial<FF>,
+    mut y: Polynomial<FF>,
+    degree_bound: isize,
+) -> (Polynomial<FF>, Polynomial<FF>) {
+    let (mut b_factor, mut b1) = (Polynomial::zero(), Polynomial::one());
+
+    while y.degree() >= degree_bound {
+        let (quotient, remainder) = x.divide(&y);
+        let d = b_factor - quotient * b1.clone();
+
+        x = y;
+        y = remainder;
+        b_factor = b1;
+        b1 = d;
+    }
+
+    (y, b1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ReedSolomonError {
+    #[error("the domain offset must not be zero")]
+    ZeroOffset,
+
+    #[error("no domain of length {0} exists")]
+    NoDomainOfLength(usize),
+
+    #[error("the message length must not be zero")]
+    EmptyMessage,
+
+    #[error("the message length must not exceed the codeword length")]
+    MessageLengthExceedsCodewordLength,
+
+    #[error("expected a message of length at most {max}, but got {actual}")]
+    MessageTooLong { max: usize, actual: usize },
+
+    #[error("expected a codeword of length {expected}, but got {actual}")]
+    IncorrectCodewordLength { expected: usize, actual: usize },
+
+    #[error("too many symbols of the codeword are corrupted")]
+    TooManyErrors,
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest::sample::subsequence;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::x_field_element::XFieldElement;
+
+    #[derive(Debug, Clone, test_strategy::Arbitrary)]
+    struct ReedSolomonToTest {
+        #[strategy(1_u64..1 << 20)]
+        offset: u64,
+
+        #[strategy(prop_oneof![Just(16_usize), Just(30), Just(64)])]
+        codeword_length: usize,
+
+        #[strategy(1..=#codeword_length)]
+        message_length: usize,
+    }
+
+    impl ReedSolomonToTest {
+        fn reed_solomon(&self) -> ReedSolomon {
+            let offset = BFieldElement::new(self.offset);
+            ReedSolomon::new(offset, self.codeword_length, self.message_length).unwrap()
+        }
+
+        fn max_num_errors(&self) -> usize {
+            (self.codeword_length - self.message_length) / 2
+        }
+    }
+
+    fn corrupt<FF: FiniteField>(codeword: &mut [FF], error_indices: &[usize], errors: &[FF]) {
+        for (&index, &error) in error_indices.iter().zip(errors) {
+            codeword[index] += error;
+        }
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let seven = BFieldElement::new(7);
+        assert_eq!(
+            Err(ReedSolomonError::ZeroOffset),
+            ReedSolomon::new(BFieldElement::new(0), 16, 4)
+        );
+        assert_eq!(
+            Err(ReedSolomonError::NoDomainOfLength(7)),
+            ReedSolomon::new(seven, 7, 4)
+        );
+        assert_eq!(
+            Err(ReedSolomonError::EmptyMessage),
+            ReedSolomon::new(seven, 16, 0)
+        );
+        assert_eq!(
+            Err(ReedSolomonError::MessageLengthExceedsCodewordLength),
+            ReedSolomon::new(seven, 16, 17)
+        );
+    }
+
+    #[test]
+    fn inputs_of_incorrect_length_are_rejected() {
+        let rs = ReedSolomon::new(BFieldElement::new(7), 16, 4).unwrap();
+        let message = [BFieldElement::new(1); 5];
+        assert_eq!(
+            Err(ReedSolomonError::MessageTooLong { max: 4, actual: 5 }),
+            rs.encode(&message)
+        );
+
+        let codeword = [BFieldElement::new(1); 15];
+        assert_eq!(
+            Err(ReedSolomonError::IncorrectCodewordLength {
+                expected: 16,
+                actual: 15
+            }),
+            rs.decode(&codeword)
+        );
+    }
+
+    #[proptest]
+    fn codeword_is_evaluation_of_message_polynomial(
+        rs_to_test: ReedSolomonToTest,
+        #[strategy(vec(arb(), #rs_to_test.message_length))] message: Vec<XFieldElement>,
+    ) {
+        let rs = rs_to_test.reed_solomon();
+        let codeword = rs.encode(&message)?;
+
+        let polynomial = Polynomial::new(message);
+        let mut point = rs.offset();
+        for value in codeword {
+            prop_assert_eq!(polynomial.evaluate(point.into()), value);
+            point *= rs.generator();
+        }
+    }
+
+    #[proptest]
+    fn uncorrupted_codeword_decodes_to_message(
+        rs_to_test: ReedSolomonToTest,
+        #[strategy(vec(arb(), #rs_to_test.message_length))] message: Vec<BFieldElement>,
+    ) {
+        let rs = rs_to_test.reed_solomon();
+        let codeword = rs.encode(&message)?;
+        prop_assert_eq!(Polynomial::new(message), rs.decode(&codeword)?);
+    }
+
+    #[proptest]
+    fn codeword_with_few_errors_decodes_to_message(
+        rs_to_test: ReedSolomonToTest,
+        #[strategy(vec(arb(), #rs_to_test.message_length))] message: Vec<XFieldElement>,
+        #[strategy(subsequence(
+            (0..#rs_to_test.codeword_length).collect::<Vec<_>>(),
+            0..=#rs_to_test.max_num_errors(),
+        ))]
+        error_indices: Vec<usize>,
+        #[strategy(vec(arb(), #error_indices.len()))] errors: Vec<XFieldElement>,
+    ) {
+        let rs = rs_to_test.reed_solomon();
+        prop_assert_eq!(rs_to_test.max_num_errors(), rs.max_num_errors());
+
+        let mut codeword = rs.encode(&message)?;
+        corrupt(&mut codeword, &error_indices, &errors);
+        prop_assert_eq!(Polynomial::new(message), rs.decode(&codeword)?);
+    }
+
+    #[proptest]
+    fn codeword_with_too_many_errors_is_rejected(
+        #[filter(#rs_to_test.message_length < #rs_to_test.codeword_length)]
+        rs_to_test: ReedSolomonToTest,
+        #[strategy(vec(arb(), #rs_to_test.message_length))] message: Vec<XFieldElement>,
+        #[strategy(subsequence(
+            (0..#rs_to_test.codeword_length).collect::<Vec<_>>(),
+            #rs_to_test.max_num_errors() + 1..=#rs_to_test.codeword_length,
+        ))]
+        error_indices: Vec<usize>,
+        #[strategy(vec(arb(), #error_indices.len()))]
+        #[filter(#errors.iter().all(|e| !e.is_zero()))]
+        errors: Vec<XFieldElement>,
+    ) {
+        let rs = rs_to_test.reed_solomon();
+        let mut codeword = rs.encode(&message)?;
+        corrupt(&mut codeword, &error_indices, &errors);
+
+        // Random errors make the codeword far from all codewords with
+        // overwhelming probability.
+        prop_assert_eq!(Err(ReedSolomonError::TooManyErrors), rs.decode(&codeword));
+    }
+}
@@ -0,0 +1,307 @@
+//! Shared implementation of prime fields whose modulus fits into 31 bits, like
+//! [BabyBear](crate::math::baby_bear) and
+//! [Mersenne-31](crate::math::mersenne_31).
+
+/// Define a prime field element type `$name` with modulus `$modulus` and
+/// multiplicative group generator `$generator`. Elements are stored in
+/// canonical form as a `u32`.
+macro_rules! small_prime_field {
+    ($(#[$attribute:meta])* $name:ident, $modulus:expr, $generator:expr $(,)?) => {
+        $(#[$attribute])*
+        #[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+        pub struct $name(u32);
+
+        impl $name {
+            pub const P: u32 = $modulus;
+            pub const MAX: u32 = Self::P - 1;
+
+            /// Reduces the given value modulo [`P`](Self::P).
+            #[inline]
+            pub const fn new(value: u32) -> Self {
+                Self(value % Self::P)
+            }
+
+            /// The canonical representative in `0..P`.
+            #[inline]
+            pub const fn value(&self) -> u32 {
+                self.0
+            }
+
+            /// A generator of the field's multiplicative group.
+            pub const fn generator() -> Self {
+                Self::new($generator)
+            }
+
+            #[inline]
+            const fn reduce(value: u64) -> Self {
+                Self((value % Self::P as u64) as u32)
+            }
+
+            #[must_use]
+            pub const fn mod_pow(&self, exp: u64) -> Self {
+                let mut acc = 1;
+                let mut base = self.0 as u64;
+                let mut exp = exp;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc = acc * base % Self::P as u64;
+                    }
+                    base = base * base % Self::P as u64;
+                    exp >>= 1;
+                }
+                Self(acc as u32)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl<'a> ::arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                u.arbitrary().map(Self::new)
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                ::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                Ok(Self::new(<u32 as ::serde::Deserialize>::deserialize(deserializer)?))
+            }
+        }
+
+        impl ::rand::distributions::Distribution<$name> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name(rng.gen_range(0..=$name::MAX))
+            }
+        }
+
+        impl $crate::math::bfield_codec::BFieldCodec for $name {
+            type Error = $crate::math::bfield_codec::BFieldCodecError;
+
+            fn decode(
+                sequence: &[$crate::math::b_field_element::BFieldElement],
+            ) -> Result<Box<Self>, Self::Error> {
+                let [element] = sequence else {
+                    return Err(match sequence {
+                        [] => Self::Error::EmptySequence,
+                        _ => Self::Error::SequenceTooLong,
+                    });
+                };
+                let value = u32::try_from(element.value())
+                    .ok()
+                    .filter(|&v| v < Self::P)
+                    .ok_or(Self::Error::ElementOutOfRange)?;
+                Ok(Box::new(Self(value)))
+            }
+
+            fn encode(&self) -> Vec<$crate::math::b_field_element::BFieldElement> {
+                vec![$crate::math::b_field_element::BFieldElement::new(self.0.into())]
+            }
+
+            fn static_length() -> Option<usize> {
+                Some(1)
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self::reduce(value)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(element: $name) -> Self {
+                element.0
+            }
+        }
+
+        impl ::std::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(0), |acc, element| acc + element)
+            }
+        }
+
+        impl ::std::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self(1), |acc, element| acc * element)
+            }
+        }
+
+        impl ::std::ops::Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                // both operands are smaller than 2^31; the sum cannot overflow
+                let sum = self.0 + rhs.0;
+                Self(if sum >= Self::P { sum - Self::P } else { sum })
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                let (difference, underflow) = self.0.overflowing_sub(rhs.0);
+                Self(if underflow {
+                    difference.wrapping_add(Self::P)
+                } else {
+                    difference
+                })
+            }
+        }
+
+        impl ::std::ops::Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::reduce(u64::from(self.0) * u64::from(rhs.0))
+            }
+        }
+
+        impl ::std::ops::Div for $name {
+            type Output = Self;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * $crate::math::traits::Inverse::inverse(&rhs)
+            }
+        }
+
+        impl ::std::ops::Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(0) - self
+            }
+        }
+
+        impl ::std::ops::AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl ::std::ops::SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl ::std::ops::MulAssign for $name {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl ::num_traits::Zero for $name {
+            fn zero() -> Self {
+                Self(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl ::num_traits::ConstZero for $name {
+            const ZERO: Self = Self(0);
+        }
+
+        impl ::num_traits::One for $name {
+            fn one() -> Self {
+                Self(1)
+            }
+
+            fn is_one(&self) -> bool {
+                self.0 == 1
+            }
+        }
+
+        impl ::num_traits::ConstOne for $name {
+            const ONE: Self = Self(1);
+        }
+
+        impl $crate::math::traits::Inverse for $name {
+            fn inverse(&self) -> Self {
+                assert_ne!(
+                    0, self.0,
+                    "Attempted to find the multiplicative inverse of zero."
+                );
+                self.mod_pow(u64::from(Self::P) - 2)
+            }
+        }
+
+        impl $crate::math::traits::ModPowU32 for $name {
+            fn mod_pow_u32(&self, exp: u32) -> Self {
+                self.mod_pow(exp.into())
+            }
+        }
+
+        impl $crate::math::traits::ModPowU64 for $name {
+            fn mod_pow_u64(&self, pow: u64) -> Self {
+                self.mod_pow(pow)
+            }
+        }
+
+        impl $crate::math::traits::CyclicGroupGenerator for $name {
+            fn get_cyclic_group_elements(&self, max: Option<usize>) -> Vec<Self> {
+                let mut val = *self;
+                let mut ret = vec![Self(1)];
+
+                loop {
+                    ret.push(val);
+                    val *= *self;
+                    if val.0 == 1 || max.is_some_and(|max_len| ret.len() >= max_len) {
+                        break;
+                    }
+                }
+                ret
+            }
+        }
+
+        impl $crate::math::traits::PrimitiveRootOfUnity for $name {
+            /// The canonical primitive `n`th root of unity, which is
+            /// [`generator`](Self::generator)^((P-1)/n), if `n` divides P-1.
+            fn primitive_root_of_unity(n: u64) -> Option<Self> {
+                let group_order = u64::from(Self::P) - 1;
+                if n == 0 || group_order % n != 0 {
+                    return None;
+                }
+                Some(Self::generator().mod_pow(group_order / n))
+            }
+        }
+
+        impl $crate::math::traits::FiniteField for $name {
+            type BaseField = Self;
+        }
+    };
+}
+
+pub(crate) use small_prime_field;
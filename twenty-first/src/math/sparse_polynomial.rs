@@ -0,0 +1,613 @@
+//! Univariate polynomials with few non-zero terms.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::Sub;
+
+use num_traits::One;
+use num_traits::Zero;
+use thiserror::Error;
+
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::FiniteField;
+
+/// The largest exponent a term of a [`SparsePolynomial`] can have. Guarantees
+/// that the [degree](SparsePolynomial::degree) fits in an [`isize`].
+pub const MAX_EXPONENT: u64 = isize::MAX as u64;
+
+/// A univariate polynomial with coefficients in a [finite field](FiniteField),
+/// represented by its non-zero terms.
+///
+/// In contrast to the dense [`Polynomial`], the memory footprint depends only
+/// on the number of terms, not on the degree. This makes it suitable for
+/// polynomials like x^(2^32) - 1, the zerofier of the subgroup of order 2^32.
+///
+/// ```
+/// # use twenty_first::math::sparse_polynomial::SparsePolynomial;
+/// # use twenty_first::math::traits::PrimitiveRootOfUnity;
+/// # use twenty_first::prelude::*;
+/// let zerofier = SparsePolynomial::new(vec![(1 << 32, bfe!(1)), (0, bfe!(-1))]);
+/// let root = BFieldElement::primitive_root_of_unity(1 << 32).unwrap();
+/// assert_eq!(bfe!(0), zerofier.evaluate(root));
+/// assert_eq!(1 << 32, zerofier.degree());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparsePolynomial<FF: FiniteField> {
+    /// The non-zero terms as pairs of exponent and coefficient, in order of
+    /// strictly increasing exponent. No exponent exceeds [`MAX_EXPONENT`].
+    terms: Vec<(u64, FF)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum SparsePolynomialError {
+    #[error("exponent {0} exceeds the maximum exponent {MAX_EXPONENT}")]
+    ExponentTooLarge(u128),
+}
+
+impl<FF: FiniteField> SparsePolynomial<FF> {
+    /// The sum of the given terms, each a pair of exponent and coefficient.
+    /// Terms may be given in any order, and may share an exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any exponent exceeds [`MAX_EXPONENT`]. See
+    /// [`try_new`](Self::try_new) for a non-panicking alternative.
+    pub fn new(terms: Vec<(u64, FF)>) -> Self {
+        Self::try_new(terms).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The sum of the given terms, like [`new`](Self::new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any exponent exceeds [`MAX_EXPONENT`].
+    pub fn try_new(terms: Vec<(u64, FF)>) -> Result<Self, SparsePolynomialError> {
+        let mut combined_terms = BTreeMap::new();
+        for (exponent, coefficient) in terms {
+            if exponent > MAX_EXPONENT {
+                return Err(SparsePolynomialError::ExponentTooLarge(exponent.into()));
+            }
+            *combined_terms.entry(exponent).or_insert(FF::ZERO) += coefficient;
+        }
+
+        combined_terms.retain(|_, coefficient| !coefficient.is_zero());
+        Ok(Self {
+            terms: combined_terms.into_iter().collect(),
+        })
+    }
+
+    /// The polynomial `coefficient`·x^`exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent exceeds [`MAX_EXPONENT`].
+    pub fn monomial(exponent: u64, coefficient: FF) -> Self {
+        Self::new(vec![(exponent, coefficient)])
+    }
+
+    /// The non-zero terms as pairs of exponent and coefficient, in order of
+    /// strictly increasing exponent.
+    pub fn terms(&self) -> &[(u64, FF)] {
+        &self.terms
+    }
+
+    /// The degree of the polynomial, or -1 for the zero polynomial.
+    pub fn degree(&self) -> isize {
+        // exponents never exceed `MAX_EXPONENT`, which is `isize::MAX`
+        self.terms
+            .last()
+            .map_or(-1, |&(exponent, _)| exponent as isize)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<FF> {
+        self.terms.last().map(|&(_, coefficient)| coefficient)
+    }
+
+    /// Evaluate the polynomial in the given point. The time complexity depends
+    /// on the number of terms and, logarithmically, on the degree.
+    pub fn evaluate(&self, x: FF) -> FF {
+        let mut acc = FF::ZERO;
+        let mut previous_exponent = 0;
+        let mut x_to_the_exponent = FF::ONE;
+        for &(exponent, coefficient) in &self.terms {
+            x_to_the_exponent *= x.mod_pow_u64(exponent - previous_exponent);
+            acc += coefficient * x_to_the_exponent;
+            previous_exponent = exponent;
+        }
+        acc
+    }
+
+    /// Multiply the polynomial by the given scalar.
+    #[must_use]
+    pub fn scalar_mul(&self, scalar: FF) -> Self {
+        let terms = self.terms.iter().map(|&(e, c)| (e, c * scalar)).collect();
+        Self::new(terms)
+    }
+
+    /// The product of `self` and `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the degree of the product exceeds [`MAX_EXPONENT`].
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, SparsePolynomialError> {
+        let mut terms = Vec::with_capacity(self.terms.len() * other.terms.len());
+        for &(self_exponent, self_coefficient) in &self.terms {
+            for &(other_exponent, other_coefficient) in &other.terms {
+                let exponent = u128::from(self_exponent) + u128::from(other_exponent);
+                let Some(exponent) = u64::try_from(exponent)
+                    .ok()
+                    .filter(|&exponent| exponent <= MAX_EXPONENT)
+                else {
+                    return Err(SparsePolynomialError::ExponentTooLarge(exponent));
+                };
+                terms.push((exponent, self_coefficient * other_coefficient));
+            }
+        }
+        Self::try_new(terms)
+    }
+
+    pub fn formal_derivative(&self) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .filter(|&&(exponent, _)| exponent > 0)
+            .map(|&(exponent, coefficient)| (exponent - 1, coefficient * FF::from(exponent)))
+            .collect();
+        Self::new(terms)
+    }
+}
+
+impl<FF: FiniteField> Polynomial<FF> {
+    /// Divide `self` by a [sparse](SparsePolynomial) `divisor`, returning the
+    /// quotient and the remainder.
+    ///
+    /// The time complexity is in O(n·t), where n is the degree of `self` and t
+    /// the number of terms of the divisor. If the divisor's degree exceeds that
+    /// of `self`, no memory is allocated for the divisor's coefficients.
+    ///
+    /// ```
+    /// # use twenty_first::math::sparse_polynomial::SparsePolynomial;
+    /// # use twenty_first::prelude::*;
+    /// let x_to_the_4_minus_1 = SparsePolynomial::new(vec![(4, bfe!(1)), (0, bfe!(-1))]);
+    /// let dividend = Polynomial::new(bfe_vec![1, 2, 3, 4, 5, 6]);
+    /// let (quotient, remainder) = dividend.divide_by_sparse(&x_to_the_4_minus_1);
+    /// assert_eq!(Polynomial::new(bfe_vec![5, 6]), quotient);
+    /// assert_eq!(Polynomial::new(bfe_vec![6, 8, 3, 4]), remainder);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `divisor` is zero.
+    pub fn divide_by_sparse(&self, divisor: &SparsePolynomial<FF>) -> (Self, Self) {
+        let Some(leading_coefficient) = divisor.leading_coefficient() else {
+            panic!("Cannot divide polynomial by zero.");
+        };
+
+        let dividend_degree = self.degree();
+        let divisor_degree = divisor.degree();
+        if dividend_degree < divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+
+        let dividend_degree = dividend_degree as usize;
+        let divisor_degree = divisor_degree as usize;
+        let leading_coefficient_inverse = leading_coefficient.inverse();
+        let (lower_terms, _) = divisor.terms.split_at(divisor.terms.len() - 1);
+
+        let mut remainder = self.coefficients[..=dividend_degree].to_vec();
+        let mut quotient = vec![FF::ZERO; dividend_degree - divisor_degree + 1];
+        for i in (0..quotient.len()).rev() {
+            let quotient_coefficient = remainder[i + divisor_degree] * leading_coefficient_inverse;
+            remainder[i + divisor_degree] = FF::ZERO;
+            if quotient_coefficient.is_zero() {
+                continue;
+            }
+            for &(exponent, coefficient) in lower_terms {
+                remainder[i + exponent as usize] -= quotient_coefficient * coefficient;
+            }
+            quotient[i] = quotient_coefficient;
+        }
+        remainder.truncate(divisor_degree);
+
+        (Self::new(quotient), Self::new(remainder))
+    }
+}
+
+impl<FF: FiniteField> From<Polynomial<FF>> for SparsePolynomial<FF> {
+    fn from(polynomial: Polynomial<FF>) -> Self {
+        let terms = (0..).zip(polynomial.coefficients).collect();
+        Self::new(terms)
+    }
+}
+
+/// Allocates memory for all coefficients up to the degree of the sparse
+/// polynomial.
+impl<FF: FiniteField> From<SparsePolynomial<FF>> for Polynomial<FF> {
+    fn from(sparse: SparsePolynomial<FF>) -> Self {
+        let num_coefficients = usize::try_from(sparse.degree() + 1).unwrap();
+        let mut coefficients = vec![FF::ZERO; num_coefficients];
+        for (exponent, coefficient) in sparse.terms {
+            coefficients[exponent as usize] = coefficient;
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<FF: FiniteField> Display for SparsePolynomial<FF> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, &(exponent, coefficient)) in self.terms.iter().rev().enumerate() {
+            if i != 0 {
+                write!(f, " + ")?;
+            }
+            if !coefficient.is_one() || exponent == 0 {
+                write!(f, "{coefficient}")?;
+            }
+            match exponent {
+                0 => (),
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{exponent}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<FF: FiniteField> Zero for SparsePolynomial<FF> {
+    fn zero() -> Self {
+        Self { terms: vec![] }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<FF: FiniteField> One for SparsePolynomial<FF> {
+    fn one() -> Self {
+        Self::monomial(0, FF::ONE)
+    }
+
+    fn is_one(&self) -> bool {
+        self.terms == [(0, FF::ONE)]
+    }
+}
+
+impl<FF: FiniteField> Add for SparsePolynomial<FF> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut terms = self.terms;
+        terms.extend(other.terms);
+        Self::new(terms)
+    }
+}
+
+impl<FF: FiniteField> Sub for SparsePolynomial<FF> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<FF: FiniteField> Neg for SparsePolynomial<FF> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let terms = self.terms.into_iter().map(|(e, c)| (e, -c)).collect();
+        Self { terms }
+    }
+}
+
+impl<FF: FiniteField> Mul for SparsePolynomial<FF> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the degree of the product exceeds [`MAX_EXPONENT`]. See
+    /// [`checked_mul`](SparsePolynomial::checked_mul) for a non-panicking
+    /// alternative.
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(&other)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl<FF: FiniteField> Add<Polynomial<FF>> for SparsePolynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn add(self, dense: Polynomial<FF>) -> Polynomial<FF> {
+        dense + self
+    }
+}
+
+impl<FF: FiniteField> Add<SparsePolynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn add(mut self, sparse: SparsePolynomial<FF>) -> Self {
+        let degree = usize::try_from(sparse.degree()).unwrap_or(0);
+        if self.coefficients.len() <= degree {
+            self.coefficients.resize(degree + 1, FF::ZERO);
+        }
+        for (exponent, coefficient) in sparse.terms {
+            self.coefficients[exponent as usize] += coefficient;
+        }
+        self
+    }
+}
+
+impl<FF: FiniteField> Sub<Polynomial<FF>> for SparsePolynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn sub(self, dense: Polynomial<FF>) -> Polynomial<FF> {
+        -dense + self
+    }
+}
+
+impl<FF: FiniteField> Sub<SparsePolynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn sub(self, sparse: SparsePolynomial<FF>) -> Self {
+        self + -sparse
+    }
+}
+
+impl<FF: FiniteField> Mul<Polynomial<FF>> for SparsePolynomial<FF> {
+    type Output = Polynomial<FF>;
+
+    fn mul(self, dense: Polynomial<FF>) -> Polynomial<FF> {
+        dense * self
+    }
+}
+
+impl<FF: FiniteField> Mul<SparsePolynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    /// The time complexity is in O(n·t), where n is the degree of the dense
+    /// polynomial and t the number of terms of the sparse polynomial.
+    fn mul(self, sparse: SparsePolynomial<FF>) -> Self {
+        let (Ok(dense_degree), Ok(sparse_degree)) = (
+            usize::try_from(self.degree()),
+            usize::try_from(sparse.degree()),
+        ) else {
+            return Self::zero();
+        };
+
+        let mut product = vec![FF::ZERO; dense_degree + sparse_degree + 1];
+        for (exponent, sparse_coefficient) in sparse.terms {
+            let shifted_product = &mut product[exponent as usize..];
+            let dense_coefficients = &self.coefficients[..=dense_degree];
+            for (p, &dense_coefficient) in shifted_product.iter_mut().zip(dense_coefficients) {
+                *p += dense_coefficient * sparse_coefficient;
+            }
+        }
+        Self::new(product)
+    }
+}
+
+impl<FF: FiniteField> Div<SparsePolynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn div(self, divisor: SparsePolynomial<FF>) -> Self {
+        let (quotient, _) = self.divide_by_sparse(&divisor);
+        quotient
+    }
+}
+
+impl<FF: FiniteField> Rem<SparsePolynomial<FF>> for Polynomial<FF> {
+    type Output = Self;
+
+    fn rem(self, divisor: SparsePolynomial<FF>) -> Self {
+        let (_, remainder) = self.divide_by_sparse(&divisor);
+        remainder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::bfe;
+    use crate::bfe_vec;
+    use crate::math::b_field_element::BFieldElement;
+    use crate::math::traits::PrimitiveRootOfUnity;
+    use crate::math::x_field_element::XFieldElement;
+
+    fn sparse_polynomial<FF>(
+        max_exponent: u64,
+        max_num_terms: usize,
+    ) -> impl Strategy<Value = SparsePolynomial<FF>>
+    where
+        FF: FiniteField + for<'a> arbitrary::Arbitrary<'a>,
+    {
+        vec((0..=max_exponent, arb()), 0..=max_num_terms).prop_map(SparsePolynomial::new)
+    }
+
+    #[test]
+    fn terms_are_combined_sorted_and_without_zeros() {
+        let polynomial = SparsePolynomial::new(vec![
+            (5, bfe!(3)),
+            (2, bfe!(1)),
+            (5, bfe!(-3)),
+            (7, bfe!(0)),
+            (2, bfe!(1)),
+            (0, bfe!(4)),
+        ]);
+        assert_eq!(&[(0, bfe!(4)), (2, bfe!(2))], polynomial.terms());
+        assert_eq!(2, polynomial.degree());
+        assert_eq!(Some(bfe!(2)), polynomial.leading_coefficient());
+        assert_eq!("2x^2 + 4", polynomial.to_string());
+        assert_eq!(-1, SparsePolynomial::<BFieldElement>::zero().degree());
+    }
+
+    #[test]
+    fn exponents_up_to_the_maximum_are_accepted() {
+        let polynomial = SparsePolynomial::monomial(MAX_EXPONENT, bfe!(1));
+        assert_eq!(isize::MAX, polynomial.degree());
+    }
+
+    #[test]
+    fn too_large_exponents_are_rejected() {
+        let err = SparsePolynomial::try_new(vec![(1 << 63, bfe!(1))]).unwrap_err();
+        assert_eq!(SparsePolynomialError::ExponentTooLarge(1 << 63), err);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the maximum exponent")]
+    fn constructing_polynomial_with_too_large_exponent_panics() {
+        SparsePolynomial::new(vec![(u64::MAX, bfe!(1))]);
+    }
+
+    #[test]
+    fn product_of_too_large_degree_is_rejected() {
+        let polynomial = SparsePolynomial::new(vec![(MAX_EXPONENT, bfe!(1)), (0, bfe!(1))]);
+        let square_degree = 2 * u128::from(MAX_EXPONENT);
+        assert_eq!(
+            SparsePolynomialError::ExponentTooLarge(square_degree),
+            polynomial.checked_mul(&polynomial).unwrap_err()
+        );
+
+        let x = SparsePolynomial::monomial(1, bfe!(1));
+        let shifted_degree = u128::from(MAX_EXPONENT) + 1;
+        assert_eq!(
+            SparsePolynomialError::ExponentTooLarge(shifted_degree),
+            polynomial.checked_mul(&x).unwrap_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the maximum exponent")]
+    fn multiplying_polynomials_of_too_large_degree_panics() {
+        let polynomial = SparsePolynomial::monomial(MAX_EXPONENT, bfe!(1));
+        let _ = polynomial.clone() * polynomial;
+    }
+
+    #[proptest]
+    fn conversion_round_trips(
+        #[strategy(sparse_polynomial(100, 10))] sparse: SparsePolynomial<XFieldElement>,
+    ) {
+        let dense = Polynomial::from(sparse.clone());
+        prop_assert_eq!(sparse.to_string(), dense.to_string());
+        prop_assert_eq!(sparse, SparsePolynomial::from(dense));
+    }
+
+    #[proptest]
+    fn evaluation_agrees_with_dense_evaluation(
+        #[strategy(sparse_polynomial(1000, 10))] sparse: SparsePolynomial<BFieldElement>,
+        #[strategy(arb())] point: BFieldElement,
+    ) {
+        let dense = Polynomial::from(sparse.clone());
+        prop_assert_eq!(dense.evaluate(point), sparse.evaluate(point));
+    }
+
+    #[test]
+    fn evaluating_subgroup_zerofier_of_huge_degree_is_fast() {
+        let n = 1 << 32;
+        let zerofier = SparsePolynomial::new(vec![(n, bfe!(1)), (0, bfe!(-1))]);
+        let root = BFieldElement::primitive_root_of_unity(n).unwrap();
+        assert!(zerofier.evaluate(root).is_zero());
+        assert!(zerofier.evaluate(root.mod_pow(42)).is_zero());
+        assert!(!zerofier.evaluate(bfe!(7)).is_zero());
+    }
+
+    #[proptest]
+    fn sparse_arithmetic_agrees_with_dense_arithmetic(
+        #[strategy(sparse_polynomial(50, 8))] a: SparsePolynomial<BFieldElement>,
+        #[strategy(sparse_polynomial(50, 8))] b: SparsePolynomial<BFieldElement>,
+        #[strategy(arb())] scalar: BFieldElement,
+    ) {
+        let dense_a = Polynomial::from(a.clone());
+        let dense_b = Polynomial::from(b.clone());
+
+        let sum = dense_a.clone() + dense_b.clone();
+        let difference = dense_a.clone() - dense_b.clone();
+        let product = dense_a.clone() * dense_b.clone();
+        prop_assert_eq!(sum, (a.clone() + b.clone()).into());
+        prop_assert_eq!(difference, (a.clone() - b.clone()).into());
+        prop_assert_eq!(product, (a.clone() * b.clone()).into());
+        prop_assert_eq!(-dense_a.clone(), (-a.clone()).into());
+        prop_assert_eq!(dense_a.scalar_mul(scalar), a.scalar_mul(scalar).into());
+        prop_assert_eq!(dense_a.formal_derivative(), a.formal_derivative().into());
+    }
+
+    #[proptest]
+    fn mixed_arithmetic_agrees_with_dense_arithmetic(
+        #[strategy(arb())] dense: Polynomial<XFieldElement>,
+        #[strategy(sparse_polynomial(50, 8))] sparse: SparsePolynomial<XFieldElement>,
+    ) {
+        let sparse_as_dense = Polynomial::from(sparse.clone());
+
+        let sum = dense.clone() + sparse_as_dense.clone();
+        prop_assert_eq!(sum.clone(), dense.clone() + sparse.clone());
+        prop_assert_eq!(sum, sparse.clone() + dense.clone());
+
+        let difference = dense.clone() - sparse_as_dense.clone();
+        prop_assert_eq!(difference.clone(), dense.clone() - sparse.clone());
+        prop_assert_eq!(-difference, sparse.clone() - dense.clone());
+
+        let product = dense.clone() * sparse_as_dense;
+        prop_assert_eq!(product.clone(), dense.clone() * sparse.clone());
+        prop_assert_eq!(product, sparse * dense);
+    }
+
+    #[proptest]
+    fn division_by_sparse_polynomial_agrees_with_dense_division(
+        #[strategy(arb())] dividend: Polynomial<BFieldElement>,
+        #[strategy(sparse_polynomial(50, 5))]
+        #[filter(!#divisor.is_zero())]
+        divisor: SparsePolynomial<BFieldElement>,
+    ) {
+        let dense_divisor = Polynomial::from(divisor.clone());
+        let (quotient, remainder) = dividend.divide_by_sparse(&divisor);
+        prop_assert_eq!(
+            dividend.divide(&dense_divisor),
+            (quotient.clone(), remainder.clone())
+        );
+        prop_assert_eq!(quotient, dividend.clone() / divisor.clone());
+        prop_assert_eq!(remainder, dividend % divisor);
+    }
+
+    #[test]
+    fn division_by_sparse_polynomial_of_huge_degree_does_not_allocate_divisor() {
+        let divisor = SparsePolynomial::new(vec![(1 << 40, bfe!(1)), (0, bfe!(-1))]);
+        let dividend = Polynomial::new(bfe_vec![1, 2, 3]);
+        let (quotient, remainder) = dividend.divide_by_sparse(&divisor);
+        assert!(quotient.is_zero());
+        assert_eq!(dividend, remainder);
+    }
+
+    #[test]
+    fn dividing_subgroup_zerofier_by_sparse_zerofier_is_clean() {
+        let n = 64;
+        let root = BFieldElement::primitive_root_of_unity(n).unwrap();
+        let subgroup = (0..n).map(|i| root.mod_pow(i)).collect::<Vec<_>>();
+        let zerofier = Polynomial::zerofier(&subgroup);
+
+        let sparse_zerofier = SparsePolynomial::new(vec![(n, bfe!(1)), (0, bfe!(-1))]);
+        let (quotient, remainder) = zerofier.divide_by_sparse(&sparse_zerofier);
+        assert!(quotient.is_one());
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide polynomial by zero.")]
+    fn dividing_by_zero_panics() {
+        let _ = Polynomial::new(bfe_vec![1, 2]).divide_by_sparse(&SparsePolynomial::zero());
+    }
+}
@@ -10,6 +10,10 @@ use serde::Serialize;
 use crate::math::b_field_element::BFieldElement;
 pub use crate::math::digest::Digest;
 use crate::math::mds::generated_function;
+use crate::math::mds::lane_wise_generated_function;
+use crate::math::packed_b_field_element;
+use crate::math::packed_b_field_element::PackedBFieldElement;
+use crate::math::packed_b_field_element::WIDTH;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
 use crate::util_types::algebraic_hasher::Domain;
 use crate::util_types::algebraic_hasher::Sponge;
@@ -501,16 +505,21 @@ impl Tip5 {
         hi = generated_function(&hi);
 
         for r in 0..STATE_SIZE {
-            let s = (lo[r] >> 4) as u128 + ((hi[r] as u128) << 28);
+            self.state[r] = Self::mds_reduce(lo[r], hi[r]);
+        }
+    }
 
-            let s_hi = (s >> 64) as u64;
-            let s_lo = s as u64;
+    /// Combine the results of applying the MDS matrix to the low and the high
+    /// 32 bits of the state elements into one state element.
+    #[inline(always)]
+    fn mds_reduce(lo: u64, hi: u64) -> BFieldElement {
+        // s = (lo >> 4) + (hi << 28) has at most 93 bits
+        let (s_lo, carry) = (lo >> 4).overflowing_add(hi << 28);
+        let s_hi = (hi >> 36) + carry as u64;
 
-            let (res, over) = s_lo.overflowing_add(s_hi * 0xffffffffu64);
+        let (res, over) = s_lo.overflowing_add(s_hi * 0xffffffffu64);
 
-            self.state[r] =
-                BFieldElement::from_raw_u64(if over { res + 0xffffffffu64 } else { res });
-        }
+        BFieldElement::from_raw_u64(if over { res + 0xffffffffu64 } else { res })
     }
 
     #[inline(always)]
@@ -558,6 +567,89 @@ impl Tip5 {
         trace
     }
 
+    /// Apply the [permutation](Self::permutation) to every state.
+    ///
+    /// The states are processed in groups of [`WIDTH`]. Within a group, the
+    /// `i`th elements of all states are held side by side, such that the power
+    /// map of the S-box layer runs on [`PackedBFieldElement`]s and the MDS
+    /// matrix as well as the round constants are applied lane-wise. Remaining
+    /// states are permuted one by one. The result is identical to that of
+    /// [`permutation`](Self::permutation).
+    pub fn batch_permutation(states: &mut [[BFieldElement; STATE_SIZE]]) {
+        let mut groups = states.chunks_exact_mut(WIDTH);
+        // inline the closure to compile it for the instruction set in use
+        packed_b_field_element::with_backend(
+            #[inline(always)]
+            || {
+                for group in &mut groups {
+                    Self::packed_permutation(group.try_into().unwrap());
+                }
+            },
+        );
+
+        for state in groups.into_remainder() {
+            let mut sponge = Self { state: *state };
+            sponge.permutation();
+            *state = sponge.state;
+        }
+    }
+
+    #[inline(always)]
+    fn packed_permutation(states: &mut [[BFieldElement; STATE_SIZE]; WIDTH]) {
+        let mut packed_state: [PackedBFieldElement; STATE_SIZE] = std::array::from_fn(|i| {
+            PackedBFieldElement::new(std::array::from_fn(|lane| states[lane][i]))
+        });
+
+        for round_index in 0..NUM_ROUNDS {
+            Self::packed_round(&mut packed_state, round_index);
+        }
+
+        for (i, packed_element) in packed_state.into_iter().enumerate() {
+            for (state, element) in states.iter_mut().zip(packed_element.to_array()) {
+                state[i] = element;
+            }
+        }
+    }
+
+    /// Like [`round`](Self::round), but on [`WIDTH`] states at once. Every
+    /// step is performed exactly like its scalar counterpart, down to the
+    /// Montgomery representation of the intermediate results.
+    #[inline(always)]
+    fn packed_round(state: &mut [PackedBFieldElement; STATE_SIZE], round_index: usize) {
+        for packed_element in &mut state[..NUM_SPLIT_AND_LOOKUP] {
+            let mut lanes = packed_element.to_array();
+            lanes.iter_mut().for_each(Self::split_and_lookup);
+            *packed_element = PackedBFieldElement::new(lanes);
+        }
+
+        for packed_element in &mut state[NUM_SPLIT_AND_LOOKUP..] {
+            let sq = *packed_element * *packed_element;
+            let qu = sq * sq;
+            *packed_element *= sq * qu;
+        }
+
+        let mut lo = [[0; WIDTH]; STATE_SIZE];
+        let mut hi = [[0; WIDTH]; STATE_SIZE];
+        for i in 0..STATE_SIZE {
+            for (lane, element) in state[i].to_array().into_iter().enumerate() {
+                let b = element.raw_u64();
+                hi[i][lane] = b >> 32;
+                lo[i][lane] = b & 0xffffffffu64;
+            }
+        }
+
+        lo = lane_wise_generated_function(&lo);
+        hi = lane_wise_generated_function(&hi);
+
+        for i in 0..STATE_SIZE {
+            let round_constant = ROUND_CONSTANTS[round_index * STATE_SIZE + i];
+            let lanes = std::array::from_fn(|lane| {
+                Self::mds_reduce(lo[i][lane], hi[i][lane]) + round_constant
+            });
+            state[i] = PackedBFieldElement::new(lanes);
+        }
+    }
+
     /// hash_10
     /// Hash 10 elements, or two digests. There is no padding because
     /// the input length is fixed.
@@ -585,6 +677,69 @@ impl AlgebraicHasher for Tip5 {
         let digest_values = sponge.state[..Digest::LEN].try_into().unwrap();
         Digest::new(digest_values)
     }
+
+    /// Like [`hash_pair`](Self::hash_pair) for every pair, but uses the
+    /// [batch permutation](Tip5::batch_permutation).
+    fn batch_hash_pairs(pairs: &[(Digest, Digest)]) -> Vec<Digest> {
+        let mut states = pairs
+            .iter()
+            .map(|(left, right)| {
+                let mut state = Self::new(Domain::FixedLength).state;
+                state[..Digest::LEN].copy_from_slice(&left.values());
+                state[Digest::LEN..2 * Digest::LEN].copy_from_slice(&right.values());
+                state
+            })
+            .collect_vec();
+
+        Self::batch_permutation(&mut states);
+
+        states
+            .iter()
+            .map(|state| Digest::new(state[..Digest::LEN].try_into().unwrap()))
+            .collect()
+    }
+
+    /// Like [`hash_varlen`](Self::hash_varlen) for every input, but uses the
+    /// [batch permutation](Tip5::batch_permutation). In each absorption step,
+    /// all inputs that still have elements left are permuted together.
+    fn batch_hash_varlen<T: AsRef<[BFieldElement]>>(inputs: &[T]) -> Vec<Digest> {
+        // the padding of [1, 0, 0, …] is at least one element
+        let num_chunks = |input: &T| input.as_ref().len() / RATE + 1;
+        let max_num_chunks = inputs.iter().map(num_chunks).max().unwrap_or(0);
+
+        let mut states = vec![Self::init().state; inputs.len()];
+        for chunk_index in 0..max_num_chunks {
+            let active_indices = (0..inputs.len())
+                .filter(|&i| chunk_index < num_chunks(&inputs[i]))
+                .collect_vec();
+            let mut active_states = active_indices
+                .iter()
+                .map(|&i| {
+                    let mut state = states[i];
+                    let chunk = &inputs[i].as_ref()[chunk_index * RATE..];
+                    if let Some(full_chunk) = chunk.get(..RATE) {
+                        state[..RATE].copy_from_slice(full_chunk);
+                    } else {
+                        state[..RATE].fill(BFieldElement::ZERO);
+                        state[..chunk.len()].copy_from_slice(chunk);
+                        state[chunk.len()] = BFieldElement::ONE;
+                    }
+                    state
+                })
+                .collect_vec();
+
+            Self::batch_permutation(&mut active_states);
+
+            for (&i, state) in active_indices.iter().zip(active_states) {
+                states[i] = state;
+            }
+        }
+
+        states
+            .iter()
+            .map(|state| Digest::new(state[..Digest::LEN].try_into().unwrap()))
+            .collect()
+    }
 }
 
 impl Sponge for Tip5 {
@@ -885,6 +1040,42 @@ pub(crate) mod tip5_tests {
         prop_assert_eq!(digest_through_pad_squeeze_absorb, hash_varlen_digest);
     }
 
+    #[proptest]
+    fn batch_permutation_is_equivalent_to_permutation(
+        #[strategy(arb())] states: Vec<[BFieldElement; STATE_SIZE]>,
+    ) {
+        let mut batch_permuted_states = states.clone();
+        Tip5::batch_permutation(&mut batch_permuted_states);
+
+        for (state, batch_permuted_state) in states.into_iter().zip_eq(batch_permuted_states) {
+            let mut sponge = Tip5 { state };
+            sponge.permutation();
+            prop_assert_eq!(sponge.state, batch_permuted_state);
+        }
+    }
+
+    #[proptest]
+    fn batch_hash_pairs_is_equivalent_to_hash_pair(
+        #[strategy(arb())] pairs: Vec<(Digest, Digest)>,
+    ) {
+        let digests = pairs
+            .iter()
+            .map(|&(left, right)| Tip5::hash_pair(left, right))
+            .collect_vec();
+        prop_assert_eq!(digests, Tip5::batch_hash_pairs(&pairs));
+    }
+
+    #[proptest]
+    fn batch_hash_varlen_is_equivalent_to_hash_varlen(
+        #[strategy(arb())] inputs: Vec<Vec<BFieldElement>>,
+    ) {
+        let digests = inputs
+            .iter()
+            .map(|input| Tip5::hash_varlen(input))
+            .collect_vec();
+        prop_assert_eq!(digests, Tip5::batch_hash_varlen(&inputs));
+    }
+
     #[test]
     fn test_linearity_of_mds() {
         type SpongeState = [BFieldElement; STATE_SIZE];
@@ -44,6 +44,17 @@ where
     Self: Sized,
 {
     fn primitive_root_of_unity(n: u64) -> Option<Self>;
+
+    /// A primitive `n`th root of unity for every `n` for which one exists, as
+    /// needed by [`ntt_any_length`](crate::math::ntt::ntt_any_length).
+    ///
+    /// Unlike [`primitive_root_of_unity`](Self::primitive_root_of_unity), which
+    /// may be restricted to some orders, this returns [`None`] only if the
+    /// field has no primitive `n`th root of unity. By default, both are the
+    /// same.
+    fn primitive_root_of_unity_of_any_order(n: u64) -> Option<Self> {
+        Self::primitive_root_of_unity(n)
+    }
 }
 
 pub trait ModPowU64 {
@@ -78,10 +89,22 @@ pub trait FiniteField:
     + PrimitiveRootOfUnity
     + Inverse
     + ModPowU32
+    + ModPowU64
     + From<u64>
     + Send
     + Sync
+    + 'static
+where
+    Self: MulAssign<Self::BaseField>,
 {
+    /// The field whose roots of unity are used to transform elements of `Self`,
+    /// for example in [NTTs](crate::math::ntt::ntt). For extension fields, this
+    /// is typically the field they extend; for prime fields, the field itself.
+    ///
+    /// `Self` must be [`MulAssign`]able by elements of the base field, since
+    /// transforms scale elements of `Self` by roots of unity of the base field.
+    type BaseField: FiniteField<BaseField = Self::BaseField>;
+
     /// Montgomery Batch Inversion
     // Adapted from https://paulmillr.com/posts/noble-secp256k1-fast-ecc/#batch-inversion
     fn batch_inversion(input: Vec<Self>) -> Vec<Self> {
@@ -114,6 +137,14 @@ pub trait FiniteField:
         res
     }
 
+    /// Embed an element of the [base field](Self::BaseField) into `Self`.
+    #[inline]
+    fn from_base_field(element: Self::BaseField) -> Self {
+        let mut embedded = Self::ONE;
+        embedded *= element;
+        embedded
+    }
+
     #[inline(always)]
     fn square(self) -> Self {
         self * self
@@ -270,6 +270,36 @@ impl TryFrom<Vec<BFieldElement>> for XFieldElement {
 }
 
 impl XFieldElement {
+    /// The images of x and x² under the first and second power of the
+    /// [Frobenius automorphism](Self::frobenius), _i.e._, x^(P^i) and x^(2·P^i)
+    /// for i ∈ {1, 2}.
+    const FROBENIUS_IMAGES: [[Self; 2]; 2] = [
+        [
+            Self::new([
+                BFieldElement::new(7831040667286096068),
+                BFieldElement::new(10050274602728160328),
+                BFieldElement::new(6700183068485440219),
+            ]),
+            Self::new([
+                BFieldElement::new(6700183068485440220),
+                BFieldElement::new(3915520333643048034),
+                BFieldElement::new(8396469466686423992),
+            ]),
+        ],
+        [
+            Self::new([
+                BFieldElement::new(10615703402128488253),
+                BFieldElement::new(8396469466686423992),
+                BFieldElement::new(11746561000929144102),
+            ]),
+            Self::new([
+                BFieldElement::new(11746561000929144103),
+                BFieldElement::new(14531223735771536287),
+                BFieldElement::new(10050274602728160328),
+            ]),
+        ],
+    ];
+
     /// The quotient defining the [field extension](XFieldElement) over the
     /// [base field](BFieldElement), namely x³ - x + 1.
     #[inline]
@@ -304,6 +334,79 @@ impl XFieldElement {
     pub fn decrement(&mut self, index: usize) {
         self.coefficients[index].decrement();
     }
+
+    /// The [Legendre symbol](BFieldElement::legendre_symbol) of `self`: 0 if
+    /// `self` is zero, 1 if `self` is a non-zero square, and -1 otherwise.
+    ///
+    /// Since the extension degree is odd, an element is a square if and only if
+    /// its norm is a square in the base field.
+    pub fn legendre_symbol(&self) -> i8 {
+        self.norm().legendre_symbol()
+    }
+
+    /// Whether `self` has a [square root](Self::sqrt). Zero is a square.
+    pub fn is_square(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// A square root of `self`, or [`None`] if `self` is not a
+    /// [square](Self::is_square). Which of the two square roots is returned is
+    /// unspecified but deterministic.
+    ///
+    /// With P the base field's prime, the norm of x is N(x) = x^m for the odd
+    /// m = P² + P + 1. Writing m = 2k + 1 gives x = N(x)·(x^-k)², hence
+    /// √N(x)·x^-k is a square root of x. The square root of the norm is
+    /// [computed in the base field](BFieldElement::sqrt).
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        let sqrt_of_norm = self.norm().sqrt()?;
+
+        // k = (P² + P)/2 = P·(P + 1)/2
+        let x_to_the_k = self.mod_pow_u64(BFieldElement::P.div_ceil(2)).frobenius(1);
+        Some(x_to_the_k.inverse() * sqrt_of_norm)
+    }
+
+    /// The [Frobenius automorphism](https://en.wikipedia.org/wiki/Frobenius_endomorphism)
+    /// applied `k` times, _i.e._, `self`^(P^k), where P is the base field's
+    /// prime. Since the extension has degree 3, only `k` mod 3 matters.
+    ///
+    /// The Frobenius automorphism is linear over the base field, making this
+    /// much cheaper than exponentiation.
+    pub fn frobenius(&self, k: usize) -> Self {
+        let [c0, c1, c2] = self.coefficients;
+        match k % EXTENSION_DEGREE {
+            0 => *self,
+            i => {
+                let [x_to_the_p_to_the_i, x_to_the_2_p_to_the_i] = Self::FROBENIUS_IMAGES[i - 1];
+                x_to_the_p_to_the_i * c1 + x_to_the_2_p_to_the_i * c2 + c0
+            }
+        }
+    }
+
+    /// The [norm](https://en.wikipedia.org/wiki/Field_norm) of `self`, _i.e._, the
+    /// product of `self` and its two conjugates, which lies in the base field.
+    pub fn norm(&self) -> BFieldElement {
+        let norm = *self * self.product_of_conjugates();
+        norm.unlift().expect("the norm must lie in the base field")
+    }
+
+    /// The [trace](https://en.wikipedia.org/wiki/Field_trace) of `self`, _i.e._,
+    /// the sum of `self` and its two conjugates, which lies in the base field.
+    ///
+    /// Because the roots of x³ - x + 1 sum to 0 and their squares sum to 2, the
+    /// trace of c0 + c1·x + c2·x² is 3·c0 + 2·c2.
+    pub fn trace(&self) -> BFieldElement {
+        let [c0, _, c2] = self.coefficients;
+        BFieldElement::new(3) * c0 + BFieldElement::new(2) * c2
+    }
+
+    /// `self`^P · `self`^(P²)
+    fn product_of_conjugates(&self) -> Self {
+        self.frobenius(1) * self.frobenius(2)
+    }
 }
 
 impl Inverse for XFieldElement {
@@ -313,9 +416,13 @@ impl Inverse for XFieldElement {
             !self.is_zero(),
             "Cannot invert the zero element in the extension field."
         );
-        let self_as_poly: Polynomial<BFieldElement> = self.to_owned().into();
-        let (_, a, _) = Polynomial::<BFieldElement>::xgcd(self_as_poly, Self::shah_polynomial());
-        a.into()
+
+        // x^-1 = x^P · x^(P²) / N(x)
+        let product_of_conjugates = self.product_of_conjugates();
+        let norm = (*self * product_of_conjugates)
+            .unlift()
+            .expect("the norm must lie in the base field");
+        product_of_conjugates * norm.inverse()
     }
 }
 
@@ -324,6 +431,10 @@ impl PrimitiveRootOfUnity for XFieldElement {
         let b_root = BFieldElement::primitive_root_of_unity(n);
         b_root.map(XFieldElement::new_const)
     }
+
+    fn primitive_root_of_unity_of_any_order(n: u64) -> Option<XFieldElement> {
+        BFieldElement::primitive_root_of_unity_of_any_order(n).map(XFieldElement::new_const)
+    }
 }
 
 impl Distribution<XFieldElement> for Standard {
@@ -392,7 +503,9 @@ impl ConstOne for XFieldElement {
     const ONE: Self = Self::new([BFieldElement::ONE, BFieldElement::ZERO, BFieldElement::ZERO]);
 }
 
-impl FiniteField for XFieldElement {}
+impl FiniteField for XFieldElement {
+    type BaseField = BFieldElement;
+}
 
 impl Add<XFieldElement> for XFieldElement {
     type Output = Self;
@@ -1229,4 +1342,105 @@ mod tests {
         let xfe = XFieldElement::new_const(scalar);
         prop_assert_eq!(xfe, xfe!(scalar));
     }
+
+    #[proptest]
+    fn square_root_of_square_squares_to_square(x: XFieldElement) {
+        let square = x * x;
+        prop_assert!(square.is_square());
+        let root = square.sqrt().unwrap();
+        prop_assert_eq!(square, root * root);
+        prop_assert!(root == x || root == -x);
+    }
+
+    #[proptest]
+    fn square_root_exists_if_and_only_if_element_is_square(x: XFieldElement) {
+        let root = x.sqrt();
+        prop_assert_eq!(x.is_square(), root.is_some());
+        if let Some(root) = root {
+            prop_assert_eq!(x, root * root);
+        }
+    }
+
+    #[proptest]
+    fn legendre_symbol_is_multiplicative(a: XFieldElement, b: XFieldElement) {
+        prop_assert_eq!(
+            a.legendre_symbol() * b.legendre_symbol(),
+            (a * b).legendre_symbol()
+        );
+    }
+
+    #[proptest]
+    fn product_of_square_and_non_square_is_non_square(#[filter(!#x.is_zero())] x: XFieldElement) {
+        let non_square = XFieldElement::new_const(BFieldElement::generator());
+        prop_assert_eq!(-1, non_square.legendre_symbol());
+        prop_assert!((x * x * non_square).sqrt().is_none());
+    }
+
+    #[test]
+    fn square_root_of_zero_and_one() {
+        assert_eq!(0, XFieldElement::ZERO.legendre_symbol());
+        assert_eq!(Some(XFieldElement::ZERO), XFieldElement::ZERO.sqrt());
+        assert_eq!(1, XFieldElement::ONE.legendre_symbol());
+        let root_of_one = XFieldElement::ONE.sqrt().unwrap();
+        assert!(root_of_one.is_one() || (-root_of_one).is_one());
+    }
+
+    #[test]
+    fn frobenius_images_are_correct() {
+        let x = XFieldElement::new([bfe!(0), bfe!(1), bfe!(0)]);
+        let x_to_the_p = x.mod_pow_u64(BFieldElement::P);
+        let x_to_the_p_squared = x_to_the_p.mod_pow_u64(BFieldElement::P);
+        let expected_images = [
+            [x_to_the_p, x_to_the_p.square()],
+            [x_to_the_p_squared, x_to_the_p_squared.square()],
+        ];
+        assert_eq!(expected_images, XFieldElement::FROBENIUS_IMAGES);
+    }
+
+    #[proptest]
+    fn frobenius_is_exponentiation_by_p(x: XFieldElement) {
+        prop_assert_eq!(x.mod_pow_u64(BFieldElement::P), x.frobenius(1));
+        prop_assert_eq!(x.frobenius(1).frobenius(1), x.frobenius(2));
+        prop_assert_eq!(x, x.frobenius(3));
+        prop_assert_eq!(x.frobenius(2), x.frobenius(5));
+    }
+
+    #[proptest]
+    fn frobenius_is_a_field_automorphism(a: XFieldElement, b: XFieldElement) {
+        prop_assert_eq!(a.frobenius(1) + b.frobenius(1), (a + b).frobenius(1));
+        prop_assert_eq!(a.frobenius(1) * b.frobenius(1), (a * b).frobenius(1));
+    }
+
+    #[proptest]
+    fn frobenius_fixes_base_field(b: BFieldElement) {
+        prop_assert_eq!(b.lift(), b.lift().frobenius(1));
+    }
+
+    #[proptest]
+    fn norm_and_trace_are_product_and_sum_of_conjugates(x: XFieldElement) {
+        let conjugates = [x, x.frobenius(1), x.frobenius(2)];
+        let product = conjugates[0] * conjugates[1] * conjugates[2];
+        let sum = conjugates.into_iter().sum::<XFieldElement>();
+        prop_assert_eq!(product, x.norm().lift());
+        prop_assert_eq!(sum, x.trace().lift());
+    }
+
+    #[proptest]
+    fn norm_is_multiplicative_and_trace_is_additive(a: XFieldElement, b: XFieldElement) {
+        prop_assert_eq!(a.norm() * b.norm(), (a * b).norm());
+        prop_assert_eq!(a.trace() + b.trace(), (a + b).trace());
+    }
+
+    #[proptest]
+    fn norm_and_trace_of_base_field_element(b: BFieldElement) {
+        prop_assert_eq!(b * b * b, b.lift().norm());
+        prop_assert_eq!(bfe!(3) * b, b.lift().trace());
+    }
+
+    #[proptest]
+    fn inverse_via_norm_agrees_with_inverse_via_xgcd(#[filter(!#x.is_zero())] x: XFieldElement) {
+        let x_as_poly: Polynomial<BFieldElement> = x.into();
+        let (_, inverse, _) = Polynomial::xgcd(x_as_poly, XFieldElement::shah_polynomial());
+        prop_assert_eq!(XFieldElement::from(inverse), x.inverse());
+    }
 }
@@ -1,21 +1,19 @@
 use std::collections::VecDeque;
-use std::ops::MulAssign;
 
 use num_traits::One;
 
-use super::b_field_element::BFieldElement;
 use super::polynomial::Polynomial;
 use super::traits::FiniteField;
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Leaf<FF: FiniteField + MulAssign<BFieldElement>> {
+pub struct Leaf<FF: FiniteField> {
     pub(crate) points: Vec<FF>,
     zerofier: Polynomial<FF>,
 }
 
 impl<FF> Leaf<FF>
 where
-    FF: FiniteField + MulAssign<BFieldElement>,
+    FF: FiniteField,
 {
     pub fn new(points: Vec<FF>) -> Self {
         let zerofier = Polynomial::zerofier(&points);
@@ -24,7 +22,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct Branch<FF: FiniteField + MulAssign<BFieldElement>> {
+pub struct Branch<FF: FiniteField> {
     zerofier: Polynomial<FF>,
     pub(crate) left: ZerofierTree<FF>,
     pub(crate) right: ZerofierTree<FF>,
@@ -32,7 +30,7 @@ pub struct Branch<FF: FiniteField + MulAssign<BFieldElement>> {
 
 impl<FF> Branch<FF>
 where
-    FF: FiniteField + MulAssign<BFieldElement>,
+    FF: FiniteField,
 {
     pub fn new(left: ZerofierTree<FF>, right: ZerofierTree<FF>) -> Self {
         let zerofier = left.zerofier().multiply(&right.zerofier());
@@ -52,13 +50,13 @@ where
 /// leaf contains a chunk of points whose size is upper-bounded and more or less
 /// equal to some constant threshold.
 #[derive(Debug, Clone, PartialEq)]
-pub enum ZerofierTree<FF: FiniteField + MulAssign<BFieldElement>> {
+pub enum ZerofierTree<FF: FiniteField> {
     Leaf(Leaf<FF>),
     Branch(Box<Branch<FF>>),
     Padding,
 }
 
-impl<FF: FiniteField + MulAssign<BFieldElement>> ZerofierTree<FF> {
+impl<FF: FiniteField> ZerofierTree<FF> {
     /// Regulates the depth at which the tree is truncated. Phrased differently,
     /// regulates the number of points contained by each leaf.
     const RECURSION_CUTOFF_THRESHOLD: usize = 16;
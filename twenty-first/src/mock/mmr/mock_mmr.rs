@@ -21,7 +21,8 @@ use crate::util_types::shared::bag_peaks;
 ///   An Archival-MMR stores all nodes in multiple trees whereas an
 ///   Accumulator-MMR only stores the roots of the binary trees.
 ///
-/// MockMmr is not intended or tested for any kind of production usage.
+/// MockMmr is not intended or tested for any kind of production usage. Use
+/// [`ArchivalMmr`](crate::util_types::mmr::archival_mmr::ArchivalMmr) instead.
 ///
 /// A Merkle Mountain Range is a datastructure for storing a list of hashes.
 ///
@@ -5,6 +5,7 @@ pub use crate::bfe_vec;
 pub use crate::math::b_field_element;
 pub use crate::math::b_field_element::BFieldElement;
 pub use crate::math::bfield_codec::BFieldCodec;
+pub use crate::math::mpolynomial::MPolynomial;
 pub use crate::math::polynomial::Polynomial;
 pub use crate::math::tip5;
 pub use crate::math::tip5::Digest;
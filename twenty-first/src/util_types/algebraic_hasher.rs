@@ -1,5 +1,4 @@
 use std::fmt::Debug;
-use std::iter;
 
 use itertools::Itertools;
 use num_traits::ConstOne;
@@ -8,6 +7,8 @@ use num_traits::ConstZero;
 use crate::math::b_field_element::BFieldElement;
 use crate::math::bfield_codec::BFieldCodec;
 use crate::math::digest::Digest;
+use crate::math::extension_field::ExtensionField;
+use crate::math::extension_field::Modulus;
 use crate::math::x_field_element::XFieldElement;
 use crate::math::x_field_element::EXTENSION_DEGREE;
 
@@ -44,17 +45,17 @@ pub trait Sponge: Clone + Debug + Default + Send + Sync {
     fn squeeze(&mut self) -> [BFieldElement; RATE];
 
     fn pad_and_absorb_all(&mut self, input: &[BFieldElement]) {
-        // pad input with [1, 0, 0, …] – padding is at least one element
-        let padded_length = (input.len() + 1).next_multiple_of(RATE);
-        let padding_iter =
-            iter::once(&BFieldElement::ONE).chain(iter::repeat(&BFieldElement::ZERO));
-        let padded_input = input.iter().chain(padding_iter).take(padded_length);
-
-        for chunk in padded_input.chunks(RATE).into_iter() {
-            // the padded input has length some multiple of `RATE`
-            let absorb_elems = chunk.cloned().collect_vec().try_into().unwrap();
-            self.absorb(absorb_elems);
+        let mut chunks = input.chunks_exact(RATE);
+        for chunk in chunks.by_ref() {
+            self.absorb(chunk.try_into().unwrap());
         }
+
+        // pad the remaining input with [1, 0, 0, …] – padding is at least one element
+        let remainder = chunks.remainder();
+        let mut last_chunk = [BFieldElement::ZERO; RATE];
+        last_chunk[..remainder.len()].copy_from_slice(remainder);
+        last_chunk[remainder.len()] = BFieldElement::ONE;
+        self.absorb(last_chunk);
     }
 }
 
@@ -80,6 +81,24 @@ pub trait AlgebraicHasher: Sponge {
         Digest::new((&produce[..Digest::LEN]).try_into().unwrap())
     }
 
+    /// [Hash](Self::hash_pair) every pair of digests. Implementors may override
+    /// this to process many pairs at once.
+    fn batch_hash_pairs(pairs: &[(Digest, Digest)]) -> Vec<Digest> {
+        pairs
+            .iter()
+            .map(|&(left, right)| Self::hash_pair(left, right))
+            .collect()
+    }
+
+    /// [Hash](Self::hash_varlen) every sequence of [`BFieldElement`]s.
+    /// Implementors may override this to process many sequences at once.
+    fn batch_hash_varlen<T: AsRef<[BFieldElement]>>(inputs: &[T]) -> Vec<Digest> {
+        inputs
+            .iter()
+            .map(|input| Self::hash_varlen(input.as_ref()))
+            .collect()
+    }
+
     /// Produce `num_indices` random integer values in the range `[0, upper_bound)`. The
     /// `upper_bound` must be a power of 2.
     ///
@@ -125,18 +144,43 @@ pub trait AlgebraicHasher: Sponge {
             .map(|elem| XFieldElement::new([elem[0], elem[1], elem[2]]))
             .collect()
     }
+
+    /// Produce `num_elements` random [extension field elements](ExtensionField) of
+    /// degree `N`, for example to reach a higher soundness level than sampling
+    /// [`XFieldElement`]s via [`sample_scalars`](Self::sample_scalars) allows.
+    ///
+    /// If `num_elements · N` is not divisible by [`RATE`][rate], spill the
+    /// remaining elements of the last [`squeeze`][Sponge::squeeze].
+    ///
+    /// [rate]: Sponge::RATE
+    fn sample_extension_field_scalars<const N: usize, M: Modulus>(
+        &mut self,
+        num_elements: usize,
+    ) -> Vec<ExtensionField<N, M>> {
+        let num_squeezes = (num_elements * N).div_ceil(Self::RATE);
+        (0..num_squeezes)
+            .flat_map(|_| self.squeeze())
+            .collect_vec()
+            .chunks_exact(N)
+            .take(num_elements)
+            .map(|elem| ExtensionField::new(elem.try_into().unwrap()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod algebraic_hasher_tests {
     use std::ops::Mul;
 
+    use num_traits::Zero;
     use rand::Rng;
     use rand_distr::Distribution;
     use rand_distr::Standard;
 
     use super::*;
     use crate::math::digest::Digest;
+    use crate::math::extension_field::QuinticModulus;
+    use crate::math::extension_field::ShahModulus;
     use crate::math::tip5::Tip5;
     use crate::math::x_field_element::EXTENSION_DEGREE;
 
@@ -228,4 +272,27 @@ mod algebraic_hasher_tests {
         }
         assert_ne!(product, XFieldElement::ZERO); // false failure with prob ~2^{-192}
     }
+
+    #[test]
+    fn sample_extension_field_scalars_test() {
+        let mut sponge = Tip5::randomly_seeded();
+        for amount in [0, 1, 2, 3, 4] {
+            let scalars = sponge.sample_extension_field_scalars::<5, QuinticModulus>(amount);
+            assert_eq!(amount, scalars.len());
+            assert!(scalars.iter().all(|scalar| !scalar.is_zero())); // false failure with prob ~2^{-318}
+        }
+    }
+
+    #[test]
+    fn sampling_cubic_extension_field_scalars_agrees_with_sample_scalars() {
+        let mut sponge = Tip5::randomly_seeded();
+        let mut sponge_clone = sponge.clone();
+        let scalars = sponge.sample_scalars(7);
+        let ext_scalars = sponge_clone.sample_extension_field_scalars::<3, ShahModulus>(7);
+        let ext_scalars = ext_scalars
+            .into_iter()
+            .map(XFieldElement::from)
+            .collect_vec();
+        assert_eq!(scalars, ext_scalars);
+    }
 }
@@ -0,0 +1,636 @@
+//! The [FRI] low-degree test, proving that a codeword over some coset domain is
+//! close to the evaluations of a polynomial of low degree.
+//!
+//! [FRI]: https://eccc.weizmann.ac.il/report/2017/134/
+
+use std::marker::PhantomData;
+use std::result;
+
+use itertools::Itertools;
+use num_traits::Zero;
+use thiserror::Error;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::bfield_codec::BFieldCodec;
+use crate::math::digest::Digest;
+use crate::math::polynomial::Polynomial;
+use crate::math::traits::ModPowU32;
+use crate::math::traits::PrimitiveRootOfUnity;
+use crate::math::x_field_element::XFieldElement;
+use crate::prelude::Tip5;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::merkle_tree::CpuParallel;
+use crate::util_types::merkle_tree::MerkleTree;
+use crate::util_types::merkle_tree::MerkleTreeError;
+use crate::util_types::merkle_tree::MerkleTreeInclusionProof;
+
+type Result<T> = result::Result<T, FriError>;
+
+/// The parameters of the [FRI](self) low-degree test.
+///
+/// The codeword to test is the evaluation of some function on the coset domain
+/// `{offset · generator^i}` of length `domain_length`. The test establishes that
+/// the codeword is close to the evaluations of a polynomial of degree at most
+/// [`max_degree`](Self::max_degree).
+///
+/// In every round, the prover commits to the current codeword using a
+/// [`MerkleTree`], receives a challenge from the [`AlgebraicHasher`] `H`
+/// acting as a Fiat-Shamir [sponge](crate::util_types::algebraic_hasher::Sponge),
+/// and folds the codeword to half its length. The last codeword is sent in the
+/// clear. Finally, the verifier checks the consistency of the folding steps at
+/// `num_colinearity_checks` many pseudo-random positions.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::util_types::fri::Fri;
+/// let fri = Fri::<Tip5>::new(bfe!(7), 256, 4, 10).unwrap();
+/// let polynomial = Polynomial::new(xfe_vec![1, 2, 3]);
+/// let codeword = polynomial.fast_coset_evaluate(fri.offset(), fri.generator(), 256);
+///
+/// let proof = fri.prove(&codeword, &mut Tip5::init()).unwrap();
+/// let revealed_leafs = fri.verify(&proof, &mut Tip5::init()).unwrap();
+/// for (index, leaf) in revealed_leafs {
+///     assert_eq!(codeword[index], leaf);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fri<H: AlgebraicHasher = Tip5> {
+    offset: BFieldElement,
+    generator: BFieldElement,
+    domain_length: usize,
+    expansion_factor: usize,
+    num_colinearity_checks: usize,
+    _hasher: PhantomData<H>,
+}
+
+/// A proof produced by [`Fri::prove`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, BFieldCodec)]
+pub struct FriProof {
+    /// The Merkle roots of the codewords of all rounds but the last.
+    pub merkle_roots: Vec<Digest>,
+
+    /// The revealed parts of the codewords of all rounds but the last.
+    pub rounds: Vec<FriRound>,
+
+    /// The codeword of the last round, sent in the clear.
+    pub last_codeword: Vec<XFieldElement>,
+}
+
+/// The revealed part of one round's codeword in a [`FriProof`].
+///
+/// For a codeword of length `n` and a colinearity check at index `i`, the values
+/// at indices `a = i mod n/2` and `b = a + n/2` are revealed. These are exactly
+/// the values from which the value at index `a` of the next round's codeword is
+/// computed.
+#[derive(Debug, Clone, PartialEq, Eq, Default, BFieldCodec)]
+pub struct FriRound {
+    pub a_values: Vec<XFieldElement>,
+    pub b_values: Vec<XFieldElement>,
+
+    /// The authentication structure for the revealed values, where all `a`
+    /// indices precede all `b` indices. See
+    /// [`MerkleTree::authentication_structure`].
+    pub authentication_structure: Vec<Digest>,
+}
+
+impl<H: AlgebraicHasher> Fri<H> {
+    /// # Errors
+    ///
+    /// - If the `offset` is zero.
+    /// - If the `domain_length` is not a power of two.
+    /// - If the `domain_length` is 2^32 or larger. Indices into the domain are
+    ///   [sampled](AlgebraicHasher::sample_indices) as [`u32`]s.
+    /// - If the `expansion_factor` is not a power of two, is smaller than 2, or
+    ///   exceeds the `domain_length`.
+    /// - If the `num_colinearity_checks` is zero.
+    pub fn new(
+        offset: BFieldElement,
+        domain_length: usize,
+        expansion_factor: usize,
+        num_colinearity_checks: usize,
+    ) -> Result<Self> {
+        if offset.is_zero() {
+            return Err(FriError::ZeroOffset);
+        }
+        if !domain_length.is_power_of_two() {
+            return Err(FriError::DomainLengthNotPowerOfTwo(domain_length));
+        }
+        if u32::try_from(domain_length).is_err() {
+            return Err(FriError::DomainTooLarge(domain_length));
+        }
+        let Some(generator) = BFieldElement::primitive_root_of_unity(domain_length as u64) else {
+            return Err(FriError::DomainTooLarge(domain_length));
+        };
+        if !expansion_factor.is_power_of_two() || expansion_factor < 2 {
+            return Err(FriError::InvalidExpansionFactor(expansion_factor));
+        }
+        if expansion_factor > domain_length {
+            return Err(FriError::ExpansionFactorExceedsDomainLength);
+        }
+        if num_colinearity_checks == 0 {
+            return Err(FriError::NoColinearityChecks);
+        }
+
+        Ok(Self {
+            offset,
+            generator,
+            domain_length,
+            expansion_factor,
+            num_colinearity_checks,
+            _hasher: PhantomData,
+        })
+    }
+
+    pub fn offset(&self) -> BFieldElement {
+        self.offset
+    }
+
+    pub fn generator(&self) -> BFieldElement {
+        self.generator
+    }
+
+    pub fn domain_length(&self) -> usize {
+        self.domain_length
+    }
+
+    pub fn expansion_factor(&self) -> usize {
+        self.expansion_factor
+    }
+
+    pub fn num_colinearity_checks(&self) -> usize {
+        self.num_colinearity_checks
+    }
+
+    /// The maximal degree of a polynomial whose codeword passes the test.
+    pub fn max_degree(&self) -> usize {
+        self.domain_length / self.expansion_factor - 1
+    }
+
+    /// The number of rounds in which the codeword is folded. Folding stops once
+    /// the codeword is too short to fold further, or once the colinearity checks
+    /// would reveal a substantial part of the codeword.
+    pub fn num_rounds(&self) -> usize {
+        let mut codeword_length = self.domain_length;
+        let mut num_rounds = 0;
+        while codeword_length > self.expansion_factor
+            && 4 * self.num_colinearity_checks < codeword_length
+        {
+            codeword_length /= 2;
+            num_rounds += 1;
+        }
+        num_rounds
+    }
+
+    /// The offset and generator of the domain in the given round.
+    fn round_domain(&self, round: usize) -> (BFieldElement, BFieldElement) {
+        let exponent = 1 << round;
+        let offset = self.offset.mod_pow_u32(exponent);
+        let generator = self.generator.mod_pow_u32(exponent);
+        (offset, generator)
+    }
+
+    /// Prove that the codeword is close to a polynomial of low degree.
+    ///
+    /// The `sponge` should have absorbed everything the codeword depends on.
+    ///
+    /// # Errors
+    ///
+    /// If the length of the codeword does not match the domain length.
+    pub fn prove(&self, codeword: &[XFieldElement], sponge: &mut H) -> Result<FriProof> {
+        if codeword.len() != self.domain_length {
+            return Err(FriError::IncorrectCodewordLength {
+                expected: self.domain_length,
+                actual: codeword.len(),
+            });
+        }
+
+        let mut codeword = codeword.to_vec();
+        let mut codewords = vec![];
+        let mut trees = vec![];
+        for round in 0..self.num_rounds() {
+            let leafs = H::batch_hash_varlen(&codeword.iter().map(|x| x.encode()).collect_vec());
+            let tree = MerkleTree::<H>::new_with_hasher::<CpuParallel>(&leafs)?;
+            sponge.pad_and_absorb_all(&tree.root().values());
+            let folding_challenge = sponge.sample_scalars(1)[0];
+
+            let (offset, generator) = self.round_domain(round);
+            let folded_codeword = Self::fold(&codeword, offset, generator, folding_challenge);
+            codewords.push(codeword);
+            trees.push(tree);
+            codeword = folded_codeword;
+        }
+        let last_codeword = codeword;
+        sponge.pad_and_absorb_all(&last_codeword.encode());
+
+        let mut indices = self.sample_indices(sponge)?;
+        let mut rounds = vec![];
+        for (round_codeword, tree) in codewords.iter().zip_eq(&trees) {
+            let (a_indices, b_indices) = Self::a_and_b_indices(&indices, round_codeword.len());
+            let revealed_indices = [a_indices.clone(), b_indices.clone()].concat();
+            let round = FriRound {
+                a_values: a_indices.iter().map(|&a| round_codeword[a]).collect(),
+                b_values: b_indices.iter().map(|&b| round_codeword[b]).collect(),
+                authentication_structure: tree.authentication_structure(&revealed_indices)?,
+            };
+            rounds.push(round);
+            indices = a_indices;
+        }
+
+        let merkle_roots = trees.iter().map(|tree| tree.root()).collect();
+        Ok(FriProof {
+            merkle_roots,
+            rounds,
+            last_codeword,
+        })
+    }
+
+    /// Verify a proof produced by [`prove`](Self::prove). The `sponge` must be in
+    /// the same state as the prover's was.
+    ///
+    /// On success, returns the revealed indices and values of the first round's
+    /// codeword, which allows linking the tested codeword to other data.
+    ///
+    /// # Errors
+    ///
+    /// If the proof is invalid.
+    pub fn verify(&self, proof: &FriProof, sponge: &mut H) -> Result<Vec<(usize, XFieldElement)>> {
+        let num_rounds = self.num_rounds();
+        if proof.merkle_roots.len() != num_rounds || proof.rounds.len() != num_rounds {
+            return Err(FriError::IncorrectNumberOfRounds);
+        }
+        for (round_index, round) in proof.rounds.iter().enumerate() {
+            if round.a_values.len() != self.num_colinearity_checks
+                || round.b_values.len() != self.num_colinearity_checks
+            {
+                return Err(FriError::IncorrectNumberOfRevealedValues(round_index));
+            }
+        }
+
+        let mut folding_challenges = vec![];
+        for root in &proof.merkle_roots {
+            sponge.pad_and_absorb_all(&root.values());
+            folding_challenges.push(sponge.sample_scalars(1)[0]);
+        }
+        sponge.pad_and_absorb_all(&proof.last_codeword.encode());
+
+        let last_codeword_length = self.domain_length >> num_rounds;
+        if proof.last_codeword.len() != last_codeword_length {
+            return Err(FriError::IncorrectCodewordLength {
+                expected: last_codeword_length,
+                actual: proof.last_codeword.len(),
+            });
+        }
+        let (last_offset, last_generator) = self.round_domain(num_rounds);
+        let last_polynomial =
+            Polynomial::fast_coset_interpolate(last_offset, last_generator, &proof.last_codeword);
+        if last_polynomial.degree() > (last_codeword_length / self.expansion_factor) as isize - 1 {
+            return Err(FriError::LastCodewordTooHighDegree);
+        }
+
+        let mut indices = self.sample_indices(sponge)?;
+        let mut first_round_leafs = vec![];
+        for (round_index, round) in proof.rounds.iter().enumerate() {
+            let codeword_length = self.domain_length >> round_index;
+            let (a_indices, b_indices) = Self::a_and_b_indices(&indices, codeword_length);
+
+            let revealed_indices = a_indices.iter().chain(&b_indices).copied();
+            let revealed_values = round.a_values.iter().chain(&round.b_values).copied();
+            if round_index == 0 {
+                first_round_leafs = revealed_indices
+                    .clone()
+                    .zip(revealed_values.clone())
+                    .collect();
+            }
+            let encoded_values = revealed_values.map(|value| value.encode()).collect_vec();
+            let indexed_leafs = revealed_indices
+                .zip(H::batch_hash_varlen(&encoded_values))
+                .collect();
+            let inclusion_proof = MerkleTreeInclusionProof::<H>::new(
+                codeword_length.ilog2() as usize,
+                indexed_leafs,
+                round.authentication_structure.clone(),
+            );
+            if !inclusion_proof.verify(proof.merkle_roots[round_index]) {
+                return Err(FriError::BadMerkleAuthentication(round_index));
+            }
+
+            // The value at index `a` of the next codeword is revealed either as an `a`
+            // value or as a `b` value in the next round, or is part of the last codeword.
+            let next_codeword_length = codeword_length / 2;
+            let c_values = a_indices.iter().enumerate().map(|(i, &a)| {
+                let Some(next_round) = proof.rounds.get(round_index + 1) else {
+                    return proof.last_codeword[a];
+                };
+                match a < next_codeword_length / 2 {
+                    true => next_round.a_values[i],
+                    false => next_round.b_values[i],
+                }
+            });
+
+            let (offset, generator) = self.round_domain(round_index);
+            let folding_challenge = folding_challenges[round_index];
+            for (((&a, a_value), b_value), c_value) in a_indices
+                .iter()
+                .zip_eq(&round.a_values)
+                .zip_eq(&round.b_values)
+                .zip_eq(c_values)
+            {
+                let a_x = (offset * generator.mod_pow(a as u64)).lift();
+                let a_point = (a_x, *a_value);
+                let b_point = (-a_x, *b_value);
+                let c_point = (folding_challenge, c_value);
+                if !Polynomial::are_colinear_3(a_point, b_point, c_point) {
+                    return Err(FriError::ColinearityCheckFailed(round_index));
+                }
+            }
+
+            indices = a_indices;
+        }
+
+        // without any folding, the first round's codeword is the last codeword
+        if num_rounds == 0 {
+            let (a_indices, b_indices) = Self::a_and_b_indices(&indices, self.domain_length);
+            first_round_leafs = a_indices
+                .into_iter()
+                .chain(b_indices)
+                .map(|index| (index, proof.last_codeword[index]))
+                .collect();
+        }
+
+        Ok(first_round_leafs)
+    }
+
+    /// Fold the codeword to half its length by evaluating, for every pair of points
+    /// `(x, f(x))` and `(-x, f(-x))`, the line through them at the folding
+    /// challenge.
+    fn fold(
+        codeword: &[XFieldElement],
+        offset: BFieldElement,
+        generator: BFieldElement,
+        folding_challenge: XFieldElement,
+    ) -> Vec<XFieldElement> {
+        let half_length = codeword.len() / 2;
+        let mut x = offset;
+        let mut folded_codeword = Vec::with_capacity(half_length);
+        for (&a_value, &b_value) in codeword[..half_length].iter().zip(&codeword[half_length..]) {
+            let a_point = (x.lift(), a_value);
+            let b_point = (-x.lift(), b_value);
+            let c_value = Polynomial::get_colinear_y(a_point, b_point, folding_challenge);
+            folded_codeword.push(c_value);
+            x *= generator;
+        }
+        folded_codeword
+    }
+
+    fn sample_indices(&self, sponge: &mut H) -> Result<Vec<usize>> {
+        let Ok(upper_bound) = u32::try_from(self.domain_length) else {
+            return Err(FriError::DomainTooLarge(self.domain_length));
+        };
+        let indices = sponge
+            .sample_indices(upper_bound, self.num_colinearity_checks)
+            .into_iter()
+            .map(|index| index as usize)
+            .collect();
+        Ok(indices)
+    }
+
+    fn a_and_b_indices(indices: &[usize], codeword_length: usize) -> (Vec<usize>, Vec<usize>) {
+        let half_length = codeword_length / 2;
+        let a_indices = indices.iter().map(|&i| i % half_length).collect_vec();
+        let b_indices = a_indices.iter().map(|&a| a + half_length).collect();
+        (a_indices, b_indices)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum FriError {
+    #[error("the domain offset must not be zero")]
+    ZeroOffset,
+
+    #[error("the domain length must be a power of two, but is {0}")]
+    DomainLengthNotPowerOfTwo(usize),
+
+    #[error("no domain of length {0} exists")]
+    DomainTooLarge(usize),
+
+    #[error("the expansion factor must be a power of two and at least 2, but is {0}")]
+    InvalidExpansionFactor(usize),
+
+    #[error("the expansion factor must not exceed the domain length")]
+    ExpansionFactorExceedsDomainLength,
+
+    #[error("at least one colinearity check is required")]
+    NoColinearityChecks,
+
+    #[error("expected a codeword of length {expected}, but got {actual}")]
+    IncorrectCodewordLength { expected: usize, actual: usize },
+
+    #[error("the proof has an incorrect number of rounds")]
+    IncorrectNumberOfRounds,
+
+    #[error("round {0} reveals an incorrect number of values")]
+    IncorrectNumberOfRevealedValues(usize),
+
+    #[error("the revealed values of round {0} are not authenticated by its Merkle root")]
+    BadMerkleAuthentication(usize),
+
+    #[error("a colinearity check in round {0} failed")]
+    ColinearityCheckFailed(usize),
+
+    #[error("the last codeword does not correspond to a polynomial of low degree")]
+    LastCodewordTooHighDegree,
+
+    #[error(transparent)]
+    MerkleTreeError(#[from] MerkleTreeError),
+}
+
+#[cfg(test)]
+mod fri_tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::util_types::algebraic_hasher::Sponge;
+
+    #[derive(Debug, Clone, test_strategy::Arbitrary)]
+    struct FriToTest {
+        #[strategy(1_u64..1 << 20)]
+        offset: u64,
+
+        #[strategy(1_u32..4)]
+        log_2_expansion_factor: u32,
+
+        #[strategy(#log_2_expansion_factor..12)]
+        log_2_domain_length: u32,
+
+        #[strategy(1_usize..20)]
+        num_colinearity_checks: usize,
+    }
+
+    impl FriToTest {
+        fn fri(&self) -> Fri {
+            let offset = BFieldElement::new(self.offset);
+            let domain_length = 1 << self.log_2_domain_length;
+            let expansion_factor = 1 << self.log_2_expansion_factor;
+            Fri::new(
+                offset,
+                domain_length,
+                expansion_factor,
+                self.num_colinearity_checks,
+            )
+            .unwrap()
+        }
+    }
+
+    fn codeword_of_polynomial_with_degree(
+        fri: &Fri,
+        coefficients: &[XFieldElement],
+        degree: usize,
+    ) -> Vec<XFieldElement> {
+        let polynomial = Polynomial::new(coefficients[..=degree].to_vec());
+        polynomial.fast_coset_evaluate(fri.offset(), fri.generator(), fri.domain_length())
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let one = BFieldElement::new(1);
+        let err = |offset, length, factor, checks| Fri::<Tip5>::new(offset, length, factor, checks);
+        assert_eq!(
+            Err(FriError::ZeroOffset),
+            err(BFieldElement::new(0), 16, 2, 1)
+        );
+        assert_eq!(
+            Err(FriError::DomainLengthNotPowerOfTwo(15)),
+            err(one, 15, 2, 1)
+        );
+        assert_eq!(
+            Err(FriError::DomainTooLarge(1 << 32)),
+            err(one, 1 << 32, 2, 1)
+        );
+        assert_eq!(
+            Err(FriError::DomainTooLarge(1 << 33)),
+            err(one, 1 << 33, 2, 1)
+        );
+        assert_eq!(Err(FriError::InvalidExpansionFactor(3)), err(one, 16, 3, 1));
+        assert_eq!(Err(FriError::InvalidExpansionFactor(1)), err(one, 16, 1, 1));
+        assert_eq!(
+            Err(FriError::ExpansionFactorExceedsDomainLength),
+            err(one, 16, 32, 1)
+        );
+        assert_eq!(Err(FriError::NoColinearityChecks), err(one, 16, 2, 0));
+    }
+
+    #[proptest(cases = 20)]
+    fn folding_preserves_low_degree(
+        fri_to_test: FriToTest,
+        #[strategy(vec(arb(), 1 << 12))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())] folding_challenge: XFieldElement,
+    ) {
+        let fri = fri_to_test.fri();
+        let codeword = codeword_of_polynomial_with_degree(&fri, &coefficients, fri.max_degree());
+        let folded_codeword =
+            Fri::<Tip5>::fold(&codeword, fri.offset(), fri.generator(), folding_challenge);
+
+        let (offset, generator) = fri.round_domain(1);
+        let folded_polynomial =
+            Polynomial::fast_coset_interpolate(offset, generator, &folded_codeword);
+        prop_assert!(folded_polynomial.degree() <= fri.max_degree() as isize / 2);
+    }
+
+    #[proptest(cases = 20)]
+    fn honest_proof_for_low_degree_codeword_can_be_verified(
+        fri_to_test: FriToTest,
+        #[strategy(vec(arb(), 1 << 12))] coefficients: Vec<XFieldElement>,
+        #[strategy(0_usize..1 << 12)] degree: usize,
+    ) {
+        let fri = fri_to_test.fri();
+        let degree = degree % (fri.max_degree() + 1);
+        let codeword = codeword_of_polynomial_with_degree(&fri, &coefficients, degree);
+
+        let proof = fri.prove(&codeword, &mut Tip5::init()).unwrap();
+        prop_assert_eq!(fri.num_rounds(), proof.rounds.len());
+
+        let revealed_leafs = fri.verify(&proof, &mut Tip5::init()).unwrap();
+        prop_assert_eq!(2 * fri.num_colinearity_checks(), revealed_leafs.len());
+        for (index, leaf) in revealed_leafs {
+            prop_assert_eq!(codeword[index], leaf);
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_for_high_degree_codeword_fails_verification(
+        #[strategy(arb())] codeword: [XFieldElement; 256],
+    ) {
+        let fri = Fri::new(BFieldElement::new(7), 256, 4, 10).unwrap();
+        let proof = fri.prove(&codeword, &mut Tip5::init()).unwrap();
+        prop_assert!(fri.verify(&proof, &mut Tip5::init()).is_err());
+    }
+
+    #[proptest(cases = 20)]
+    fn verification_with_different_sponge_state_fails(
+        #[strategy(vec(arb(), 64))] coefficients: Vec<XFieldElement>,
+        #[strategy(arb())] prover_input: BFieldElement,
+        #[strategy(arb())]
+        #[filter(#prover_input != #verifier_input)]
+        verifier_input: BFieldElement,
+    ) {
+        let fri = Fri::new(BFieldElement::new(7), 256, 4, 10).unwrap();
+        let codeword = codeword_of_polynomial_with_degree(&fri, &coefficients, fri.max_degree());
+
+        let mut prover_sponge = Tip5::init();
+        prover_sponge.pad_and_absorb_all(&[prover_input]);
+        let proof = fri.prove(&codeword, &mut prover_sponge).unwrap();
+
+        let mut verifier_sponge = Tip5::init();
+        verifier_sponge.pad_and_absorb_all(&[verifier_input]);
+        prop_assert!(fri.verify(&proof, &mut verifier_sponge).is_err());
+    }
+
+    #[proptest(cases = 20)]
+    fn tampering_with_revealed_values_leads_to_verification_failure(
+        #[strategy(vec(arb(), 64))] coefficients: Vec<XFieldElement>,
+        #[strategy(0_usize..2)] round_index: usize,
+        #[strategy(0_usize..10)] value_index: usize,
+        #[strategy(arb())] new_value: XFieldElement,
+    ) {
+        let fri = Fri::new(BFieldElement::new(7), 256, 4, 10).unwrap();
+        let codeword = codeword_of_polynomial_with_degree(&fri, &coefficients, fri.max_degree());
+        let mut proof = fri.prove(&codeword, &mut Tip5::init()).unwrap();
+
+        let a_value = &mut proof.rounds[round_index].a_values[value_index];
+        prop_assume!(*a_value != new_value);
+        *a_value = new_value;
+
+        prop_assert!(fri.verify(&proof, &mut Tip5::init()).is_err());
+    }
+
+    #[proptest(cases = 20)]
+    fn tampering_with_last_codeword_leads_to_verification_failure(
+        #[strategy(vec(arb(), 64))] coefficients: Vec<XFieldElement>,
+        #[strategy(0_usize..32)] index: usize,
+        #[strategy(arb())] new_value: XFieldElement,
+    ) {
+        let fri = Fri::new(BFieldElement::new(7), 256, 4, 10).unwrap();
+        let codeword = codeword_of_polynomial_with_degree(&fri, &coefficients, fri.max_degree());
+        let mut proof = fri.prove(&codeword, &mut Tip5::init()).unwrap();
+
+        prop_assume!(proof.last_codeword[index] != new_value);
+        proof.last_codeword[index] = new_value;
+        prop_assert!(fri.verify(&proof, &mut Tip5::init()).is_err());
+    }
+
+    #[test]
+    fn proving_codeword_of_incorrect_length_fails() {
+        let fri = Fri::new(BFieldElement::new(7), 256, 4, 10).unwrap();
+        let codeword = vec![XFieldElement::new_const(BFieldElement::new(1)); 128];
+        let err = fri.prove(&codeword, &mut Tip5::init()).unwrap_err();
+        let expected_err = FriError::IncorrectCodewordLength {
+            expected: 256,
+            actual: 128,
+        };
+        assert_eq!(expected_err, err);
+    }
+}
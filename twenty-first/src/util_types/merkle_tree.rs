@@ -1,6 +1,7 @@
 use std::collections::hash_map::Entry::*;
 use std::collections::*;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::result;
 
 use arbitrary::*;
@@ -9,11 +10,18 @@ use lazy_static::lazy_static;
 use rayon::prelude::*;
 use thiserror::Error;
 
+use crate::math::b_field_element::BFieldElement;
 use crate::math::digest::Digest;
 use crate::prelude::Tip5;
 use crate::util_types::algebraic_hasher::AlgebraicHasher;
 
 const DEFAULT_PARALLELIZATION_CUTOFF: usize = 256;
+
+/// The number of sibling pairs [`CpuParallel`] hands to
+/// [`AlgebraicHasher::batch_hash_pairs`] at once when a level is hashed in
+/// parallel.
+const NUM_PAIRS_PER_BATCH: usize = 64;
+
 lazy_static! {
     static ref PARALLELIZATION_CUTOFF: usize =
         std::env::var("TWENTY_FIRST_MERKLE_TREE_PARALLELIZATION_CUTOFF")
@@ -40,18 +48,30 @@ pub const MAX_TREE_HEIGHT: usize = MAX_NUM_LEAFS.ilog2() as usize;
 /// It is recommended to use [`root()`](MerkleTree::root) instead.
 const ROOT_INDEX: usize = 1;
 
+/// The domain separator from which the [padding leaf](MerkleTree::padding_leaf)
+/// is derived.
+const PADDING_LEAF_DOMAIN_SEPARATOR: &[u8] = b"twenty-first::merkle_tree::padding_leaf";
+
 type Result<T> = result::Result<T, MerkleTreeError>;
 
 /// A [Merkle tree][merkle_tree] is a binary tree of [digests](Digest) that is
 /// used to efficiently prove the inclusion of items in a set. Set inclusion can
 /// be verified through an [inclusion proof](MerkleTreeInclusionProof).
 ///
-/// The used hash function is [`Tip5`].
+/// The internal nodes are computed using the [`AlgebraicHasher`] `H`, which
+/// defaults to [`Tip5`].
 ///
 /// [merkle_tree]: https://en.wikipedia.org/wiki/Merkle_tree
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MerkleTree {
+pub struct MerkleTree<H: AlgebraicHasher = Tip5> {
     nodes: Vec<Digest>,
+
+    /// The number of leafs before [padding][padded], if the tree was padded.
+    ///
+    /// [padded]: MerkleTreeMaker::from_digests_padded
+    num_unpadded_leafs: Option<usize>,
+
+    _hasher: PhantomData<H>,
 }
 
 /// A trait for creating a [`MerkleTree`] from a list of [`Digest`]s.
@@ -70,12 +90,19 @@ pub struct MerkleTree {
 ///
 /// # fn wrapper_fn() -> Result<(), MerkleTreeError> {
 /// let leafs = [bfe_vec![42, 43, 44], bfe_vec![1337, 1338]];
-/// let leaf_digests = leafs.map(|leaf| Tip5::hash_varlen(&leaf));
+/// let leaf_digests = Tip5::batch_hash_varlen(&leafs);
 ///
 /// let tree = MerkleTree::new::<CpuParallel>(&leaf_digests)?;
 ///
 /// // also works:
 /// let tree = CpuParallel::from_digests(&leaf_digests)?;
+///
+/// // other hash functions are supported, too:
+/// let tree = MerkleTree::<Tip5>::new_with_hasher::<CpuParallel>(&leaf_digests)?;
+///
+/// // the number of leafs does not need to be a power of two when padding:
+/// let leaf_digests = [leaf_digests[0], leaf_digests[1], leaf_digests[0]];
+/// let tree = MerkleTree::new_padded::<CpuParallel>(&leaf_digests)?;
 /// # Ok(())
 /// # }
 /// # wrapper_fn().unwrap();
@@ -92,16 +119,51 @@ pub struct MerkleTree {
 /// Implementations of this trait should return an error if:
 /// - The number of digests is zero.
 /// - The number of digests is not a power of two.
-pub trait MerkleTreeMaker {
-    fn from_digests(digests: &[Digest]) -> Result<MerkleTree>;
+pub trait MerkleTreeMaker<H: AlgebraicHasher = Tip5> {
+    fn from_digests(digests: &[Digest]) -> Result<MerkleTree<H>>;
+
+    /// Build a Merkle tree over the given digests, appending as many copies of the
+    /// [padding leaf](MerkleTree::padding_leaf) as are needed to make the number
+    /// of leafs a power of two. No padding leafs are added if the number of digests
+    /// already is a power of two.
+    ///
+    /// The [root](MerkleTree::root) of a padded tree commits to the number of
+    /// digests: it is the hash of the root of the underlying binary tree and the
+    /// number of digests. Inclusion proofs for leafs of a padded tree must be
+    /// verified using [`verify_padded`](MerkleTreeInclusionProof::verify_padded).
+    ///
+    /// # Errors
+    ///
+    /// - If the number of digests is 0.
+    /// - If the number of digests exceeds the maximum number of leafs.
+    fn from_digests_padded(digests: &[Digest]) -> Result<MerkleTree<H>> {
+        if digests.is_empty() {
+            return Err(MerkleTreeError::TooFewLeafs);
+        }
+        if digests.len() > MAX_NUM_LEAFS {
+            return Err(MerkleTreeError::TreeTooHigh);
+        }
+
+        let mut padded_digests = digests.to_vec();
+        padded_digests.resize(
+            digests.len().next_power_of_two(),
+            MerkleTree::<H>::padding_leaf(),
+        );
+        let mut tree = Self::from_digests(&padded_digests)?;
+        tree.num_unpadded_leafs = Some(digests.len());
+        Ok(tree)
+    }
 }
 
 /// A full inclusion proof for the leafs at the supplied indices, including the
 /// leafs themselves. The proof is relative to some [Merkle tree](MerkleTree),
 /// which is not necessarily (and generally cannot be) known in its entirety by
 /// the verifier.
+///
+/// The proof can only be verified using the same [`AlgebraicHasher`] `H` that
+/// was used to build the Merkle tree.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
-pub struct MerkleTreeInclusionProof {
+pub struct MerkleTreeInclusionProof<H: AlgebraicHasher = Tip5> {
     /// The stated height of the Merkle tree this proof is relative to.
     pub tree_height: usize,
 
@@ -119,6 +181,10 @@ pub struct MerkleTreeInclusionProof {
     ///
     /// [auth_structure]: MerkleTree::authentication_structure
     pub authentication_structure: Vec<Digest>,
+
+    /// Marks the [`AlgebraicHasher`] the proof is relative to. Holds no data;
+    /// when constructing a proof from its fields, set this to [`PhantomData`].
+    pub _hasher: PhantomData<H>,
 }
 
 /// Helper struct for verifying inclusion of items in a Merkle tree.
@@ -140,17 +206,73 @@ pub struct MerkleTreeInclusionProof {
 ///
 /// [auth_structure]: MerkleTree::authentication_structure
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
-pub(crate) struct PartialMerkleTree {
+pub(crate) struct PartialMerkleTree<H: AlgebraicHasher = Tip5> {
     tree_height: usize,
     leaf_indices: Vec<usize>,
     nodes: HashMap<usize, Digest>,
+    _hasher: PhantomData<H>,
 }
 
 impl MerkleTree {
+    /// Build a Merkle tree using [`Tip5`]. To use a different hash function, see
+    /// [`new_with_hasher`](Self::new_with_hasher).
     pub fn new<Maker: MerkleTreeMaker>(leafs: &[Digest]) -> Result<Self> {
         Maker::from_digests(leafs)
     }
 
+    /// Build a Merkle tree using [`Tip5`], padding the leafs if their number is
+    /// not a power of two. See [`MerkleTreeMaker::from_digests_padded`] for details.
+    pub fn new_padded<Maker: MerkleTreeMaker>(leafs: &[Digest]) -> Result<Self> {
+        Maker::from_digests_padded(leafs)
+    }
+}
+
+impl<H: AlgebraicHasher> MerkleTree<H> {
+    /// Build a Merkle tree using the [`AlgebraicHasher`] `H`.
+    pub fn new_with_hasher<Maker: MerkleTreeMaker<H>>(leafs: &[Digest]) -> Result<Self> {
+        Maker::from_digests(leafs)
+    }
+
+    /// Build a Merkle tree using the [`AlgebraicHasher`] `H`, padding the leafs if
+    /// their number is not a power of two. See
+    /// [`MerkleTreeMaker::from_digests_padded`] for details.
+    pub fn new_padded_with_hasher<Maker: MerkleTreeMaker<H>>(leafs: &[Digest]) -> Result<Self> {
+        Maker::from_digests_padded(leafs)
+    }
+
+    /// The digest used to pad the leafs of a Merkle tree to a power of two. It is
+    /// derived from a fixed domain separator using the [`AlgebraicHasher`] `H`,
+    /// which makes it independent of any specific application.
+    pub fn padding_leaf() -> Digest {
+        let domain_separator = PADDING_LEAF_DOMAIN_SEPARATOR
+            .iter()
+            .map(|&byte| BFieldElement::new(byte.into()))
+            .collect_vec();
+        H::hash_varlen(&domain_separator)
+    }
+
+    /// The height of the Merkle tree built by [padding][padded] `num_leafs` many
+    /// leafs.
+    ///
+    /// [padded]: MerkleTreeMaker::from_digests_padded
+    fn padded_height(num_leafs: usize) -> usize {
+        num_leafs.next_power_of_two().ilog2() as usize
+    }
+
+    /// The root of a [padded][padded] Merkle tree: the hash of the root of the
+    /// underlying binary tree and the number of leafs before padding.
+    ///
+    /// Uses [`hash_varlen`](AlgebraicHasher::hash_varlen) to separate it from the
+    /// internal nodes, which are computed using
+    /// [`hash_pair`](AlgebraicHasher::hash_pair).
+    ///
+    /// [padded]: MerkleTreeMaker::from_digests_padded
+    fn padded_root(binary_tree_root: Digest, num_leafs: usize) -> Digest {
+        let num_leafs = BFieldElement::new(num_leafs as u64);
+        let preimage = [binary_tree_root.values().as_slice(), &[num_leafs]].concat();
+        H::hash_varlen(&preimage)
+    }
+
     /// Given a list of leaf indices, return the indices of exactly those nodes that
     /// are needed to prove (or verify) that the indicated leafs are in the Merkle
     /// tree.
@@ -230,8 +352,17 @@ impl MerkleTree {
         Ok(auth_structure)
     }
 
+    /// The root of the Merkle tree. For [padded][padded] trees, the root also
+    /// commits to the number of leafs before padding, and is therefore different
+    /// from the root [node](Self::node).
+    ///
+    /// [padded]: MerkleTreeMaker::from_digests_padded
     pub fn root(&self) -> Digest {
-        self.nodes[ROOT_INDEX]
+        let binary_tree_root = self.nodes[ROOT_INDEX];
+        match self.num_unpadded_leafs {
+            Some(num_leafs) => Self::padded_root(binary_tree_root, num_leafs),
+            None => binary_tree_root,
+        }
     }
 
     pub fn num_leafs(&self) -> usize {
@@ -285,29 +416,42 @@ impl MerkleTree {
     pub fn inclusion_proof_for_leaf_indices(
         &self,
         indices: &[usize],
-    ) -> Result<MerkleTreeInclusionProof> {
-        let proof = MerkleTreeInclusionProof {
-            tree_height: self.height(),
-            indexed_leafs: self.indexed_leafs(indices)?,
-            authentication_structure: self.authentication_structure(indices)?,
-        };
+    ) -> Result<MerkleTreeInclusionProof<H>> {
+        let proof = MerkleTreeInclusionProof::new(
+            self.height(),
+            self.indexed_leafs(indices)?,
+            self.authentication_structure(indices)?,
+        );
         Ok(proof)
     }
 }
 
-impl<'a> Arbitrary<'a> for MerkleTree {
+impl<'a, H: AlgebraicHasher> Arbitrary<'a> for MerkleTree<H> {
     fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
         let height = u.int_in_range(0..=13)?;
         let num_leafs = 1 << height;
         let leaf_digests: arbitrary::Result<Vec<_>> =
             (0..num_leafs).map(|_| u.arbitrary()).collect();
 
-        let tree = Self::new::<CpuParallel>(&leaf_digests?).unwrap();
+        let tree = Self::new_with_hasher::<CpuParallel>(&leaf_digests?).unwrap();
         Ok(tree)
     }
 }
 
-impl MerkleTreeInclusionProof {
+impl<H: AlgebraicHasher> MerkleTreeInclusionProof<H> {
+    pub fn new(
+        tree_height: usize,
+        indexed_leafs: Vec<(usize, Digest)>,
+        authentication_structure: Vec<Digest>,
+    ) -> Self {
+        Self {
+            tree_height,
+            indexed_leafs,
+            authentication_structure,
+            _hasher: PhantomData,
+        }
+    }
+
     fn leaf_indices(&self) -> impl Iterator<Item = &usize> {
         self.indexed_leafs.iter().map(|(index, _)| index)
     }
@@ -322,13 +466,44 @@ impl MerkleTreeInclusionProof {
         if self.is_trivial() {
             return true;
         }
-        let Ok(partial_tree) = PartialMerkleTree::try_from(self) else {
+        let Ok(computed_root) = self.compute_root() else {
             return false;
         };
-        let Ok(computed_root) = partial_tree.root() else {
+        computed_root == expected_root
+    }
+
+    fn compute_root(self) -> Result<Digest> {
+        PartialMerkleTree::try_from(self)?.root()
+    }
+
+    /// Verify that the given root digest is the root of a [padded][padded] Merkle
+    /// tree built from `num_leafs` many leafs, and that this tree contains the
+    /// indicated leafs.
+    ///
+    /// The root of a padded tree commits to `num_leafs`, so a proof relative to a
+    /// tree with a different number of leafs fails verification. In addition, this
+    /// checks that the stated tree height matches `num_leafs`, and that no revealed
+    /// leaf is a padding leaf, _i.e._, that every revealed leaf index is less than
+    /// `num_leafs`.
+    ///
+    /// [padded]: MerkleTreeMaker::from_digests_padded
+    pub fn verify_padded(self, expected_root: Digest, num_leafs: usize) -> bool {
+        if num_leafs == 0 || num_leafs > MAX_NUM_LEAFS {
+            return false;
+        }
+        if self.tree_height != MerkleTree::<H>::padded_height(num_leafs) {
+            return false;
+        }
+        if self.leaf_indices().any(|&index| index >= num_leafs) {
+            return false;
+        }
+        if self.is_trivial() {
+            return true;
+        }
+        let Ok(binary_tree_root) = self.compute_root() else {
             return false;
         };
-        computed_root == expected_root
+        MerkleTree::<H>::padded_root(binary_tree_root, num_leafs) == expected_root
     }
 
     /// Transform the inclusion proof into a list of authentication paths.
@@ -363,7 +538,7 @@ impl MerkleTreeInclusionProof {
     }
 }
 
-impl PartialMerkleTree {
+impl<H: AlgebraicHasher> PartialMerkleTree<H> {
     pub fn root(&self) -> Result<Digest> {
         self.nodes
             .get(&ROOT_INDEX)
@@ -419,7 +594,7 @@ impl PartialMerkleTree {
 
     fn insert_digest_for_index(&mut self, parent_index: usize) -> Result<()> {
         let (left_child, right_child) = self.children_of_node(parent_index)?;
-        let parent_digest = Tip5::hash_pair(left_child, right_child);
+        let parent_digest = H::hash_pair(left_child, right_child);
 
         match self.nodes.insert(parent_index, parent_digest) {
             Some(_) => Err(MerkleTreeError::SpuriousNodeIndex(parent_index)),
@@ -470,15 +645,16 @@ impl PartialMerkleTree {
     }
 }
 
-impl TryFrom<MerkleTreeInclusionProof> for PartialMerkleTree {
+impl<H: AlgebraicHasher> TryFrom<MerkleTreeInclusionProof<H>> for PartialMerkleTree<H> {
     type Error = MerkleTreeError;
 
-    fn try_from(proof: MerkleTreeInclusionProof) -> Result<Self> {
+    fn try_from(proof: MerkleTreeInclusionProof<H>) -> Result<Self> {
         let leaf_indices = proof.leaf_indices().copied().collect();
         let mut partial_tree = PartialMerkleTree {
             tree_height: proof.tree_height,
             leaf_indices,
             nodes: HashMap::new(),
+            _hasher: PhantomData,
         };
 
         let num_leafs = partial_tree.num_leafs()?;
@@ -486,7 +662,7 @@ impl TryFrom<MerkleTreeInclusionProof> for PartialMerkleTree {
             return Err(MerkleTreeError::LeafIndexInvalid { num_leafs });
         }
 
-        let node_indices = MerkleTree::authentication_structure_node_indices(
+        let node_indices = MerkleTree::<H>::authentication_structure_node_indices(
             num_leafs,
             &partial_tree.leaf_indices,
         )?;
@@ -516,7 +692,31 @@ impl TryFrom<MerkleTreeInclusionProof> for PartialMerkleTree {
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub struct CpuParallel;
 
-impl MerkleTreeMaker for CpuParallel {
+impl CpuParallel {
+    /// Build a Merkle tree using [`Tip5`]. Allows calling
+    /// `CpuParallel::from_digests` without specifying the hash function. For
+    /// other hash functions, see [`MerkleTree::new_with_hasher`].
+    ///
+    /// # Errors
+    ///
+    /// See [`MerkleTreeMaker::from_digests`].
+    pub fn from_digests(digests: &[Digest]) -> Result<MerkleTree> {
+        <Self as MerkleTreeMaker>::from_digests(digests)
+    }
+
+    /// Build a padded Merkle tree using [`Tip5`]. Allows calling
+    /// `CpuParallel::from_digests_padded` without specifying the hash function.
+    /// For other hash functions, see [`MerkleTree::new_padded_with_hasher`].
+    ///
+    /// # Errors
+    ///
+    /// See [`MerkleTreeMaker::from_digests_padded`].
+    pub fn from_digests_padded(digests: &[Digest]) -> Result<MerkleTree> {
+        <Self as MerkleTreeMaker>::from_digests_padded(digests)
+    }
+}
+
+impl<H: AlgebraicHasher> MerkleTreeMaker<H> for CpuParallel {
     /// Takes an array of digests and builds a MerkleTree over them. The digests are
     /// copied as the leafs of the tree.
     ///
@@ -524,7 +724,7 @@ impl MerkleTreeMaker for CpuParallel {
     ///
     /// - If the number of digests is 0.
     /// - If the number of digests is not a power of two.
-    fn from_digests(digests: &[Digest]) -> Result<MerkleTree> {
+    fn from_digests(digests: &[Digest]) -> Result<MerkleTree<H>> {
         if digests.is_empty() {
             return Err(MerkleTreeError::TooFewLeafs);
         }
@@ -539,32 +739,40 @@ impl MerkleTreeMaker for CpuParallel {
         let mut nodes = vec![filler; 2 * leafs_count];
         nodes[leafs_count..(leafs_count + leafs_count)].clone_from_slice(&digests[..leafs_count]);
 
-        // Parallel digest calculations
+        // The nodes of a level with `n` nodes live at indices `n..2n`, their
+        // children at indices `2n..4n`. All pairs of children on one level are
+        // hashed in batches; large levels are split across threads.
+        let hash_level = |parents: &mut [Digest], children: &[Digest]| {
+            let pairs = children
+                .chunks_exact(2)
+                .map(|siblings| (siblings[0], siblings[1]))
+                .collect_vec();
+            parents.copy_from_slice(&H::batch_hash_pairs(&pairs));
+        };
+
         let mut node_count_on_this_level: usize = leafs_count / 2;
-        let mut count_acc: usize = 0;
-        while node_count_on_this_level >= *PARALLELIZATION_CUTOFF {
-            let mut local_digests: Vec<Digest> = Vec::with_capacity(node_count_on_this_level);
-            (0..node_count_on_this_level)
-                .into_par_iter()
-                .map(|i| {
-                    let j = node_count_on_this_level + i;
-                    let left_child = nodes[j * 2];
-                    let right_child = nodes[j * 2 + 1];
-                    Tip5::hash_pair(left_child, right_child)
-                })
-                .collect_into_vec(&mut local_digests);
-            nodes[node_count_on_this_level..(node_count_on_this_level + node_count_on_this_level)]
-                .clone_from_slice(&local_digests[..node_count_on_this_level]);
-            count_acc += node_count_on_this_level;
+        while node_count_on_this_level > 0 {
+            let (upper_levels, lower_levels) = nodes.split_at_mut(2 * node_count_on_this_level);
+            let parents = &mut upper_levels[node_count_on_this_level..];
+            let children = &lower_levels[..2 * node_count_on_this_level];
+
+            if node_count_on_this_level >= *PARALLELIZATION_CUTOFF {
+                parents
+                    .par_chunks_mut(NUM_PAIRS_PER_BATCH)
+                    .zip(children.par_chunks(2 * NUM_PAIRS_PER_BATCH))
+                    .for_each(|(parents, children)| hash_level(parents, children));
+            } else {
+                hash_level(parents, children);
+            }
             node_count_on_this_level /= 2;
         }
 
-        // Sequential digest calculations
-        for i in (ROOT_INDEX..(digests.len() - count_acc)).rev() {
-            nodes[i] = Tip5::hash_pair(nodes[i * 2], nodes[i * 2 + 1]);
-        }
-
-        Ok(MerkleTree { nodes })
+        let tree = MerkleTree {
+            nodes,
+            num_unpadded_leafs: None,
+            _hasher: PhantomData,
+        };
+        Ok(tree)
     }
 }
 
@@ -591,7 +799,7 @@ pub enum MerkleTreeError {
     #[error("Too few leafs to build a Merkle tree.")]
     TooFewLeafs,
 
-    #[error("The number of leafs must be a power of two.")]
+    #[error("The number of leafs must be a power of two. Consider padding the leafs.")]
     IncorrectNumberOfLeafs,
 
     #[error("Tree height must not exceed {MAX_TREE_HEIGHT}.")]
@@ -610,6 +818,35 @@ pub mod merkle_tree_test {
     use crate::math::b_field_element::BFieldElement;
     use crate::math::digest::digest_tests::DigestCorruptor;
     use crate::math::tip5::Tip5;
+    use crate::util_types::algebraic_hasher::Sponge;
+    use crate::util_types::algebraic_hasher::RATE;
+
+    /// A hash function different from [`Tip5`] that is only used to test that
+    /// Merkle trees respect the hasher they are generic over.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    struct FlippedTip5(Tip5);
+
+    impl Sponge for FlippedTip5 {
+        const RATE: usize = RATE;
+
+        fn init() -> Self {
+            Self(Tip5::init())
+        }
+
+        fn absorb(&mut self, input: [BFieldElement; RATE]) {
+            self.0.absorb(input);
+        }
+
+        fn squeeze(&mut self) -> [BFieldElement; RATE] {
+            self.0.squeeze()
+        }
+    }
+
+    impl AlgebraicHasher for FlippedTip5 {
+        fn hash_pair(left: Digest, right: Digest) -> Digest {
+            Tip5::hash_pair(right, left)
+        }
+    }
 
     impl MerkleTree {
         fn test_tree_of_height(tree_height: usize) -> Self {
@@ -864,6 +1101,128 @@ pub mod merkle_tree_test {
         assert_eq!(MerkleTreeError::LeafIndexInvalid { num_leafs }, err);
     }
 
+    #[test]
+    fn building_padded_merkle_tree_from_empty_list_of_digests_fails_with_expected_error() {
+        let maybe_tree = MerkleTree::new_padded::<CpuParallel>(&[]);
+        let err = maybe_tree.unwrap_err();
+        assert_eq!(MerkleTreeError::TooFewLeafs, err);
+    }
+
+    #[test]
+    fn padding_leaf_is_not_default_digest() {
+        assert_ne!(Digest::default(), MerkleTree::<Tip5>::padding_leaf());
+    }
+
+    #[proptest(cases = 30)]
+    fn padding_leafs_that_are_a_power_of_two_adds_no_leafs_but_changes_root(
+        #[strategy(0_usize..10)] _log_2_num_leafs: usize,
+        #[strategy(vec(arb(), 1 << #_log_2_num_leafs))] leafs: Vec<Digest>,
+    ) {
+        let tree = MerkleTree::new::<CpuParallel>(&leafs).unwrap();
+        let padded_tree = MerkleTree::new_padded::<CpuParallel>(&leafs).unwrap();
+        prop_assert_eq!(tree.nodes(), padded_tree.nodes());
+        prop_assert_ne!(tree.root(), padded_tree.root());
+    }
+
+    #[proptest(cases = 30)]
+    fn padded_merkle_tree_has_expected_leafs(#[strategy(vec(arb(), 1..600))] leafs: Vec<Digest>) {
+        let tree = MerkleTree::new_padded::<CpuParallel>(&leafs).unwrap();
+        prop_assert_eq!(leafs.len().next_power_of_two(), tree.num_leafs());
+
+        let (actual_leafs, padding_leafs) = tree.leafs().split_at(leafs.len());
+        prop_assert_eq!(&leafs, actual_leafs);
+        let padding_leaf = MerkleTree::<Tip5>::padding_leaf();
+        prop_assert!(padding_leafs.iter().all(|&leaf| leaf == padding_leaf));
+    }
+
+    #[proptest(cases = 30)]
+    fn honest_proof_for_padded_merkle_tree_can_be_verified(
+        #[strategy(vec(arb(), 1..600))] leafs: Vec<Digest>,
+        #[strategy(vec(0..#leafs.len(), 1..=#leafs.len()))] leaf_indices: Vec<usize>,
+    ) {
+        let num_leafs = leafs.len();
+        let tree = MerkleTree::new_padded::<CpuParallel>(&leafs).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(proof.verify_padded(tree.root(), num_leafs));
+    }
+
+    #[proptest(cases = 30)]
+    fn proof_for_padded_merkle_tree_with_incorrect_number_of_leafs_fails_verification(
+        #[strategy(vec(arb(), 1..600))] leafs: Vec<Digest>,
+        #[strategy(vec(0..#leafs.len(), 1..=#leafs.len()))] leaf_indices: Vec<usize>,
+        #[strategy(0_usize..1200)]
+        #[filter(#wrong_num_leafs != #leafs.len())]
+        wrong_num_leafs: usize,
+    ) {
+        let tree = MerkleTree::new_padded::<CpuParallel>(&leafs).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(!proof.verify_padded(tree.root(), wrong_num_leafs));
+    }
+
+    #[proptest(cases = 30)]
+    fn proof_revealing_padding_leaf_fails_padded_verification(
+        #[strategy(vec(arb(), 1..600))]
+        #[filter(!#leafs.len().is_power_of_two())]
+        leafs: Vec<Digest>,
+        #[strategy(#leafs.len()..#leafs.len().next_power_of_two())] padding_index: usize,
+    ) {
+        let tree = MerkleTree::new_padded::<CpuParallel>(&leafs).unwrap();
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&[padding_index])
+            .unwrap();
+        let binary_tree_root = tree.nodes()[ROOT_INDEX];
+        prop_assert!(proof.clone().verify(binary_tree_root));
+        prop_assert!(!proof.verify_padded(tree.root(), leafs.len()));
+    }
+
+    #[proptest(cases = 20)]
+    fn merkle_tree_root_depends_on_hasher(
+        #[strategy(vec(arb(), 2..64))]
+        #[filter(#leafs.iter().all_unique())]
+        leafs: Vec<Digest>,
+    ) {
+        let num_leafs = leafs.len().next_power_of_two();
+        let leafs = leafs.into_iter().cycle().take(num_leafs).collect_vec();
+
+        let tip5_tree = MerkleTree::<Tip5>::new_with_hasher::<CpuParallel>(&leafs).unwrap();
+        let flipped_tree =
+            MerkleTree::<FlippedTip5>::new_with_hasher::<CpuParallel>(&leafs).unwrap();
+        prop_assert_ne!(tip5_tree.root(), flipped_tree.root());
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_for_tree_with_non_default_hasher_can_be_verified(
+        #[strategy(arb())] tree: MerkleTree<FlippedTip5>,
+        #[strategy(vec(0..#tree.num_leafs(), 1..=#tree.num_leafs()))] leaf_indices: Vec<usize>,
+    ) {
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        prop_assert!(proof.verify(tree.root()));
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_verification_with_mismatching_hasher_fails(
+        #[strategy(arb())]
+        #[filter(#tree.height() > 0)]
+        tree: MerkleTree<FlippedTip5>,
+        #[strategy(vec(0..#tree.num_leafs(), 1..=#tree.num_leafs()))] leaf_indices: Vec<usize>,
+    ) {
+        let proof = tree
+            .inclusion_proof_for_leaf_indices(&leaf_indices)
+            .unwrap();
+        let proof = MerkleTreeInclusionProof::<Tip5>::new(
+            proof.tree_height,
+            proof.indexed_leafs,
+            proof.authentication_structure,
+        );
+        prop_assert!(!proof.verify(tree.root()));
+    }
+
     #[test]
     fn authentication_paths_of_extremely_small_tree_use_expected_digests() {
         //     _ 1_
@@ -916,11 +1275,11 @@ pub mod merkle_tree_test {
         let tree = test_tree.tree;
         for (leaf_index, &leaf) in tree.leafs().iter().enumerate() {
             let authentication_path = tree.authentication_structure(&[leaf_index]).unwrap();
-            let proof = MerkleTreeInclusionProof {
-                tree_height: tree.height(),
-                indexed_leafs: [(leaf_index, leaf)].into(),
-                authentication_structure: authentication_path,
-            };
+            let proof: MerkleTreeInclusionProof = MerkleTreeInclusionProof::new(
+                tree.height(),
+                [(leaf_index, leaf)].into(),
+                authentication_path,
+            );
             let verdict = proof.verify(tree.root());
             prop_assert!(verdict);
         }
@@ -932,7 +1291,7 @@ pub mod merkle_tree_test {
         let proof = merkle_tree
             .inclusion_proof_for_leaf_indices(&[0, 2])
             .unwrap();
-        let partial_tree = PartialMerkleTree::try_from(proof).unwrap();
+        let partial_tree = PartialMerkleTree::<Tip5>::try_from(proof).unwrap();
 
         //         ──── 1 ────
         //        ╱           ╲
@@ -964,10 +1323,11 @@ pub mod merkle_tree_test {
         //  0      2   <-- opened_leaf_indices
 
         let node_indices = [3, 8, 9, 10, 11];
-        let mut partial_tree = PartialMerkleTree {
+        let mut partial_tree: PartialMerkleTree = PartialMerkleTree {
             tree_height: 3,
             leaf_indices: vec![0, 2],
             nodes: PartialMerkleTree::dummy_nodes_for_indices(&node_indices),
+            ..Default::default()
         };
         partial_tree.fill().unwrap();
     }
@@ -986,10 +1346,11 @@ pub mod merkle_tree_test {
         //  0      2   <-- opened_leaf_indices
 
         let node_indices = [8, 9, 10, 11];
-        let mut partial_tree = PartialMerkleTree {
+        let mut partial_tree: PartialMerkleTree = PartialMerkleTree {
             tree_height: 3,
             leaf_indices: vec![0, 2],
             nodes: PartialMerkleTree::dummy_nodes_for_indices(&node_indices),
+            ..Default::default()
         };
 
         let err = partial_tree.fill().unwrap_err();
@@ -1010,10 +1371,11 @@ pub mod merkle_tree_test {
         //  0      2   <-- opened_leaf_indices
 
         let node_indices = [2, 3, 8, 9, 10, 11];
-        let mut partial_tree = PartialMerkleTree {
+        let mut partial_tree: PartialMerkleTree = PartialMerkleTree {
             tree_height: 3,
             leaf_indices: vec![0, 2],
             nodes: PartialMerkleTree::dummy_nodes_for_indices(&node_indices),
+            ..Default::default()
         };
 
         let err = partial_tree.fill().unwrap_err();
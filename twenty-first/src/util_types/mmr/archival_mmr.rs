@@ -0,0 +1,508 @@
+use itertools::Itertools;
+use thiserror::Error;
+
+use super::mmr_accumulator::MmrAccumulator;
+use super::mmr_membership_proof::MmrMembershipProof;
+use super::mmr_storage::InMemoryMmrStorage;
+use super::mmr_storage::MmrStorage;
+use super::mmr_trait::LeafMutation;
+use super::mmr_trait::Mmr;
+use super::shared_advanced;
+use crate::math::digest::Digest;
+use crate::prelude::Tip5;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+use crate::util_types::shared::bag_peaks;
+
+/// An archival [Merkle Mountain Range](Mmr), storing all nodes of all its Merkle
+/// trees in some [`MmrStorage`].
+///
+/// Archival-MMR vs Accumulator-MMR:
+///
+///   An Archival-MMR stores all nodes in multiple trees whereas an
+///   [Accumulator-MMR](MmrAccumulator) only stores the roots of the binary
+///   trees. Consequently, only an Archival-MMR can
+///   [prove membership](Self::prove_membership) of arbitrary leafs.
+///
+/// Merkle Mountain Ranges only know about hashes. When values are to be
+/// associated with MMRs, these values must be stored by the caller, or in a
+/// wrapper to this data structure.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::util_types::mmr::archival_mmr::ArchivalMmr;
+/// let leaf = Tip5::hash_varlen(&bfe_vec![42]);
+///
+/// let mut mmr: ArchivalMmr = ArchivalMmr::default();
+/// mmr.append(leaf);
+/// assert_eq!(Some(leaf), mmr.get_leaf(0));
+///
+/// let proof = mmr.prove_membership(0).unwrap();
+/// assert!(proof.verify(0, leaf, &mmr.peaks(), mmr.num_leafs()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchivalMmr<S: MmrStorage = InMemoryMmrStorage> {
+    /// The MMR's nodes. Since node indices start at 1, the node with index `i` is
+    /// stored at position `i - 1`.
+    storage: S,
+}
+
+impl<S: MmrStorage> ArchivalMmr<S> {
+    /// Create an archival MMR backed by the given storage, which may already hold
+    /// the nodes of an MMR.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the number of nodes in the storage is not the number of nodes
+    /// of any MMR, for example because an append was interrupted by a crash. Use
+    /// [`recover`](Self::recover) to roll back such an incomplete append.
+    pub fn new(storage: S) -> Result<Self, ArchivalMmrError> {
+        let num_nodes = storage.len();
+        if !Self::is_valid_num_nodes(num_nodes) {
+            return Err(ArchivalMmrError::InconsistentNumNodes(num_nodes));
+        }
+        Ok(Self { storage })
+    }
+
+    /// Create an archival MMR backed by the given storage, removing the nodes of
+    /// an incomplete append from the storage's end, if any.
+    ///
+    /// Unlike [`new`](Self::new), this modifies the storage. Only use it to
+    /// recover from a crash.
+    pub fn recover(mut storage: S) -> Self {
+        while !Self::is_valid_num_nodes(storage.len()) {
+            storage.pop();
+        }
+        Self { storage }
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    fn num_nodes(&self) -> u64 {
+        self.storage.len()
+    }
+
+    fn node(&self, node_index: u64) -> Digest {
+        self.storage.get(node_index - 1)
+    }
+
+    fn set_node(&mut self, node_index: u64, digest: Digest) {
+        self.storage.set(node_index - 1, digest);
+    }
+
+    /// The number of leafs of the MMR with the given number of nodes. The result is
+    /// only meaningful if the number of nodes is [valid](Self::is_valid_num_nodes).
+    fn num_nodes_to_num_leafs(mut num_nodes: u64) -> u64 {
+        // An MMR consists of perfect binary trees of pairwise distinct heights. Since
+        // a tree of height h has more nodes than all trees of smaller heights
+        // combined, the trees can be identified greedily.
+        let mut num_leafs = 0;
+        for height in (0..u64::BITS).rev() {
+            let num_nodes_in_tree = u64::MAX >> (u64::BITS - 1 - height);
+            if num_nodes >= num_nodes_in_tree {
+                num_nodes -= num_nodes_in_tree;
+                num_leafs += 1 << height;
+            }
+        }
+        num_leafs
+    }
+
+    fn is_valid_num_nodes(num_nodes: u64) -> bool {
+        let num_leafs = Self::num_nodes_to_num_leafs(num_nodes);
+        shared_advanced::num_leafs_to_num_nodes(num_leafs) == num_nodes
+    }
+
+    /// Get a leaf from the MMR, or [`None`] if the index is out of range.
+    pub fn get_leaf(&self, leaf_index: u64) -> Option<Digest> {
+        if leaf_index >= self.num_leafs() {
+            return None;
+        }
+        let node_index = shared_advanced::leaf_index_to_node_index(leaf_index);
+        Some(self.node(node_index))
+    }
+
+    /// Overwrite a leaf and update all its ancestors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn set_leaf(&mut self, leaf_index: u64, new_leaf: Digest) {
+        let num_leafs = self.num_leafs();
+        assert!(
+            leaf_index < num_leafs,
+            "Cannot set leaf outside of range. Got leaf_index {leaf_index}. Leaf count is {num_leafs}"
+        );
+
+        let mut node_index = shared_advanced::leaf_index_to_node_index(leaf_index);
+        self.set_node(node_index, new_leaf);
+
+        let mut parent_index = shared_advanced::parent(node_index);
+        let mut acc_hash = new_leaf;
+        while parent_index <= self.num_nodes() {
+            let (right_lineage_count, height) =
+                shared_advanced::right_lineage_length_and_own_height(node_index);
+            acc_hash = if right_lineage_count != 0 {
+                let left_sibling = shared_advanced::left_sibling(node_index, height);
+                Tip5::hash_pair(self.node(left_sibling), acc_hash)
+            } else {
+                let right_sibling = shared_advanced::right_sibling(node_index, height);
+                Tip5::hash_pair(acc_hash, self.node(right_sibling))
+            };
+            self.set_node(parent_index, acc_hash);
+            node_index = parent_index;
+            parent_index = shared_advanced::parent(parent_index);
+        }
+    }
+
+    /// Return the MMR membership proof for the leaf with the given index, or
+    /// [`None`] if the index is out of range.
+    pub fn prove_membership(&self, leaf_index: u64) -> Option<MmrMembershipProof> {
+        if leaf_index >= self.num_leafs() {
+            return None;
+        }
+
+        let mut node_index = shared_advanced::leaf_index_to_node_index(leaf_index);
+        let mut authentication_path = vec![];
+        while shared_advanced::parent(node_index) <= self.num_nodes() {
+            let (right_lineage_count, height) =
+                shared_advanced::right_lineage_length_and_own_height(node_index);
+            let sibling_index = if right_lineage_count != 0 {
+                shared_advanced::left_sibling(node_index, height)
+            } else {
+                shared_advanced::right_sibling(node_index, height)
+            };
+            authentication_path.push(self.node(sibling_index));
+            node_index = shared_advanced::parent(node_index);
+        }
+
+        Some(MmrMembershipProof::new(authentication_path))
+    }
+
+    /// Remove the last leaf from the MMR and return it, or [`None`] if the MMR is
+    /// empty.
+    pub fn remove_last_leaf(&mut self) -> Option<Digest> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // The last leaf was appended together with all its ancestors that are now
+        // in the MMR, which are exactly the most recently added nodes.
+        let (_, height) = shared_advanced::right_lineage_length_and_own_height(self.num_nodes());
+        for _ in 0..height {
+            self.storage.pop();
+        }
+        self.storage.pop()
+    }
+
+    /// Append a leaf to the MMR without computing a membership proof for it.
+    pub fn append_raw(&mut self, new_leaf: Digest) {
+        let mut node_index = self.num_nodes() + 1;
+        let mut node = new_leaf;
+        self.storage.push(node);
+
+        let (mut right_lineage_count, mut height) =
+            shared_advanced::right_lineage_length_and_own_height(node_index);
+        while right_lineage_count != 0 {
+            let left_sibling = shared_advanced::left_sibling(node_index, height);
+            node = Tip5::hash_pair(self.node(left_sibling), node);
+            self.storage.push(node);
+
+            // parent of right child is index + 1
+            node_index += 1;
+            (right_lineage_count, height) =
+                shared_advanced::right_lineage_length_and_own_height(node_index);
+        }
+    }
+}
+
+impl<S: MmrStorage> Mmr for ArchivalMmr<S> {
+    fn bag_peaks(&self) -> Digest {
+        bag_peaks(&self.peaks())
+    }
+
+    fn peaks(&self) -> Vec<Digest> {
+        let (_, peak_node_indices) =
+            shared_advanced::get_peak_heights_and_peak_node_indices(self.num_leafs());
+        peak_node_indices
+            .into_iter()
+            .map(|node_index| self.node(node_index))
+            .collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    fn num_leafs(&self) -> u64 {
+        Self::num_nodes_to_num_leafs(self.num_nodes())
+    }
+
+    fn append(&mut self, new_leaf: Digest) -> MmrMembershipProof {
+        let leaf_index = self.num_leafs();
+        self.append_raw(new_leaf);
+        self.prove_membership(leaf_index).unwrap()
+    }
+
+    /// Mutate an existing leaf. Since an archival MMR knows all its nodes, the
+    /// leaf mutation's membership proof is ignored.
+    fn mutate_leaf(&mut self, leaf_mutation: LeafMutation) {
+        self.set_leaf(leaf_mutation.leaf_index, leaf_mutation.new_leaf);
+    }
+
+    fn batch_mutate_leaf_and_update_mps(
+        &mut self,
+        membership_proofs: &mut [&mut MmrMembershipProof],
+        membership_proof_leaf_indices: &[u64],
+        leaf_mutations: Vec<LeafMutation>,
+    ) -> Vec<usize> {
+        assert!(
+            leaf_mutations
+                .iter()
+                .map(|leaf_mutation| leaf_mutation.leaf_index)
+                .all_unique(),
+            "Duplicated leafs are not allowed in membership proof updater"
+        );
+
+        for leaf_mutation in leaf_mutations {
+            self.mutate_leaf(leaf_mutation);
+        }
+
+        let mut modified_mps = vec![];
+        for (i, (mp, &mp_leaf_index)) in membership_proofs
+            .iter_mut()
+            .zip_eq(membership_proof_leaf_indices)
+            .enumerate()
+        {
+            let new_mp = self.prove_membership(mp_leaf_index).unwrap();
+            if new_mp != **mp {
+                modified_mps.push(i);
+            }
+            **mp = new_mp;
+        }
+
+        modified_mps
+    }
+
+    fn verify_batch_update(
+        &self,
+        new_peaks: &[Digest],
+        appended_leafs: &[Digest],
+        leaf_mutations: Vec<LeafMutation>,
+    ) -> bool {
+        self.to_accumulator()
+            .verify_batch_update(new_peaks, appended_leafs, leaf_mutations)
+    }
+
+    fn to_accumulator(&self) -> MmrAccumulator {
+        MmrAccumulator::init(self.peaks(), self.num_leafs())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ArchivalMmrError {
+    #[error("The storage holds {0} nodes, which is not the number of nodes of any MMR.")]
+    InconsistentNumNodes(u64),
+}
+
+#[cfg(test)]
+mod archival_mmr_tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::util_types::mmr::mmr_storage::mmr_storage_tests::TempFile;
+    use crate::util_types::mmr::mmr_storage::FileMmrStorage;
+
+    fn archival_mmr_from_leafs(leafs: &[Digest]) -> ArchivalMmr {
+        let mut mmr = ArchivalMmr::default();
+        for &leaf in leafs {
+            mmr.append_raw(leaf);
+        }
+        mmr
+    }
+
+    #[test]
+    fn number_of_leafs_can_be_recovered_from_number_of_nodes() {
+        for num_leafs in 0..1000 {
+            let num_nodes = shared_advanced::num_leafs_to_num_nodes(num_leafs);
+            let recovered_num_leafs =
+                ArchivalMmr::<InMemoryMmrStorage>::num_nodes_to_num_leafs(num_nodes);
+            assert_eq!(num_leafs, recovered_num_leafs);
+        }
+    }
+
+    #[proptest(cases = 30)]
+    fn archival_mmr_agrees_with_accumulator(#[strategy(vec(arb(), 0..100))] leafs: Vec<Digest>) {
+        let archival_mmr = archival_mmr_from_leafs(&leafs);
+        let accumulator = MmrAccumulator::new_from_leafs(leafs.clone());
+        prop_assert_eq!(accumulator, archival_mmr.to_accumulator());
+        prop_assert_eq!(leafs.is_empty(), archival_mmr.is_empty());
+
+        for (leaf_index, &leaf) in (0..).zip(&leafs) {
+            prop_assert_eq!(Some(leaf), archival_mmr.get_leaf(leaf_index));
+        }
+        prop_assert_eq!(None, archival_mmr.get_leaf(leafs.len() as u64));
+    }
+
+    #[proptest(cases = 30)]
+    fn membership_proofs_for_all_leafs_can_be_verified(
+        #[strategy(vec(arb(), 1..100))] leafs: Vec<Digest>,
+    ) {
+        let archival_mmr = archival_mmr_from_leafs(&leafs);
+        let peaks = archival_mmr.peaks();
+        let num_leafs = archival_mmr.num_leafs();
+        for (leaf_index, &leaf) in (0..).zip(&leafs) {
+            let proof = archival_mmr.prove_membership(leaf_index).unwrap();
+            prop_assert!(proof.verify(leaf_index, leaf, &peaks, num_leafs));
+        }
+        prop_assert_eq!(None, archival_mmr.prove_membership(num_leafs));
+    }
+
+    #[proptest(cases = 30)]
+    fn membership_proof_from_append_agrees_with_accumulator(
+        #[strategy(vec(arb(), 0..100))] leafs: Vec<Digest>,
+        #[strategy(arb())] new_leaf: Digest,
+    ) {
+        let mut archival_mmr = archival_mmr_from_leafs(&leafs);
+        let mut accumulator = MmrAccumulator::new_from_leafs(leafs);
+        let archival_proof = archival_mmr.append(new_leaf);
+        let accumulator_proof = accumulator.append(new_leaf);
+        prop_assert_eq!(accumulator_proof, archival_proof);
+        prop_assert_eq!(accumulator.peaks(), archival_mmr.peaks());
+    }
+
+    #[proptest(cases = 30)]
+    fn mutating_leaf_agrees_with_accumulator(
+        #[strategy(vec(arb(), 1..100))] leafs: Vec<Digest>,
+        #[strategy(0..#leafs.len() as u64)] leaf_index: u64,
+        #[strategy(arb())] new_leaf: Digest,
+    ) {
+        let mut archival_mmr = archival_mmr_from_leafs(&leafs);
+        let mut accumulator = MmrAccumulator::new_from_leafs(leafs);
+        let membership_proof = archival_mmr.prove_membership(leaf_index).unwrap();
+        let leaf_mutation = LeafMutation::new(leaf_index, new_leaf, membership_proof);
+
+        let old_archival_mmr = archival_mmr.clone();
+        archival_mmr.mutate_leaf(leaf_mutation.clone());
+        accumulator.mutate_leaf(leaf_mutation.clone());
+        prop_assert_eq!(accumulator.peaks(), archival_mmr.peaks());
+        prop_assert!(old_archival_mmr.verify_batch_update(
+            &archival_mmr.peaks(),
+            &[],
+            vec![leaf_mutation]
+        ));
+        prop_assert_eq!(Some(new_leaf), archival_mmr.get_leaf(leaf_index));
+    }
+
+    #[proptest(cases = 30)]
+    fn removing_last_leaf_undoes_append(
+        #[strategy(vec(arb(), 0..100))] leafs: Vec<Digest>,
+        #[strategy(arb())] new_leaf: Digest,
+    ) {
+        let mut archival_mmr = archival_mmr_from_leafs(&leafs);
+        let original_mmr = archival_mmr.clone();
+
+        archival_mmr.append(new_leaf);
+        prop_assert_eq!(Some(new_leaf), archival_mmr.remove_last_leaf());
+        prop_assert_eq!(original_mmr, archival_mmr);
+    }
+
+    #[test]
+    fn removing_last_leaf_from_empty_mmr_gives_none() {
+        let mut archival_mmr: ArchivalMmr = ArchivalMmr::default();
+        assert_eq!(None, archival_mmr.remove_last_leaf());
+    }
+
+    #[proptest(cases = 10)]
+    fn batch_mutation_updates_membership_proofs(
+        #[strategy(vec(arb(), 1..100))] leafs: Vec<Digest>,
+        #[strategy(vec(0..#leafs.len() as u64, 1..10))]
+        #[filter(#mutated_leaf_indices.iter().all_unique())]
+        mutated_leaf_indices: Vec<u64>,
+        #[strategy(vec(arb(), #mutated_leaf_indices.len()))] new_leafs: Vec<Digest>,
+        #[strategy(vec(0..#leafs.len() as u64, 1..10))] proven_leaf_indices: Vec<u64>,
+    ) {
+        let mut archival_mmr = archival_mmr_from_leafs(&leafs);
+        let leaf_mutations = mutated_leaf_indices
+            .iter()
+            .zip_eq(new_leafs)
+            .map(|(&leaf_index, new_leaf)| {
+                let membership_proof = archival_mmr.prove_membership(leaf_index).unwrap();
+                LeafMutation::new(leaf_index, new_leaf, membership_proof)
+            })
+            .collect_vec();
+
+        let mut membership_proofs = proven_leaf_indices
+            .iter()
+            .map(|&leaf_index| archival_mmr.prove_membership(leaf_index).unwrap())
+            .collect_vec();
+        let mut membership_proof_refs = membership_proofs.iter_mut().collect_vec();
+        archival_mmr.batch_mutate_leaf_and_update_mps(
+            &mut membership_proof_refs,
+            &proven_leaf_indices,
+            leaf_mutations,
+        );
+
+        let peaks = archival_mmr.peaks();
+        let num_leafs = archival_mmr.num_leafs();
+        for (proof, &leaf_index) in membership_proofs.iter().zip_eq(&proven_leaf_indices) {
+            let leaf = archival_mmr.get_leaf(leaf_index).unwrap();
+            prop_assert!(proof.verify(leaf_index, leaf, &peaks, num_leafs));
+        }
+    }
+
+    #[proptest(cases = 10)]
+    fn file_backed_mmr_can_be_reopened(
+        #[strategy(vec(arb(), 0..50))] leafs: Vec<Digest>,
+        #[strategy(arb())] new_leaf: Digest,
+    ) {
+        let temp_file = TempFile::new();
+        let mut archival_mmr =
+            ArchivalMmr::new(FileMmrStorage::open(&temp_file.0).unwrap()).unwrap();
+        for &leaf in &leafs {
+            archival_mmr.append(leaf);
+        }
+        if !leafs.is_empty() {
+            archival_mmr.set_leaf(0, new_leaf);
+            archival_mmr.remove_last_leaf();
+            archival_mmr.append(new_leaf);
+        }
+        let expected_accumulator = archival_mmr.to_accumulator();
+        drop(archival_mmr);
+
+        let reopened_mmr = ArchivalMmr::new(FileMmrStorage::open(&temp_file.0).unwrap()).unwrap();
+        prop_assert_eq!(expected_accumulator, reopened_mmr.to_accumulator());
+    }
+
+    #[proptest(cases = 30)]
+    fn incomplete_append_is_rejected_and_can_be_rolled_back(
+        // appending to an MMR with an odd number of leafs pushes at least 2 nodes
+        #[strategy(vec(arb(), 0..100))]
+        #[filter(!#leafs.len().is_multiple_of(2))]
+        leafs: Vec<Digest>,
+        #[strategy(arb())] new_leaf: Digest,
+    ) {
+        let original_mmr = archival_mmr_from_leafs(&leafs);
+        let mut full_mmr = original_mmr.clone();
+        full_mmr.append_raw(new_leaf);
+        let full_storage = full_mmr.into_storage();
+
+        // simulate a crash right before the last node of the append is pushed
+        let mut storage = original_mmr.clone().into_storage();
+        while storage.len() + 1 < full_storage.len() {
+            storage.push(full_storage.get(storage.len()));
+        }
+
+        let num_nodes = storage.len();
+        let err = ArchivalMmr::new(storage.clone()).unwrap_err();
+        prop_assert_eq!(ArchivalMmrError::InconsistentNumNodes(num_nodes), err);
+        prop_assert_eq!(original_mmr, ArchivalMmr::recover(storage));
+    }
+}
@@ -0,0 +1,324 @@
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::mem;
+use std::path::Path;
+
+use crate::math::digest::Digest;
+
+/// The storage backend of an [`ArchivalMmr`](super::archival_mmr::ArchivalMmr):
+/// a growable list of [`Digest`]s, indexed from 0.
+///
+/// Storage operations are infallible from the perspective of the MMR. If a
+/// backend can fail, for example because it performs I/O, it should report
+/// failures when it is created, and panic if an operation fails afterwards.
+pub trait MmrStorage {
+    /// The number of digests in the storage.
+    fn len(&self) -> u64;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The digest at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn get(&self, index: u64) -> Digest;
+
+    /// Overwrite the digest at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn set(&mut self, index: u64, digest: Digest);
+
+    /// Append a digest.
+    fn push(&mut self, digest: Digest);
+
+    /// Remove the last digest and return it, or [`None`] if the storage is empty.
+    fn pop(&mut self) -> Option<Digest>;
+}
+
+/// An [`MmrStorage`] that keeps all digests in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InMemoryMmrStorage {
+    digests: Vec<Digest>,
+}
+
+impl InMemoryMmrStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MmrStorage for InMemoryMmrStorage {
+    fn len(&self) -> u64 {
+        self.digests.len() as u64
+    }
+
+    fn get(&self, index: u64) -> Digest {
+        self.digests[usize::try_from(index).unwrap()]
+    }
+
+    fn set(&mut self, index: u64, digest: Digest) {
+        self.digests[usize::try_from(index).unwrap()] = digest;
+    }
+
+    fn push(&mut self, digest: Digest) {
+        self.digests.push(digest);
+    }
+
+    fn pop(&mut self) -> Option<Digest> {
+        self.digests.pop()
+    }
+}
+
+/// An [`MmrStorage`] that persists all digests to a file.
+///
+/// The file is an append-only log of storage operations. Every operation appends
+/// one fixed-size record, consisting of a little-endian `u64` index followed by
+/// the bytes of a [`Digest`]. A record with index equal to the current length is
+/// a [push](MmrStorage::push), one with a smaller index a
+/// [set](MmrStorage::set), and one with index [`u64::MAX`] a
+/// [pop](MmrStorage::pop). When [opening](Self::open) an existing file, the log
+/// is replayed.
+///
+/// Records are written to the file immediately but not synced to disk; use
+/// [`sync`](Self::sync) for durability. If the process stops while a record is
+/// being written, the file can end in an incomplete record. Opening the file
+/// discards it.
+///
+/// All digests are additionally kept in memory, making reads cheap.
+///
+/// # Panics
+///
+/// All storage operations that modify the storage panic if writing to the file
+/// fails.
+#[derive(Debug)]
+pub struct FileMmrStorage {
+    file: File,
+    digests: Vec<Digest>,
+}
+
+impl FileMmrStorage {
+    const RECORD_SIZE: usize = mem::size_of::<u64>() + Digest::BYTES;
+    const POP_INDEX: u64 = u64::MAX;
+
+    /// Open the storage file at the given path, creating it if it does not exist.
+    ///
+    /// An incomplete record at the end of the file is truncated away.
+    ///
+    /// # Errors
+    ///
+    /// - If the file cannot be opened, created, read, or truncated.
+    /// - If the file's content is not a valid log of storage operations.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)?;
+
+        let log_len = bytes.len() - bytes.len() % Self::RECORD_SIZE;
+        if log_len < bytes.len() {
+            file.set_len(log_len as u64)?;
+            file.sync_data()?;
+        }
+        let digests = Self::replay(&bytes[..log_len])?;
+
+        Ok(Self { file, digests })
+    }
+
+    /// Sync all records written so far to disk.
+    ///
+    /// # Errors
+    ///
+    /// If syncing the file fails.
+    pub fn sync(&self) -> io::Result<()> {
+        self.file.sync_data()
+    }
+
+    fn replay(log: &[u8]) -> io::Result<Vec<Digest>> {
+        let invalid_data = |reason| io::Error::new(io::ErrorKind::InvalidData, reason);
+        let mut digests = vec![];
+        for record in log.chunks_exact(Self::RECORD_SIZE) {
+            let (index, digest) = record.split_at(mem::size_of::<u64>());
+            let index = u64::from_le_bytes(index.try_into().unwrap());
+            let digest = Digest::try_from(digest)
+                .map_err(|_| invalid_data("log contains an invalid digest"))?;
+
+            match index {
+                Self::POP_INDEX if digests.pop().is_none() => {
+                    return Err(invalid_data("log pops from empty storage"))
+                }
+                Self::POP_INDEX => (),
+                i if i == digests.len() as u64 => digests.push(digest),
+                i if i < digests.len() as u64 => digests[i as usize] = digest,
+                _ => return Err(invalid_data("log writes out of bounds")),
+            }
+        }
+
+        Ok(digests)
+    }
+
+    fn append_record(&mut self, index: u64, digest: Digest) {
+        let mut record = Vec::with_capacity(Self::RECORD_SIZE);
+        record.extend(index.to_le_bytes());
+        record.extend(<[u8; Digest::BYTES]>::from(digest));
+        self.file
+            .write_all(&record)
+            .expect("writing to MMR storage file must succeed");
+    }
+}
+
+impl MmrStorage for FileMmrStorage {
+    fn len(&self) -> u64 {
+        self.digests.len() as u64
+    }
+
+    fn get(&self, index: u64) -> Digest {
+        self.digests[usize::try_from(index).unwrap()]
+    }
+
+    fn set(&mut self, index: u64, digest: Digest) {
+        assert!(index < self.len(), "index {index} out of bounds");
+        self.append_record(index, digest);
+        self.digests[usize::try_from(index).unwrap()] = digest;
+    }
+
+    fn push(&mut self, digest: Digest) {
+        self.append_record(self.len(), digest);
+        self.digests.push(digest);
+    }
+
+    fn pop(&mut self) -> Option<Digest> {
+        if self.digests.is_empty() {
+            return None;
+        }
+        self.append_record(Self::POP_INDEX, Digest::default());
+        self.digests.pop()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod mmr_storage_tests {
+    use std::path::PathBuf;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use rand::random;
+    use test_strategy::proptest;
+    use test_strategy::Arbitrary;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Arbitrary)]
+    enum StorageOperation {
+        Push(#[strategy(arb())] Digest),
+        Set(usize, #[strategy(arb())] Digest),
+        Pop,
+    }
+
+    impl StorageOperation {
+        fn apply(self, storage: &mut impl MmrStorage) {
+            match self {
+                Self::Push(digest) => storage.push(digest),
+                Self::Set(_, _) if storage.is_empty() => (),
+                Self::Set(index, digest) => storage.set(index as u64 % storage.len(), digest),
+                Self::Pop => _ = storage.pop(),
+            }
+        }
+    }
+
+    /// A path to a file that is deleted when the guard is dropped.
+    pub(crate) struct TempFile(pub(crate) PathBuf);
+
+    impl TempFile {
+        pub(crate) fn new() -> Self {
+            let file_name = format!("twenty-first-mmr-storage-{}", random::<u64>());
+            Self(std::env::temp_dir().join(file_name))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn contents(storage: &impl MmrStorage) -> Vec<Digest> {
+        (0..storage.len()).map(|i| storage.get(i)).collect()
+    }
+
+    #[proptest(cases = 30)]
+    fn file_storage_behaves_like_in_memory_storage(
+        #[strategy(vec(any::<StorageOperation>(), 0..50))] operations: Vec<StorageOperation>,
+    ) {
+        let temp_file = TempFile::new();
+        let mut file_storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        let mut in_memory_storage = InMemoryMmrStorage::new();
+        for operation in operations {
+            operation.clone().apply(&mut file_storage);
+            operation.apply(&mut in_memory_storage);
+        }
+        prop_assert_eq!(contents(&in_memory_storage), contents(&file_storage));
+    }
+
+    #[proptest(cases = 30)]
+    fn reopened_file_storage_has_same_contents(
+        #[strategy(vec(any::<StorageOperation>(), 0..50))] operations: Vec<StorageOperation>,
+    ) {
+        let temp_file = TempFile::new();
+        let mut storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        for operation in operations {
+            operation.apply(&mut storage);
+        }
+
+        let reopened_storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        prop_assert_eq!(contents(&storage), contents(&reopened_storage));
+    }
+
+    #[test]
+    fn opening_file_with_incomplete_record_discards_the_record() {
+        let temp_file = TempFile::new();
+        let mut in_memory_storage = InMemoryMmrStorage::new();
+        let mut file_storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        let first_digest = random();
+        in_memory_storage.push(first_digest);
+        file_storage.push(first_digest);
+        file_storage.sync().unwrap();
+        drop(file_storage);
+
+        let mut file = OpenOptions::new().append(true).open(&temp_file.0).unwrap();
+        file.write_all(&[0; 3]).unwrap();
+        drop(file);
+
+        let mut recovered_storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        assert_eq!(contents(&in_memory_storage), contents(&recovered_storage));
+
+        let second_digest = random();
+        in_memory_storage.push(second_digest);
+        recovered_storage.push(second_digest);
+        drop(recovered_storage);
+
+        let reopened_storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        assert_eq!(contents(&in_memory_storage), contents(&reopened_storage));
+    }
+
+    #[test]
+    fn popping_from_empty_storage_gives_none() {
+        assert_eq!(None, InMemoryMmrStorage::new().pop());
+
+        let temp_file = TempFile::new();
+        let mut storage = FileMmrStorage::open(&temp_file.0).unwrap();
+        assert_eq!(None, storage.pop());
+    }
+}
@@ -0,0 +1,299 @@
+use std::result;
+
+use arbitrary::Arbitrary;
+use thiserror::Error;
+
+use crate::math::b_field_element::BFieldElement;
+use crate::math::bfield_codec::BFieldCodec;
+use crate::math::bfield_codec::BFieldCodecError;
+use crate::math::x_field_element::XFieldElement;
+use crate::prelude::Tip5;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+type Result<T> = result::Result<T, ProofStreamError>;
+
+/// A transcript for non-interactive protocols using the [Fiat-Shamir
+/// heuristic][fiat_shamir].
+///
+/// The prover [enqueues](Self::enqueue) items, the verifier
+/// [dequeues](Self::dequeue) them in the same order. Every item is absorbed into
+/// the [`Sponge`](crate::util_types::algebraic_hasher::Sponge) `H` when it is
+/// enqueued or dequeued, respectively. Consequently, challenges
+/// [sampled](Self::sample_scalars) by the prover and the verifier are identical
+/// if and only if they have seen the same items in the same order.
+///
+/// Every item is absorbed together with its length, and every proof stream
+/// starts by absorbing a protocol-specific domain separator. This guarantees
+/// that transcripts of different protocols, or with different item boundaries,
+/// lead to different challenges.
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::util_types::proof_stream::ProofStream;
+/// let domain_separator = b"my protocol";
+///
+/// // prover
+/// let mut proof_stream = ProofStream::new(domain_separator);
+/// proof_stream.enqueue(&bfe!(42));
+/// let prover_challenge = proof_stream.sample_scalars(1);
+/// let proof = proof_stream.into_proof();
+///
+/// // verifier
+/// let mut proof_stream = ProofStream::from_proof(proof, domain_separator);
+/// let item: BFieldElement = proof_stream.dequeue().unwrap();
+/// let verifier_challenge = proof_stream.sample_scalars(1);
+///
+/// assert_eq!(bfe!(42), item);
+/// assert_eq!(prover_challenge, verifier_challenge);
+/// ```
+///
+/// [fiat_shamir]: https://en.wikipedia.org/wiki/Fiat%E2%80%93Shamir_heuristic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofStream<H: AlgebraicHasher = Tip5> {
+    items: Vec<Vec<BFieldElement>>,
+    items_index: usize,
+    sponge: H,
+}
+
+/// The items of a [`ProofStream`] in serializable form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BFieldCodec, Arbitrary)]
+pub struct Proof {
+    /// The [encodings](BFieldCodec::encode) of all items, in order.
+    pub items: Vec<Vec<BFieldElement>>,
+}
+
+impl ProofStream {
+    /// An empty proof stream using [`Tip5`]. To use a different hash function, see
+    /// [`new_with_hasher`](Self::new_with_hasher).
+    pub fn new(domain_separator: &[u8]) -> Self {
+        Self::new_with_hasher(domain_separator)
+    }
+
+    /// A proof stream using [`Tip5`], containing the items of the given proof.
+    /// To use a different hash function, see
+    /// [`from_proof_with_hasher`](Self::from_proof_with_hasher).
+    pub fn from_proof(proof: Proof, domain_separator: &[u8]) -> Self {
+        Self::from_proof_with_hasher(proof, domain_separator)
+    }
+}
+
+impl<H: AlgebraicHasher> ProofStream<H> {
+    /// An empty proof stream using the [`AlgebraicHasher`] `H`.
+    pub fn new_with_hasher(domain_separator: &[u8]) -> Self {
+        Self::from_proof_with_hasher(Proof::default(), domain_separator)
+    }
+
+    /// A proof stream using the [`AlgebraicHasher`] `H`, containing the items of
+    /// the given proof, ready to be [dequeued](Self::dequeue). The domain
+    /// separator must be the same one the prover used.
+    pub fn from_proof_with_hasher(proof: Proof, domain_separator: &[u8]) -> Self {
+        let mut proof_stream = Self {
+            items: proof.items,
+            items_index: 0,
+            sponge: H::init(),
+        };
+        let domain_separator = domain_separator
+            .iter()
+            .map(|&byte| BFieldElement::new(byte.into()))
+            .collect::<Vec<_>>();
+        proof_stream.absorb(&domain_separator);
+        proof_stream
+    }
+
+    pub fn into_proof(self) -> Proof {
+        Proof { items: self.items }
+    }
+
+    /// The total number of items in the proof stream.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The number of items that have not been [dequeued](Self::dequeue) yet.
+    pub fn num_remaining_items(&self) -> usize {
+        self.items.len() - self.items_index
+    }
+
+    /// Add an item to the proof stream and absorb it into the sponge.
+    pub fn enqueue<T: BFieldCodec>(&mut self, item: &T) {
+        let encoding = item.encode();
+        self.absorb(&encoding);
+        self.items.push(encoding);
+    }
+
+    /// Take the next item from the proof stream and absorb it into the sponge.
+    ///
+    /// # Errors
+    ///
+    /// - If all items have been dequeued already.
+    /// - If the next item cannot be decoded as a `T`. The item is consumed
+    ///   nevertheless.
+    pub fn dequeue<T: BFieldCodec>(&mut self) -> Result<T> {
+        let Some(encoding) = self.items.get(self.items_index).cloned() else {
+            return Err(ProofStreamError::EmptyQueue);
+        };
+        self.items_index += 1;
+        self.absorb(&encoding);
+
+        let item = T::decode(&encoding).map_err(|err| BFieldCodecError::from(err.into()))?;
+        Ok(*item)
+    }
+
+    /// Sample `num_scalars` many challenges. See
+    /// [`AlgebraicHasher::sample_scalars`].
+    pub fn sample_scalars(&mut self, num_scalars: usize) -> Vec<XFieldElement> {
+        self.sponge.sample_scalars(num_scalars)
+    }
+
+    /// Sample `num_indices` many indices in range `[0, upper_bound)`. See
+    /// [`AlgebraicHasher::sample_indices`].
+    pub fn sample_indices(&mut self, upper_bound: u32, num_indices: usize) -> Vec<u32> {
+        self.sponge.sample_indices(upper_bound, num_indices)
+    }
+
+    /// Absorb the length of the input followed by the input itself. Prefixing the
+    /// length makes the sequence of absorbed inputs uniquely decodable.
+    fn absorb(&mut self, input: &[BFieldElement]) {
+        let length = BFieldElement::new(input.len() as u64);
+        let length_prefixed_input = [&[length], input].concat();
+        self.sponge.pad_and_absorb_all(&length_prefixed_input);
+    }
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ProofStreamError {
+    #[error("queue must be non-empty in order to dequeue an item")]
+    EmptyQueue,
+
+    #[error("could not decode item")]
+    DecodingError(#[from] BFieldCodecError),
+}
+
+#[cfg(test)]
+mod proof_stream_tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::digest::Digest;
+    use crate::math::polynomial::Polynomial;
+    use crate::math::poseidon2::Poseidon2;
+
+    const DOMAIN_SEPARATOR: &[u8] = b"twenty-first proof stream tests";
+
+    #[proptest]
+    fn prover_and_verifier_agree_on_items_and_challenges(
+        #[strategy(arb())] digest: Digest,
+        #[strategy(vec(arb(), 0..10))] xfes: Vec<XFieldElement>,
+        #[strategy(arb())] polynomial: Polynomial<BFieldElement>,
+    ) {
+        let mut prover_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        prover_stream.enqueue(&digest);
+        let prover_scalars = prover_stream.sample_scalars(3);
+        prover_stream.enqueue(&xfes);
+        prover_stream.enqueue(&polynomial);
+        let prover_indices = prover_stream.sample_indices(1 << 10, 5);
+        let proof = prover_stream.into_proof();
+
+        let mut verifier_stream = ProofStream::from_proof(proof, DOMAIN_SEPARATOR);
+        prop_assert_eq!(3, verifier_stream.len());
+        prop_assert_eq!(digest, verifier_stream.dequeue()?);
+        prop_assert_eq!(prover_scalars, verifier_stream.sample_scalars(3));
+        prop_assert_eq!(xfes, verifier_stream.dequeue::<Vec<XFieldElement>>()?);
+        prop_assert_eq!(polynomial, verifier_stream.dequeue()?);
+        prop_assert_eq!(prover_indices, verifier_stream.sample_indices(1 << 10, 5));
+        prop_assert_eq!(0, verifier_stream.num_remaining_items());
+    }
+
+    #[proptest]
+    fn prover_and_verifier_agree_with_other_hasher(#[strategy(arb())] digest: Digest) {
+        let mut prover_stream = ProofStream::<Poseidon2>::new_with_hasher(DOMAIN_SEPARATOR);
+        prover_stream.enqueue(&digest);
+        let prover_scalars = prover_stream.sample_scalars(3);
+        let proof = prover_stream.into_proof();
+
+        let mut verifier_stream =
+            ProofStream::<Poseidon2>::from_proof_with_hasher(proof, DOMAIN_SEPARATOR);
+        prop_assert_eq!(digest, verifier_stream.dequeue()?);
+        prop_assert_eq!(prover_scalars, verifier_stream.sample_scalars(3));
+    }
+
+    #[proptest]
+    fn proof_survives_encoding_round_trip(#[strategy(arb())] proof: Proof) {
+        let decoded_proof = *Proof::decode(&proof.encode()).unwrap();
+        prop_assert_eq!(proof, decoded_proof);
+    }
+
+    #[test]
+    fn different_domain_separators_lead_to_different_challenges() {
+        let mut proof_stream = ProofStream::new(b"protocol A");
+        let mut other_proof_stream = ProofStream::new(b"protocol B");
+        assert_ne!(
+            proof_stream.sample_scalars(1),
+            other_proof_stream.sample_scalars(1)
+        );
+    }
+
+    #[proptest]
+    fn different_item_boundaries_lead_to_different_challenges(
+        #[strategy(arb())] elements: [BFieldElement; 3],
+    ) {
+        let [a, b, c] = elements;
+        let mut proof_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        proof_stream.enqueue(&[a, b]);
+        proof_stream.enqueue(&[c]);
+
+        let mut other_proof_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        other_proof_stream.enqueue(&[a]);
+        other_proof_stream.enqueue(&[b, c]);
+
+        prop_assert_ne!(
+            proof_stream.sample_scalars(1),
+            other_proof_stream.sample_scalars(1)
+        );
+    }
+
+    #[proptest]
+    fn modified_item_leads_to_different_challenges(
+        #[strategy(arb())] item: XFieldElement,
+        #[strategy(arb())]
+        #[filter(#item != #other_item)]
+        other_item: XFieldElement,
+    ) {
+        let mut prover_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        prover_stream.enqueue(&item);
+        let prover_challenge = prover_stream.sample_scalars(1);
+
+        let proof = Proof {
+            items: vec![other_item.encode()],
+        };
+        let mut verifier_stream = ProofStream::from_proof(proof, DOMAIN_SEPARATOR);
+        let _: XFieldElement = verifier_stream.dequeue()?;
+        prop_assert_ne!(prover_challenge, verifier_stream.sample_scalars(1));
+    }
+
+    #[test]
+    fn dequeueing_from_empty_proof_stream_fails() {
+        let mut proof_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        let err = proof_stream.dequeue::<BFieldElement>().unwrap_err();
+        assert!(matches!(err, ProofStreamError::EmptyQueue));
+    }
+
+    #[test]
+    fn dequeueing_item_of_wrong_type_fails() {
+        let mut prover_stream = ProofStream::new(DOMAIN_SEPARATOR);
+        prover_stream.enqueue(&XFieldElement::new_const(BFieldElement::new(1)));
+        let proof = prover_stream.into_proof();
+
+        let mut verifier_stream = ProofStream::from_proof(proof, DOMAIN_SEPARATOR);
+        let err = verifier_stream.dequeue::<Digest>().unwrap_err();
+        assert!(matches!(err, ProofStreamError::DecodingError(_)));
+    }
+}
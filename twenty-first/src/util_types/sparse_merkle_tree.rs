@@ -0,0 +1,675 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::result;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+use thiserror::Error;
+
+use crate::math::digest::Digest;
+use crate::prelude::Tip5;
+use crate::util_types::algebraic_hasher::AlgebraicHasher;
+
+/// The number of bits in a [`Digest`], which is the maximum depth of a
+/// [sparse Merkle tree](SparseMerkleTree).
+pub const MAX_DEPTH: usize = Digest::LEN * u64::BITS as usize;
+
+/// The first `depth` bits of a key, _i.e._, the position of a node in a
+/// [sparse Merkle tree](SparseMerkleTree). Bits are ordered from most significant
+/// to least significant, starting with the first element of the [`Digest`].
+type Path = [u64; Digest::LEN];
+
+/// The (key, value) pair stored in a leaf of a [sparse Merkle tree](SparseMerkleTree).
+type Entry = (Digest, Digest);
+
+type Result<T> = result::Result<T, SparseMerkleTreeError>;
+
+lazy_static! {
+    /// For every hash function, the digests of empty subtrees of height 0 through
+    /// [`MAX_DEPTH`], indexed by their height.
+    static ref EMPTY_SUBTREE_DIGESTS: RwLock<HashMap<TypeId, Arc<[Digest]>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// A [sparse Merkle tree][smt] is an authenticated key-value map. Its keys and
+/// values are [`Digest`]s.
+///
+/// Conceptually, the tree has `2^depth` leafs, one for every possible path
+/// described by the first `depth` bits of a key. Only the non-empty leafs and
+/// their ancestors are stored. Empty subtrees are represented by precomputed
+/// default digests. The leaf for the key-value pair `(key, value)` is
+/// `H::hash_pair(key, value)`; an empty leaf is the default [`Digest`].
+///
+/// If the depth is less than [`MAX_DEPTH`], two distinct keys can share a path.
+/// Such keys cannot both be stored in the tree at the same time.
+///
+/// Both membership and non-membership of keys can be proven; see
+/// [`SparseMerkleTreeProof`].
+///
+/// ```
+/// # use twenty_first::prelude::*;
+/// # use twenty_first::util_types::sparse_merkle_tree::SparseMerkleTree;
+/// let key = Tip5::hash_varlen(&bfe_vec![42]);
+/// let value = Tip5::hash_varlen(&bfe_vec![1337]);
+///
+/// let mut tree = SparseMerkleTree::new();
+/// tree.insert(key, value).unwrap();
+/// assert_eq!(Some(value), tree.get(key));
+///
+/// let proof = tree.prove(key);
+/// assert!(proof.verify_membership(tree.root(), key, value));
+/// ```
+///
+/// [smt]: https://eprint.iacr.org/2016/683
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleTree<H: AlgebraicHasher = Tip5> {
+    depth: usize,
+
+    /// The non-empty leafs, indexed by their path.
+    leafs: HashMap<Path, Entry>,
+
+    /// The digests of all nodes that differ from the default digest of their
+    /// depth, indexed by their depth and path. The root has depth 0.
+    nodes: HashMap<(usize, Path), Digest>,
+
+    /// The digests of empty subtrees, indexed by their height. See
+    /// [`default_digest`](Self::default_digest).
+    empty_subtree_digests: Arc<[Digest]>,
+
+    _hasher: PhantomData<H>,
+}
+
+/// A proof of membership or non-membership of a key in a
+/// [sparse Merkle tree](SparseMerkleTree).
+///
+/// The same proof can be used for both purposes: it reveals the leaf at the
+/// key's path, which is either empty, holds the key, or holds a different key
+/// with the same path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleTreeProof<H: AlgebraicHasher = Tip5> {
+    /// The depth of the sparse Merkle tree this proof is relative to.
+    pub depth: usize,
+
+    /// The (key, value) pair stored at the leaf the proof is about, if any.
+    pub leaf: Option<(Digest, Digest)>,
+
+    /// The siblings of all nodes on the path from the leaf to the root, starting
+    /// with the leaf's sibling. A sibling that is the root of an empty subtree is
+    /// [`None`].
+    pub siblings: Vec<Option<Digest>>,
+
+    _hasher: PhantomData<H>,
+}
+
+impl SparseMerkleTree {
+    /// An empty sparse Merkle tree of [maximal depth](MAX_DEPTH) using [`Tip5`].
+    /// To use a different hash function or depth, see
+    /// [`with_depth`](Self::with_depth).
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<H: AlgebraicHasher + 'static> Default for SparseMerkleTree<H> {
+    fn default() -> Self {
+        Self::with_depth(MAX_DEPTH).unwrap()
+    }
+}
+
+impl<H: AlgebraicHasher + 'static> SparseMerkleTree<H> {
+    /// An empty sparse Merkle tree of the given depth using the
+    /// [`AlgebraicHasher`] `H`.
+    ///
+    /// # Errors
+    ///
+    /// If the depth is 0 or exceeds [`MAX_DEPTH`].
+    pub fn with_depth(depth: usize) -> Result<Self> {
+        if depth == 0 || depth > MAX_DEPTH {
+            return Err(SparseMerkleTreeError::InvalidDepth(depth));
+        }
+
+        let tree = Self {
+            depth,
+            leafs: HashMap::new(),
+            nodes: HashMap::new(),
+            empty_subtree_digests: empty_subtree_digests::<H>(),
+            _hasher: PhantomData,
+        };
+        Ok(tree)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of keys stored in the tree.
+    pub fn len(&self) -> usize {
+        self.leafs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leafs.is_empty()
+    }
+
+    pub fn root(&self) -> Digest {
+        self.node(0, [0; Digest::LEN])
+    }
+
+    /// The value stored for the given key, if any.
+    pub fn get(&self, key: Digest) -> Option<Digest> {
+        self.leafs
+            .get(&key_path(key, self.depth))
+            .filter(|&&(stored_key, _)| stored_key == key)
+            .map(|&(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: Digest) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// All (key, value) pairs stored in the tree, in arbitrary order.
+    pub fn entries(&self) -> impl Iterator<Item = (Digest, Digest)> + '_ {
+        self.leafs.values().copied()
+    }
+
+    /// Insert or update the value for the given key. Returns the previous value,
+    /// if any.
+    ///
+    /// # Errors
+    ///
+    /// If a different key with the same path is already stored in the tree.
+    pub fn insert(&mut self, key: Digest, value: Digest) -> Result<Option<Digest>> {
+        let previous_value = self.get(key);
+        self.batch_update([(key, Some(value))])?;
+        Ok(previous_value)
+    }
+
+    /// Remove the given key from the tree. Returns the removed value, if any.
+    pub fn remove(&mut self, key: Digest) -> Option<Digest> {
+        let previous_value = self.get(key)?;
+        self.batch_update([(key, None)])
+            .expect("removing an existing key never fails");
+        Some(previous_value)
+    }
+
+    /// Apply many updates at once. An update `(key, Some(value))` inserts or
+    /// updates the value for `key`, an update `(key, None)` removes `key`. Updates
+    /// are applied in order, _i.e._, a later update for some key overrides an
+    /// earlier one.
+    ///
+    /// This is more efficient than updating keys individually because common
+    /// ancestors of the updated leafs are hashed only once.
+    ///
+    /// # Errors
+    ///
+    /// If an inserted key has the same path as a different key that is in the tree
+    /// at the time of insertion. In this case, the tree is left unchanged.
+    pub fn batch_update(
+        &mut self,
+        updates: impl IntoIterator<Item = (Digest, Option<Digest>)>,
+    ) -> Result<()> {
+        let mut staged_leafs = HashMap::<Path, Option<Entry>>::new();
+        for (key, maybe_value) in updates {
+            let path = key_path(key, self.depth);
+            let current_entry = match staged_leafs.get(&path) {
+                Some(&staged_entry) => staged_entry,
+                None => self.leafs.get(&path).copied(),
+            };
+            let occupant = current_entry
+                .map(|(stored_key, _)| stored_key)
+                .filter(|&stored_key| stored_key != key);
+
+            match (maybe_value, occupant) {
+                (Some(_), Some(occupant)) => {
+                    return Err(SparseMerkleTreeError::KeyCollision { key, occupant })
+                }
+                (Some(value), None) => _ = staged_leafs.insert(path, Some((key, value))),
+                (None, Some(_)) => (),
+                (None, None) => _ = staged_leafs.insert(path, None),
+            }
+        }
+
+        for (&path, &maybe_entry) in &staged_leafs {
+            match maybe_entry {
+                Some(entry) => _ = self.leafs.insert(path, entry),
+                None => _ = self.leafs.remove(&path),
+            }
+        }
+
+        self.rehash(staged_leafs.into_keys().collect());
+        Ok(())
+    }
+
+    /// Prove membership or non-membership of the given key.
+    pub fn prove(&self, key: Digest) -> SparseMerkleTreeProof<H> {
+        let path = key_path(key, self.depth);
+        let siblings = (1..=self.depth)
+            .rev()
+            .map(|depth| {
+                let sibling_path = truncated(flipped(path, depth - 1), depth);
+                self.nodes.get(&(depth, sibling_path)).copied()
+            })
+            .collect();
+
+        SparseMerkleTreeProof {
+            depth: self.depth,
+            leaf: self.leafs.get(&path).copied(),
+            siblings,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn node(&self, depth: usize, path: Path) -> Digest {
+        self.nodes
+            .get(&(depth, path))
+            .copied()
+            .unwrap_or(self.default_digest(depth))
+    }
+
+    /// The digest of an empty subtree whose root has the given depth.
+    fn default_digest(&self, depth: usize) -> Digest {
+        self.empty_subtree_digests[self.depth - depth]
+    }
+
+    fn set_node(&mut self, depth: usize, path: Path, digest: Digest) {
+        if digest == self.default_digest(depth) {
+            self.nodes.remove(&(depth, path));
+        } else {
+            self.nodes.insert((depth, path), digest);
+        }
+    }
+
+    /// Recompute the digests of the leafs with the given paths and of all their
+    /// ancestors, layer by layer.
+    fn rehash(&mut self, leaf_paths: HashSet<Path>) {
+        for &path in &leaf_paths {
+            let leaf = self
+                .leafs
+                .get(&path)
+                .map_or(Digest::default(), |&(key, value)| H::hash_pair(key, value));
+            self.set_node(self.depth, path, leaf);
+        }
+
+        let mut dirty_paths = leaf_paths;
+        for depth in (0..self.depth).rev() {
+            dirty_paths = dirty_paths
+                .into_iter()
+                .map(|path| truncated(path, depth))
+                .collect();
+            for &path in &dirty_paths {
+                let left_child = self.node(depth + 1, path);
+                let right_child = self.node(depth + 1, flipped(path, depth));
+                self.set_node(depth, path, H::hash_pair(left_child, right_child));
+            }
+        }
+    }
+}
+
+impl<H: AlgebraicHasher + 'static> SparseMerkleTreeProof<H> {
+    /// Verify that the given root is the root of a sparse Merkle tree that maps the
+    /// given key to the given value.
+    pub fn verify_membership(&self, expected_root: Digest, key: Digest, value: Digest) -> bool {
+        self.leaf == Some((key, value)) && self.root(key) == Some(expected_root)
+    }
+
+    /// Verify that the given root is the root of a sparse Merkle tree that does not
+    /// contain the given key.
+    pub fn verify_non_membership(&self, expected_root: Digest, key: Digest) -> bool {
+        let leaf_holds_key = self.leaf.is_some_and(|(stored_key, _)| stored_key == key);
+        !leaf_holds_key && self.root(key) == Some(expected_root)
+    }
+
+    /// The root of the tree described by this proof, or [`None`] if the proof is
+    /// malformed with respect to the given key.
+    fn root(&self, key: Digest) -> Option<Digest> {
+        if self.depth == 0 || self.depth > MAX_DEPTH || self.siblings.len() != self.depth {
+            return None;
+        }
+
+        let path = key_path(key, self.depth);
+        let mut node = match self.leaf {
+            Some((stored_key, _)) if key_path(stored_key, self.depth) != path => return None,
+            Some((stored_key, value)) => H::hash_pair(stored_key, value),
+            None => Digest::default(),
+        };
+
+        let empty_subtree_digests = empty_subtree_digests::<H>();
+        for (depth, sibling) in (1..=self.depth).rev().zip(&self.siblings) {
+            let sibling = sibling.unwrap_or(empty_subtree_digests[self.depth - depth]);
+            node = match bit(path, depth - 1) {
+                false => H::hash_pair(node, sibling),
+                true => H::hash_pair(sibling, node),
+            };
+        }
+
+        Some(node)
+    }
+}
+
+/// The digests of empty subtrees of height 0 through [`MAX_DEPTH`], indexed by
+/// their height. They are computed only once per hash function and then taken
+/// from a global cache.
+fn empty_subtree_digests<H: AlgebraicHasher + 'static>() -> Arc<[Digest]> {
+    let hasher = TypeId::of::<H>();
+    if let Some(digests) = EMPTY_SUBTREE_DIGESTS.read().unwrap().get(&hasher) {
+        return Arc::clone(digests);
+    }
+
+    let mut digests = Vec::with_capacity(MAX_DEPTH + 1);
+    let mut digest = Digest::default();
+    digests.push(digest);
+    for _ in 0..MAX_DEPTH {
+        digest = H::hash_pair(digest, digest);
+        digests.push(digest);
+    }
+
+    let mut cache = EMPTY_SUBTREE_DIGESTS.write().unwrap();
+    Arc::clone(cache.entry(hasher).or_insert_with(|| digests.into()))
+}
+
+fn key_path(key: Digest, depth: usize) -> Path {
+    truncated(key.values().map(|element| element.value()), depth)
+}
+
+/// The `index`th bit of the path, where bit 0 is the most significant bit.
+fn bit(path: Path, index: usize) -> bool {
+    let bit_index = u64::BITS as usize - 1 - index % u64::BITS as usize;
+    (path[index / u64::BITS as usize] >> bit_index) & 1 == 1
+}
+
+/// The path with its `index`th bit flipped. See also [`bit`].
+fn flipped(mut path: Path, index: usize) -> Path {
+    let bit_index = u64::BITS as usize - 1 - index % u64::BITS as usize;
+    path[index / u64::BITS as usize] ^= 1 << bit_index;
+    path
+}
+
+/// The path with all but the first `num_bits` bits set to 0.
+fn truncated(mut path: Path, num_bits: usize) -> Path {
+    for (i, word) in path.iter_mut().enumerate() {
+        let num_bits_in_word = num_bits.saturating_sub(i * u64::BITS as usize);
+        *word = match num_bits_in_word {
+            0 => 0,
+            n if n >= u64::BITS as usize => *word,
+            n => *word & !(u64::MAX >> n),
+        };
+    }
+    path
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum SparseMerkleTreeError {
+    #[error("The depth must be in range 1..={MAX_DEPTH}, but is {0}.")]
+    InvalidDepth(usize),
+
+    #[error("Key {key} has the same path as key {occupant}, which is already in the tree.")]
+    KeyCollision { key: Digest, occupant: Digest },
+}
+
+#[cfg(test)]
+mod sparse_merkle_tree_test {
+    use std::collections::BTreeMap;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::math::b_field_element::BFieldElement;
+
+    #[test]
+    fn empty_trees_of_different_depth_have_different_roots() {
+        let shallow_tree = SparseMerkleTree::<Tip5>::with_depth(10).unwrap();
+        let deep_tree = SparseMerkleTree::<Tip5>::with_depth(11).unwrap();
+        assert_ne!(shallow_tree.root(), deep_tree.root());
+    }
+
+    #[proptest(cases = 20)]
+    fn root_of_empty_tree_is_repeated_hash_of_empty_leaf(#[strategy(1..=MAX_DEPTH)] depth: usize) {
+        let mut expected_root = Digest::default();
+        for _ in 0..depth {
+            expected_root = Tip5::hash_pair(expected_root, expected_root);
+        }
+        let tree = SparseMerkleTree::<Tip5>::with_depth(depth).unwrap();
+        prop_assert_eq!(expected_root, tree.root());
+    }
+
+    #[test]
+    fn tree_with_invalid_depth_cannot_be_built() {
+        for depth in [0, MAX_DEPTH + 1] {
+            let err = SparseMerkleTree::<Tip5>::with_depth(depth).unwrap_err();
+            assert_eq!(SparseMerkleTreeError::InvalidDepth(depth), err);
+        }
+    }
+
+    #[proptest]
+    fn truncating_path_keeps_only_leading_bits(
+        #[strategy(arb())] key: Digest,
+        #[strategy(0..=MAX_DEPTH)] num_bits: usize,
+    ) {
+        let path = key_path(key, MAX_DEPTH);
+        let truncated_path = truncated(path, num_bits);
+        for i in 0..MAX_DEPTH {
+            let expected_bit = i < num_bits && bit(path, i);
+            prop_assert_eq!(expected_bit, bit(truncated_path, i));
+        }
+    }
+
+    #[proptest]
+    fn flipping_path_bit_changes_exactly_that_bit(
+        #[strategy(arb())] key: Digest,
+        #[strategy(0..MAX_DEPTH)] index: usize,
+    ) {
+        let path = key_path(key, MAX_DEPTH);
+        let flipped_path = flipped(path, index);
+        for i in 0..MAX_DEPTH {
+            prop_assert_eq!(i == index, bit(path, i) != bit(flipped_path, i));
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn inserted_values_can_be_retrieved_and_proven(
+        #[strategy(vec((arb(), arb()), 1..20))] entries: Vec<(Digest, Digest)>,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        for &(key, value) in &entries {
+            tree.insert(key, value).unwrap();
+        }
+
+        let expected_entries = entries.into_iter().collect::<BTreeMap<_, _>>();
+        prop_assert_eq!(expected_entries.len(), tree.len());
+        for (&key, &value) in &expected_entries {
+            prop_assert_eq!(Some(value), tree.get(key));
+            let proof = tree.prove(key);
+            prop_assert!(proof.verify_membership(tree.root(), key, value));
+            prop_assert!(!proof.verify_non_membership(tree.root(), key));
+        }
+    }
+
+    #[proptest(cases = 20)]
+    fn absent_key_can_be_proven_to_be_absent(
+        #[strategy(vec((arb(), arb()), 0..20))] entries: Vec<(Digest, Digest)>,
+        #[strategy(arb())] absent_key: Digest,
+        #[strategy(arb())] value: Digest,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        tree.batch_update(entries.into_iter().map(|(k, v)| (k, Some(v))))
+            .unwrap();
+        prop_assume!(!tree.contains_key(absent_key));
+
+        let proof = tree.prove(absent_key);
+        prop_assert!(proof.verify_non_membership(tree.root(), absent_key));
+        prop_assert!(!proof.verify_membership(tree.root(), absent_key, value));
+    }
+
+    #[proptest(cases = 20)]
+    fn root_is_independent_of_insertion_order(
+        #[strategy(vec((arb(), arb()), 0..20))] entries: Vec<(Digest, Digest)>,
+    ) {
+        let entries = entries.into_iter().collect::<BTreeMap<_, _>>();
+        let mut tree = SparseMerkleTree::new();
+        let mut reverse_tree = SparseMerkleTree::new();
+        for (&key, &value) in &entries {
+            tree.insert(key, value).unwrap();
+        }
+        for (&key, &value) in entries.iter().rev() {
+            reverse_tree.insert(key, value).unwrap();
+        }
+        prop_assert_eq!(tree, reverse_tree);
+    }
+
+    #[proptest(cases = 20)]
+    fn batch_update_is_equivalent_to_individual_updates(
+        #[strategy(vec((arb(), arb()), 0..20))] initial_entries: Vec<(Digest, Digest)>,
+        #[strategy(vec((arb(), arb()), 0..20))] updates: Vec<(Digest, Option<Digest>)>,
+        #[strategy(vec(0..#initial_entries.len().max(1), #updates.len()))]
+        indices_of_updated_keys: Vec<usize>,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        for &(key, value) in &initial_entries {
+            tree.insert(key, value).unwrap();
+        }
+
+        // make sure some updates modify or remove existing keys
+        let updates = updates
+            .into_iter()
+            .zip(indices_of_updated_keys)
+            .enumerate()
+            .map(
+                |(i, ((key, value), key_idx))| match initial_entries.get(key_idx) {
+                    Some(&(existing_key, _)) if i % 2 == 0 => (existing_key, value),
+                    _ => (key, value),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let mut batch_tree = tree.clone();
+        batch_tree.batch_update(updates.clone()).unwrap();
+
+        for (key, maybe_value) in updates {
+            match maybe_value {
+                Some(value) => _ = tree.insert(key, value).unwrap(),
+                None => _ = tree.remove(key),
+            }
+        }
+        prop_assert_eq!(tree, batch_tree);
+    }
+
+    #[proptest(cases = 20)]
+    fn removing_all_keys_gives_empty_tree(
+        #[strategy(vec((arb(), arb()), 0..20))] entries: Vec<(Digest, Digest)>,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        for &(key, value) in &entries {
+            tree.insert(key, value).unwrap();
+        }
+        for (key, _) in entries {
+            tree.remove(key);
+        }
+        prop_assert!(tree.is_empty());
+        prop_assert_eq!(SparseMerkleTree::new(), tree);
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_with_corrupted_sibling_fails_verification(
+        #[strategy(vec((arb(), arb()), 1..20))] entries: Vec<(Digest, Digest)>,
+        #[strategy(0..#entries.len())] entry_index: usize,
+        #[strategy(0..MAX_DEPTH)] sibling_index: usize,
+        #[strategy(arb())] corrupt_sibling: Digest,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        for &(key, value) in &entries {
+            tree.insert(key, value).unwrap();
+        }
+        let key = entries[entry_index].0;
+        let value = tree.get(key).unwrap();
+
+        let mut proof = tree.prove(key);
+        prop_assume!(proof.siblings[sibling_index] != Some(corrupt_sibling));
+        proof.siblings[sibling_index] = Some(corrupt_sibling);
+        prop_assert!(!proof.verify_membership(tree.root(), key, value));
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_with_wrong_value_fails_verification(
+        #[strategy(arb())] key: Digest,
+        #[strategy(arb())] value: Digest,
+        #[strategy(arb())]
+        #[filter(#value != #other_value)]
+        other_value: Digest,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key, value).unwrap();
+        let mut proof = tree.prove(key);
+        prop_assert!(!proof.verify_membership(tree.root(), key, other_value));
+
+        proof.leaf = Some((key, other_value));
+        prop_assert!(!proof.verify_membership(tree.root(), key, other_value));
+    }
+
+    #[proptest(cases = 20)]
+    fn proof_of_non_membership_cannot_hide_existing_key(
+        #[strategy(arb())] key: Digest,
+        #[strategy(arb())] value: Digest,
+    ) {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key, value).unwrap();
+        let mut proof = tree.prove(key);
+        proof.leaf = None;
+        prop_assert!(!proof.verify_non_membership(tree.root(), key));
+    }
+
+    #[test]
+    fn keys_with_same_path_collide_in_shallow_tree() {
+        let key = Digest::new([BFieldElement::new(0b1010 << 60); Digest::LEN]);
+        let other_key = Digest::new([BFieldElement::new(0b1011 << 60); Digest::LEN]);
+        let value = Digest::default();
+
+        let mut tree = SparseMerkleTree::<Tip5>::with_depth(3).unwrap();
+        tree.insert(key, value).unwrap();
+        let err = tree.insert(other_key, value).unwrap_err();
+        assert_eq!(
+            SparseMerkleTreeError::KeyCollision {
+                key: other_key,
+                occupant: key
+            },
+            err
+        );
+        assert_eq!(1, tree.len());
+
+        let proof = tree.prove(other_key);
+        assert!(proof.verify_non_membership(tree.root(), other_key));
+
+        tree.remove(key);
+        tree.insert(other_key, value).unwrap();
+        assert_eq!(Some(value), tree.get(other_key));
+        assert_eq!(None, tree.get(key));
+    }
+
+    #[test]
+    fn failing_batch_update_leaves_tree_unchanged() {
+        let key = Digest::new([BFieldElement::new(0b1010 << 60); Digest::LEN]);
+        let other_key = Digest::new([BFieldElement::new(0b1011 << 60); Digest::LEN]);
+        let unrelated_key = Digest::new([BFieldElement::new(0b0101 << 60); Digest::LEN]);
+        let value = Digest::default();
+
+        let mut tree = SparseMerkleTree::<Tip5>::with_depth(3).unwrap();
+        tree.insert(key, value).unwrap();
+        let original_tree = tree.clone();
+
+        let updates = [(unrelated_key, Some(value)), (other_key, Some(value))];
+        let err = tree.batch_update(updates).unwrap_err();
+        assert_eq!(
+            SparseMerkleTreeError::KeyCollision {
+                key: other_key,
+                occupant: key
+            },
+            err
+        );
+        assert_eq!(original_tree, tree);
+    }
+}